@@ -3,6 +3,8 @@ use std::collections::BTreeMap;
 
 /// JSON-Format zum Austausch von .gbx-Dateien zwischen Server / Client
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
 pub struct PdfFile {
     /// Ob diese Datei digitalisiert wurde (hat zugehörige PDF-Datei) oder nicht
     #[serde(default)]
@@ -14,13 +16,365 @@ pub struct PdfFile {
     /// Benutzerdefinierte Anpassungen an das Seitenlayout (SeitenTyp, etc.), indexiert nach Seitenzahl
     #[serde(default)]
     #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    #[serde(alias = "anpassungenSeite")]
     pub anpassungen_seite: BTreeMap<String, AnpassungSeite>,
     /// Analysiertes / bearbeitetes Grundbuchblatt
     pub analysiert: Grundbuch,
 }
 
+impl PdfFile {
+    /// Kopie der Datei ohne hOCR-Layout (z.B. für schlanke Archivkopien)
+    pub fn strip_hocr(&self) -> PdfFile {
+        PdfFile {
+            digitalisiert: self.digitalisiert,
+            hocr: HocrLayout::default(),
+            anpassungen_seite: self.anpassungen_seite.clone(),
+            analysiert: self.analysiert.clone(),
+        }
+    }
+
+    /// Entfernt das hOCR-Layout aus der Datei
+    pub fn clear_hocr(&mut self) {
+        self.hocr = HocrLayout::default();
+    }
+
+    /// Größe des hOCR-Layouts in Bytes (als JSON serialisiert)
+    pub fn hocr_groesse_bytes(&self) -> usize {
+        json_groesse_bytes(&self.hocr)
+    }
+
+    /// Größen der einzelnen Teilbäume in Bytes (jeweils als JSON serialisiert), z.B. um zu
+    /// entscheiden, ob `strip_hocr` lohnt
+    pub fn groessen_report(&self) -> GroessenReport {
+        let gb = &self.analysiert;
+        GroessenReport {
+            gesamt: json_groesse_bytes(self),
+            hocr: json_groesse_bytes(&self.hocr),
+            anpassungen_seite: json_groesse_bytes(&self.anpassungen_seite),
+            analysiert: json_groesse_bytes(gb),
+            titelblatt: json_groesse_bytes(&gb.titelblatt),
+            bestandsverzeichnis: json_groesse_bytes(&gb.bestandsverzeichnis),
+            abt1: json_groesse_bytes(&gb.abt1),
+            abt2: json_groesse_bytes(&gb.abt2),
+            abt3: json_groesse_bytes(&gb.abt3),
+        }
+    }
+
+    /// Qualitäts-Score (0 - 100) des Dokuments zum Priorisieren der Nachbearbeitung: Mittelwert
+    /// aus durchschnittlicher Wort-Konfidenz, Anteil verorteter Einträge und Anteil ausgefüllter
+    /// Pflichtfelder. Teilfaktoren ohne Daten (keine hOCR-Worte / keine Einträge) fließen nicht
+    /// in den Score ein.
+    pub fn qualitaets_score(&self) -> QualitaetsScore {
+        let konfidenzen = self
+            .hocr
+            .seiten
+            .values()
+            .flat_map(|s| s.parsed.worte())
+            .map(|w| w.confidence)
+            .filter(|c| !c.is_nan())
+            .collect::<Vec<_>>();
+
+        let mut anzahl_eintraege = 0;
+        let mut verortet = 0;
+        let mut mit_pflichtfeld = 0;
+        let mut pflichtfeld_ausgefuellt = 0;
+        for eintrag in self.analysiert.alle_eintraege() {
+            anzahl_eintraege += 1;
+            if eintrag.position_in_pdf().is_some_and(|p| !p.ist_leer()) {
+                verortet += 1;
+            }
+            if let Some(text) = eintrag.pflichtfeld().and_then(|f| eintrag.text_feld(f)) {
+                mit_pflichtfeld += 1;
+                if !text.text().trim().is_empty() {
+                    pflichtfeld_ausgefuellt += 1;
+                }
+            }
+        }
+
+        let anteil = |teil: usize, gesamt: usize| -> Option<f32> {
+            if gesamt == 0 {
+                None
+            } else {
+                Some(teil as f32 / gesamt as f32)
+            }
+        };
+
+        let durchschnittliche_konfidenz = if konfidenzen.is_empty() {
+            None
+        } else {
+            Some(konfidenzen.iter().sum::<f32>() / konfidenzen.len() as f32)
+        };
+        let anteil_verortet = anteil(verortet, anzahl_eintraege);
+        let anteil_pflichtfelder = anteil(pflichtfeld_ausgefuellt, mit_pflichtfeld);
+
+        let faktoren = [
+            durchschnittliche_konfidenz,
+            anteil_verortet,
+            anteil_pflichtfelder,
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+        let score = if faktoren.is_empty() {
+            0.0
+        } else {
+            faktoren.iter().sum::<f32>() / faktoren.len() as f32 * 100.0
+        };
+
+        QualitaetsScore {
+            score: score.clamp(0.0, 100.0),
+            durchschnittliche_konfidenz,
+            anteil_verortet,
+            anteil_pflichtfelder,
+            anzahl_worte: konfidenzen.len(),
+            anzahl_eintraege,
+        }
+    }
+
+    /// Ob beide Dateien dasselbe Grundbuchblatt beschreiben (Vergleich der normalisierten Titelblätter)
+    pub fn ist_gleiches_blatt(&self, other: &PdfFile) -> bool {
+        self.analysiert.titelblatt.normalisiert() == other.analysiert.titelblatt.normalisiert()
+    }
+
+    /// Kompakte Zusammenfassung des Blatts für Listenansichten (ohne hOCR und Einträge).
+    /// Gerötete Flurstücke und Rechte zählen nicht als aktiv, Warnungen umfassen
+    /// `pruefe_flurstueck`, `Abteilung1::pruefe_bv_referenzen`, `pruefe_flaechenbilanz` und
+    /// `pruefe_bisherige_lfd_nr_ketten`.
+    pub fn kurzuebersicht(&self) -> BlattUebersicht {
+        let gb = &self.analysiert;
+        let bv = &gb.bestandsverzeichnis;
+        let flurstuecke = bv
+            .eintraege
+            .iter()
+            .filter_map(|e| match e {
+                BvEintrag::Flurstueck(f) => Some(f),
+                BvEintrag::Recht(_) => None,
+            })
+            .collect::<Vec<_>>();
+
+        let aktive_flurstuecke = flurstuecke
+            .iter()
+            .filter(|f| !f.ist_geroetet())
+            .collect::<Vec<_>>();
+
+        let hat_warnungen = flurstuecke.iter().any(|f| f.pruefe_flurstueck().is_some())
+            || !gb.abt1.pruefe_bv_referenzen(bv).is_empty()
+            || !bv.pruefe_flaechenbilanz().is_empty()
+            || !bv.pruefe_bisherige_lfd_nr_ketten().is_empty();
+
+        BlattUebersicht {
+            titelblatt: gb.titelblatt.clone(),
+            digitalisiert: self.digitalisiert,
+            anzahl_flurstuecke: aktive_flurstuecke.len(),
+            gesamtflaeche_m2: aktive_flurstuecke
+                .iter()
+                .map(|f| f.groesse.get_m2())
+                .fold(0, u64::saturating_add),
+            aktive_rechte_abt2: gb
+                .abt2
+                .eintraege
+                .iter()
+                .filter(|e| !e.ist_geroetet())
+                .count(),
+            aktive_rechte_abt3: gb
+                .abt3
+                .eintraege
+                .iter()
+                .filter(|e| !e.ist_geroetet())
+                .count(),
+            hat_warnungen,
+        }
+    }
+
+    /// Alle Rechtecke (in mm) zum Einzeichnen über der PDF-Seite: Positionen der Einträge,
+    /// angepasste Spalten, Zeilen (über die volle Seitenbreite) und die Hüllrechtecke der
+    /// roten Linien
+    pub fn overlay_rechtecke(&self, seite: &str) -> Vec<OverlayRect> {
+        let mut rechtecke = Vec::new();
+
+        for eintrag in self.analysiert.alle_eintraege() {
+            let position = match eintrag.position_in_pdf() {
+                Some(p) => p,
+                None => continue,
+            };
+            let kategorie = match eintrag.abteilung() {
+                Abteilungsbereich::Bestandsverzeichnis => OverlayKategorie::EintragBv,
+                Abteilungsbereich::Abt1 => OverlayKategorie::EintragAbt1,
+                Abteilungsbereich::Abt2 => OverlayKategorie::EintragAbt2,
+                Abteilungsbereich::Abt3 => OverlayKategorie::EintragAbt3,
+            };
+            let lfd_nr = Some(eintrag.lfd_nr_string()).filter(|s| !s.trim().is_empty());
+            for (_, rect) in position
+                .alle_rects()
+                .into_iter()
+                .filter(|(s, _)| s.trim() == seite.trim())
+            {
+                rechtecke.push(OverlayRect {
+                    rect: rect.normalized(),
+                    kategorie,
+                    lfd_nr: lfd_nr.clone(),
+                });
+            }
+        }
+
+        let hocr_seite = self.hocr.seiten.get(seite);
+
+        if let Some(anpassung) = self.anpassungen_seite.get(seite) {
+            for rect in anpassung.spalten.values() {
+                rechtecke.push(OverlayRect {
+                    rect: rect.normalized(),
+                    kategorie: OverlayKategorie::Spalte,
+                    lfd_nr: None,
+                });
+            }
+
+            let breite_mm = hocr_seite.map(|s| s.breite_mm).unwrap_or(0.0);
+            for y in anpassung.alle_zeilen_sortiert() {
+                rechtecke.push(OverlayRect {
+                    rect: Rect {
+                        min_x: 0.0,
+                        min_y: y,
+                        max_x: breite_mm,
+                        max_y: y,
+                    },
+                    kategorie: OverlayKategorie::Zeile,
+                    lfd_nr: None,
+                });
+            }
+        }
+
+        for linie in hocr_seite.iter().flat_map(|s| s.rote_linien.iter()) {
+            if linie.punkte.is_empty() {
+                continue;
+            }
+            let rect = linie.punkte.iter().fold(
+                Rect {
+                    min_x: f32::MAX,
+                    min_y: f32::MAX,
+                    max_x: f32::MIN,
+                    max_y: f32::MIN,
+                },
+                |r, p| Rect {
+                    min_x: r.min_x.min(p.x),
+                    min_y: r.min_y.min(p.y),
+                    max_x: r.max_x.max(p.x),
+                    max_y: r.max_y.max(p.y),
+                },
+            );
+            rechtecke.push(OverlayRect {
+                rect,
+                kategorie: OverlayKategorie::RoteLinie,
+                lfd_nr: None,
+            });
+        }
+
+        rechtecke
+    }
+}
+
+/// Größe eines Werts in Bytes als JSON serialisiert (0, wenn die Serialisierung fehlschlägt)
+fn json_groesse_bytes<T: Serialize>(wert: &T) -> usize {
+    serde_json::to_vec(wert).map(|v| v.len()).unwrap_or(0)
+}
+
+/// Serialisierte Größen (JSON, in Bytes) der Teilbäume einer `PdfFile`,
+/// siehe `PdfFile::groessen_report`
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GroessenReport {
+    /// Gesamte Datei
+    pub gesamt: usize,
+    pub hocr: usize,
+    pub anpassungen_seite: usize,
+    /// Gesamtes Grundbuch (`analysiert`)
+    pub analysiert: usize,
+    pub titelblatt: usize,
+    pub bestandsverzeichnis: usize,
+    pub abt1: usize,
+    pub abt2: usize,
+    pub abt3: usize,
+}
+
+/// Qualitäts-Score eines Dokuments mit seinen Teilfaktoren, siehe `PdfFile::qualitaets_score`
+#[derive(Debug, Default, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QualitaetsScore {
+    /// Gesamt-Score (0 - 100)
+    pub score: f32,
+    /// Durchschnittliche `confidence` aller hOCR-Worte (0.0 - 1.0), `None` ohne hOCR-Worte
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub durchschnittliche_konfidenz: Option<f32>,
+    /// Anteil der Einträge mit `position_in_pdf` (0.0 - 1.0), `None` ohne Einträge
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub anteil_verortet: Option<f32>,
+    /// Anteil der Einträge mit ausgefülltem Pflichtfeld (0.0 - 1.0), `None` ohne Einträge
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub anteil_pflichtfelder: Option<f32>,
+    pub anzahl_worte: usize,
+    pub anzahl_eintraege: usize,
+}
+
+/// Kompakte Zusammenfassung eines Blatts für Listenansichten, siehe `PdfFile::kurzuebersicht`
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
+pub struct BlattUebersicht {
+    pub titelblatt: Titelblatt,
+    pub digitalisiert: bool,
+    /// Anzahl der nicht geröteten Flurstücke im Bestandsverzeichnis
+    #[serde(alias = "anzahlFlurstuecke")]
+    pub anzahl_flurstuecke: usize,
+    /// Summe der Größen aller nicht geröteten Flurstücke in m²
+    #[serde(alias = "gesamtflaecheM2")]
+    pub gesamtflaeche_m2: u64,
+    /// Anzahl der nicht geröteten Einträge in Abteilung 2
+    #[serde(alias = "aktiveRechteAbt2")]
+    pub aktive_rechte_abt2: usize,
+    /// Anzahl der nicht geröteten Einträge in Abteilung 3
+    #[serde(alias = "aktiveRechteAbt3")]
+    pub aktive_rechte_abt3: usize,
+    /// Ob eine der Plausibilitätsprüfungen Fehler oder Warnungen meldet
+    #[serde(alias = "hatWarnungen")]
+    pub hat_warnungen: bool,
+}
+
+/// Art eines Overlay-Rechtecks, siehe `PdfFile::overlay_rechtecke`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum OverlayKategorie {
+    /// Eintrag (auch Zu- / Abschreibung, Veränderung, Löschung) im Bestandsverzeichnis
+    EintragBv,
+    EintragAbt1,
+    EintragAbt2,
+    EintragAbt3,
+    /// Spalte aus `AnpassungSeite::spalten`
+    Spalte,
+    /// Manuelle oder automatische Zeile, als Rechteck der Höhe 0
+    Zeile,
+    /// Hüllrechteck einer roten Linie
+    RoteLinie,
+}
+
+/// Typisiertes Rechteck (in mm) zum Einzeichnen über der PDF-Seite
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct OverlayRect {
+    pub rect: Rect,
+    pub kategorie: OverlayKategorie,
+    /// Laufende Nummer des zugehörigen Eintrags (siehe `GrundbuchEintragRef::lfd_nr_string`)
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lfd_nr: Option<String>,
+}
+
 /// Digitalisiertes Layout der erkannten Buchstaben auf den Seiten, indexiert nach Seitenzahl
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
 pub struct HocrLayout {
     /// hOCR-Layout der individuellen PDF-Seiten, indexiert nach Seitenzahl
     #[serde(default)]
@@ -32,50 +386,717 @@ impl HocrLayout {
     fn is_empty(&self) -> bool {
         self.seiten.is_empty()
     }
+
+    /// Effektiver SeitenTyp je Seite (aus `klassifikation_neu`, sonst `None`) in Seitenreihenfolge
+    pub fn seitentyp_sequenz(
+        &self,
+        anpassungen: &BTreeMap<String, AnpassungSeite>,
+    ) -> Vec<(String, Option<SeitenTyp>)> {
+        let mut seiten = self.seiten.keys().collect::<Vec<_>>();
+        seiten.sort_by_key(|s| seiten_sortierschluessel(s));
+        seiten
+            .into_iter()
+            .map(|s| {
+                let typ = anpassungen.get(s).and_then(|a| a.klassifikation_neu);
+                (s.clone(), typ)
+            })
+            .collect()
+    }
+
+    /// Unplausible Wechsel in der SeitenTyp-Sequenz, bei denen auf eine Seite einer
+    /// späteren Abteilung wieder eine Seite einer früheren Abteilung folgt
+    /// (z.B. eine Abt2-Seite mitten in einer BV-Sequenz)
+    pub fn ungewoehnliche_uebergaenge(
+        &self,
+        anpassungen: &BTreeMap<String, AnpassungSeite>,
+    ) -> Vec<SeitenTypUebergang> {
+        let klassifiziert = self
+            .seitentyp_sequenz(anpassungen)
+            .into_iter()
+            .filter_map(|(s, t)| Some((s, t?)))
+            .collect::<Vec<_>>();
+
+        klassifiziert
+            .windows(2)
+            .filter(|w| w[1].1.abteilung() < w[0].1.abteilung())
+            .map(|w| SeitenTypUebergang {
+                von_seite: w[0].0.clone(),
+                von: w[0].1,
+                nach_seite: w[1].0.clone(),
+                nach: w[1].1,
+            })
+            .collect()
+    }
+}
+
+/// Wechsel des SeitenTyps zwischen zwei aufeinanderfolgenden (klassifizierten) Seiten
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SeitenTypUebergang {
+    pub von_seite: String,
+    pub von: SeitenTyp,
+    pub nach_seite: String,
+    pub nach: SeitenTyp,
+}
+
+/// Fehler beim Zusammenführen zweier `HocrLayout`s
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum MergeFehler {
+    /// Diese Seitennummern wären trotz Offset doppelt vergeben
+    SeitenKollision(Vec<String>),
+}
+
+impl std::fmt::Display for MergeFehler {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            MergeFehler::SeitenKollision(seiten) => {
+                write!(f, "Seiten bereits vorhanden: {}", seiten.join(", "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for MergeFehler {}
+
+impl HocrLayout {
+    /// Seitenzahlen aller leeren Seiten (siehe `HocrSeite::ist_leer`) in Seitenreihenfolge
+    pub fn leere_seiten(&self, min_worte: usize, min_confidence: f32) -> Vec<String> {
+        let mut seiten = self
+            .seiten
+            .iter()
+            .filter(|(_, s)| s.ist_leer(min_worte, min_confidence))
+            .map(|(k, _)| k.clone())
+            .collect::<Vec<_>>();
+        seiten.sort_by_key(|s| seiten_sortierschluessel(s));
+        seiten
+    }
+
+    /// Fügt die Seiten eines weiteren Teilscans ein, numerische Seitenschlüssel werden dabei um
+    /// `seiten_offset` verschoben (nicht-numerische bleiben unverändert). Existiert ein
+    /// Zielschlüssel bereits, wird nichts eingefügt und `MergeFehler::SeitenKollision` zurückgegeben.
+    pub fn merge(&mut self, other: HocrLayout, seiten_offset: usize) -> Result<(), MergeFehler> {
+        let neu = other
+            .seiten
+            .into_iter()
+            .map(|(k, v)| {
+                let k = match k.trim().parse::<usize>() {
+                    Ok(n) => n.saturating_add(seiten_offset).to_string(),
+                    Err(_) => k,
+                };
+                (k, v)
+            })
+            .collect::<Vec<_>>();
+
+        let mut kollisionen = Vec::new();
+        let mut gesehen = std::collections::BTreeSet::new();
+        for (k, _) in neu.iter() {
+            if self.seiten.contains_key(k) || !gesehen.insert(k.clone()) {
+                kollisionen.push(k.clone());
+            }
+        }
+        if !kollisionen.is_empty() {
+            return Err(MergeFehler::SeitenKollision(kollisionen));
+        }
+
+        self.seiten.extend(neu);
+        Ok(())
+    }
+}
+
+/// Wortindex über alle Seiten eines `HocrLayout`, siehe `HocrLayout::build_index`
+#[derive(Debug, Clone, Default)]
+pub struct HocrIndex {
+    /// Treffer indexiert nach normalisiertem Worttext (siehe `normalisiere_suchtext`)
+    worte: BTreeMap<String, Vec<HocrTreffer>>,
+}
+
+/// Fundstelle eines Worts im hOCR
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct HocrTreffer {
+    /// Seitenzahl
+    pub seite: String,
+    /// Koordinaten des Worts in Pixeln (wie im hOCR)
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Rect::is_zero")]
+    pub rect: Rect,
+    /// Wahrscheinlichkeit des Worts, richtig erkannt zu sein
+    pub confidence: f32,
+    /// Erkannter Text
+    pub text: String,
+}
+
+/// Optionen für `HocrIndex::suche_mit`
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct HocrSuchOptionen {
+    /// Groß- / Kleinschreibung beachten
+    pub gross_klein_beachten: bool,
+    /// Auch Worte finden, die den Suchbegriff nur enthalten
+    pub teilwort: bool,
+}
+
+impl HocrLayout {
+    /// Baut einen Wortindex über alle Seiten auf, der wiederholt abgefragt werden kann
+    pub fn build_index(&self) -> HocrIndex {
+        let mut worte: BTreeMap<String, Vec<HocrTreffer>> = BTreeMap::new();
+        let mut seiten = self.seiten.iter().collect::<Vec<_>>();
+        seiten.sort_by_key(|(s, _)| seiten_sortierschluessel(s));
+        for (seite, hocr) in seiten {
+            for word in hocr.parsed.worte() {
+                if word.text.trim().is_empty() {
+                    continue;
+                }
+                worte
+                    .entry(normalisiere_suchtext(&word.text))
+                    .or_default()
+                    .push(HocrTreffer {
+                        seite: seite.clone(),
+                        rect: word.bounds.clone(),
+                        confidence: word.confidence,
+                        text: word.text.clone(),
+                    });
+            }
+        }
+        HocrIndex { worte }
+    }
+}
+
+impl HocrIndex {
+    /// Exakte Suche ohne Beachtung der Groß- / Kleinschreibung und Umlautschreibweise
+    /// (siehe `normalisiere_suchtext`)
+    pub fn suche(&self, wort: &str) -> Vec<HocrTreffer> {
+        self.suche_mit(wort, HocrSuchOptionen::default())
+    }
+
+    /// Suche mit Optionen, Treffer in Seitenreihenfolge
+    pub fn suche_mit(&self, wort: &str, optionen: HocrSuchOptionen) -> Vec<HocrTreffer> {
+        let klein = normalisiere_suchtext(wort);
+        let passt = |t: &HocrTreffer| -> bool {
+            if !optionen.gross_klein_beachten {
+                return true;
+            }
+            if optionen.teilwort {
+                t.text.contains(wort)
+            } else {
+                t.text == wort
+            }
+        };
+
+        let mut treffer = if optionen.teilwort {
+            self.worte
+                .iter()
+                .filter(|(k, _)| k.contains(&klein))
+                .flat_map(|(_, v)| v.iter())
+                .filter(|t| passt(t))
+                .cloned()
+                .collect::<Vec<_>>()
+        } else {
+            self.worte
+                .get(&klein)
+                .map(|v| v.iter().filter(|t| passt(t)).cloned().collect())
+                .unwrap_or_default()
+        };
+
+        treffer.sort_by(|a, b| {
+            seiten_sortierschluessel(&a.seite)
+                .cmp(&seiten_sortierschluessel(&b.seite))
+                .then(a.rect.min_y.total_cmp(&b.rect.min_y))
+                .then(a.rect.min_x.total_cmp(&b.rect.min_x))
+        });
+        treffer
+    }
+}
+
+impl HocrLayout {
+    /// Seiten nach Seitennummer sortiert (statt lexikografisch wie in `seiten`),
+    /// nicht-numerische Schlüssel stehen mit der Nummer `usize::MAX` am Ende
+    pub fn seiten_sortiert(&self) -> Vec<(usize, &HocrSeite)> {
+        sortiert_nach_seite(&self.seiten)
+    }
+
+    /// Iteriert lazy über alle Worte aller Seiten, nach Seitennummer sortiert wie
+    /// `seiten_sortiert`. Nur die Seitenliste wird vorab sortiert, Zeilen und Worte werden
+    /// ohne Zwischenlisten durchlaufen.
+    pub fn iter_worte(&self) -> impl Iterator<Item = WortKontext<'_>> {
+        let mut seiten = self.seiten.iter().collect::<Vec<_>>();
+        seiten.sort_by_key(|(seite, _)| seiten_sortierschluessel(seite));
+        seiten.into_iter().flat_map(|(seite, hocr_seite)| {
+            hocr_seite.parsed.zeilen().flat_map(move |zeile| {
+                zeile.words.iter().map(move |wort| WortKontext {
+                    seite: seite.as_str(),
+                    hocr_seite,
+                    zeile,
+                    wort,
+                })
+            })
+        })
+    }
+}
+
+/// Ein Wort aus `HocrLayout::iter_worte` mit der Seite und der Zeile, in der es steht
+#[derive(Debug, Clone, Copy)]
+pub struct WortKontext<'a> {
+    /// Seitenschlüssel (wie in `HocrLayout::seiten`)
+    pub seite: &'a str,
+    /// Seite des Wortes, z.B. für `HocrSeite::px_to_mm`
+    pub hocr_seite: &'a HocrSeite,
+    /// Zeile, die das Wort enthält
+    pub zeile: &'a HocrLine,
+    /// Das Wort selbst
+    pub wort: &'a HocrWord,
+}
+
+impl PdfFile {
+    /// `anpassungen_seite` nach Seitennummer sortiert, siehe `HocrLayout::seiten_sortiert`
+    pub fn anpassungen_sortiert(&self) -> Vec<(usize, &AnpassungSeite)> {
+        sortiert_nach_seite(&self.anpassungen_seite)
+    }
+}
+
+fn sortiert_nach_seite<T>(map: &BTreeMap<String, T>) -> Vec<(usize, &T)> {
+    let mut seiten = map.iter().collect::<Vec<_>>();
+    seiten.sort_by_key(|(k, _)| seiten_sortierschluessel(k));
+    seiten
+        .into_iter()
+        .map(|(k, v)| (seiten_sortierschluessel(k).0, v))
+        .collect()
+}
+
+/// Sortierschlüssel für Seitenschlüssel: numerische Seiten aufsteigend, nicht-numerische am Ende
+fn seiten_sortierschluessel(seite: &str) -> (usize, String) {
+    (
+        seite.trim().parse::<usize>().unwrap_or(usize::MAX),
+        seite.to_string(),
+    )
 }
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
 pub struct HocrSeite {
     /// Breite der PDF-Seite in Millimeter
+    #[cfg_attr(feature = "round-f32", serde(serialize_with = "round_f32"))]
+    #[serde(alias = "breiteMm")]
     pub breite_mm: f32,
     /// Höhe der PDF-Seite in Millimeter
+    #[cfg_attr(feature = "round-f32", serde(serialize_with = "round_f32"))]
+    #[serde(alias = "hoeheMm")]
     pub hoehe_mm: f32,
     /// Automatisch digitalisierte hOCR-Ausgabe der erkannten Texte auf der Seite
     pub parsed: ParsedHocr,
     /// Rote Linien auf der PDF-Seite
     #[serde(default)]
+    #[serde(alias = "roteLinien")]
     pub rote_linien: Vec<Linie>,
 }
 
+/// Serialisiert einen `f32`-Wert auf 3 Nachkommastellen gerundet, um Ausgaben wie
+/// `12.300000190734863` zu vermeiden (wird mit dem Feature `round-f32` für Koordinaten verwendet)
+pub fn round_f32<S>(wert: &f32, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.serialize_f64((*wert as f64 * 1000.0).round() / 1000.0)
+}
+
+/// Für `skip_serializing_if`: lässt `0.0`-Koordinaten in der Ausgabe weg
+fn ist_null_f32(wert: &f32) -> bool {
+    *wert == 0.0
+}
+
+/// Für `skip_serializing_if` (Feature `skip-zero-lfd-nr`): `lfd_nr == 0` gilt als
+/// "nicht gesetzt" und wird weggelassen
+#[cfg(feature = "skip-zero-lfd-nr")]
+fn ist_null_usize(wert: &usize) -> bool {
+    *wert == 0
+}
+
+/// Millimeter pro Zoll, für die Umrechnung zwischen Pixeln und Millimetern
+const MM_PRO_ZOLL: f32 = 25.4;
+
+impl HocrSeite {
+    /// Leere Seite aus Bildgröße in Pixeln und Auflösung in DPI
+    pub fn from_pixel_dpi(breite_px: u32, hoehe_px: u32, dpi: f32) -> HocrSeite {
+        HocrSeite {
+            breite_mm: breite_px as f32 / dpi * MM_PRO_ZOLL,
+            hoehe_mm: hoehe_px as f32 / dpi * MM_PRO_ZOLL,
+            parsed: ParsedHocr {
+                bounds: Rect {
+                    min_x: 0.0,
+                    min_y: 0.0,
+                    max_x: breite_px as f32,
+                    max_y: hoehe_px as f32,
+                },
+                careas: Vec::new(),
+            },
+            rote_linien: Vec::new(),
+        }
+    }
+}
+
+/// Seitenverhältnis der Seite in mm passt nicht zum Seitenverhältnis der hOCR-Bounds in Pixeln
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct KonsistenzWarnung {
+    /// Breite / Höhe aus `breite_mm` / `hoehe_mm`
+    pub verhaeltnis_mm: f32,
+    /// Breite / Höhe aus `parsed.bounds`
+    pub verhaeltnis_px: f32,
+    /// Relative Abweichung der beiden Verhältnisse (0.05 = 5 %), unendlich bei leeren Maßen
+    pub abweichung: f32,
+}
+
+impl HocrSeite {
+    /// Vergleicht das Seitenverhältnis in mm mit dem der hOCR-Bounds in Pixeln, warnt wenn die
+    /// relative Abweichung größer als `max_abweichung` ist (z.B. 0.02 für 2 %), was meist auf
+    /// eine falsche DPI-Annahme hindeutet
+    pub fn pruefe_konsistenz(&self, max_abweichung: f32) -> Option<KonsistenzWarnung> {
+        let bounds = self.parsed.bounds.normalized();
+        let verhaeltnis_mm = self.breite_mm / self.hoehe_mm;
+        let verhaeltnis_px = bounds.width() / bounds.height();
+
+        if !verhaeltnis_mm.is_finite()
+            || !verhaeltnis_px.is_finite()
+            || verhaeltnis_mm <= 0.0
+            || verhaeltnis_px <= 0.0
+        {
+            return Some(KonsistenzWarnung {
+                verhaeltnis_mm,
+                verhaeltnis_px,
+                abweichung: f32::INFINITY,
+            });
+        }
+
+        let abweichung = (verhaeltnis_mm - verhaeltnis_px).abs() / verhaeltnis_px;
+        if abweichung > max_abweichung {
+            Some(KonsistenzWarnung {
+                verhaeltnis_mm,
+                verhaeltnis_px,
+                abweichung,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+/// Drehung einer Seite im Uhrzeigersinn, siehe `HocrSeite::rotiere`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Rotation {
+    /// Vierteldrehung nach rechts
+    Grad90,
+    /// Seite steht auf dem Kopf
+    Grad180,
+    /// Vierteldrehung nach links
+    Grad270,
+}
+
+impl Rotation {
+    /// Dreht den Punkt innerhalb eines Bereichs `breite` x `hoehe` mit Ursprung oben links,
+    /// der Bereich hat danach bei 90° / 270° die Maße `hoehe` x `breite`
+    fn drehe(self, x: f32, y: f32, breite: f32, hoehe: f32) -> (f32, f32) {
+        match self {
+            Rotation::Grad90 => (hoehe - y, x),
+            Rotation::Grad180 => (breite - x, hoehe - y),
+            Rotation::Grad270 => (y, breite - x),
+        }
+    }
+}
+
+impl HocrSeite {
+    /// Dreht die Seite im Uhrzeigersinn: alle hOCR-Bounds (in Pixeln, relativ zu `parsed.bounds`)
+    /// und die roten Linien (in mm) werden transformiert, bei 90° / 270° werden `breite_mm` und
+    /// `hoehe_mm` getauscht. 90° und 270° heben sich gegenseitig auf, ebenso zweimal 180°.
+    pub fn rotiere(&mut self, grad: Rotation) {
+        let bounds = self.parsed.bounds.normalized();
+        let (ox, oy) = (bounds.min_x, bounds.min_y);
+        let (breite_px, hoehe_px) = (bounds.width(), bounds.height());
+        let (breite_mm, hoehe_mm) = (self.breite_mm, self.hoehe_mm);
+
+        self.transformiere(
+            |x, y| {
+                let (x, y) = grad.drehe(x - ox, y - oy, breite_px, hoehe_px);
+                (x + ox, y + oy)
+            },
+            |x, y| grad.drehe(x, y, breite_mm, hoehe_mm),
+        );
+
+        if grad != Rotation::Grad180 {
+            std::mem::swap(&mut self.breite_mm, &mut self.hoehe_mm);
+        }
+    }
+
+    /// Spiegelt die Seite an der senkrechten Mittelachse (links / rechts vertauscht),
+    /// zweimaliges Spiegeln ergibt wieder die ursprüngliche Seite
+    pub fn spiegele_horizontal(&mut self) {
+        let bounds = self.parsed.bounds.normalized();
+        let (min_x, max_x) = (bounds.min_x, bounds.max_x);
+        let breite_mm = self.breite_mm;
+
+        self.transformiere(|x, y| (min_x + max_x - x, y), |x, y| (breite_mm - x, y));
+    }
+
+    /// Wendet `px` auf alle hOCR-Bounds und `mm` auf alle Punkte der roten Linien an,
+    /// leere Rechtecke (`Rect::is_zero`) bleiben unverändert
+    fn transformiere(
+        &mut self,
+        px: impl Fn(f32, f32) -> (f32, f32),
+        mm: impl Fn(f32, f32) -> (f32, f32),
+    ) {
+        let rect = |r: &mut Rect| {
+            if r.is_zero() {
+                return;
+            }
+            let (ax, ay) = px(r.min_x, r.min_y);
+            let (bx, by) = px(r.max_x, r.max_y);
+            *r = Rect {
+                min_x: ax.min(bx),
+                min_y: ay.min(by),
+                max_x: ax.max(bx),
+                max_y: ay.max(by),
+            };
+        };
+
+        rect(&mut self.parsed.bounds);
+        for carea in self.parsed.careas.iter_mut() {
+            rect(&mut carea.bounds);
+            for paragraph in carea.paragraphs.iter_mut() {
+                rect(&mut paragraph.bounds);
+                for line in paragraph.lines.iter_mut() {
+                    rect(&mut line.bounds);
+                    for word in line.words.iter_mut() {
+                        rect(&mut word.bounds);
+                    }
+                }
+            }
+        }
+
+        for punkt in self
+            .rote_linien
+            .iter_mut()
+            .flat_map(|l| l.punkte.iter_mut())
+        {
+            let (x, y) = mm(punkt.x, punkt.y);
+            punkt.x = x;
+            punkt.y = y;
+        }
+    }
+}
+
 /// Definition für eine rote Linie mit n Punkten auf der PDF-Seite
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
 pub struct Linie {
     /// Punkte der Linie auf der Seite
     pub punkte: Vec<Punkt>,
 }
 
+/// Maximaler Abstand in mm zwischen erstem und letztem Punkt, bis zu dem eine Linie für
+/// `Linie::enthaelt_punkt` als geschlossen gilt
+pub const LINIE_GESCHLOSSEN_TOLERANZ_MM: f32 = 2.0;
+
+impl Linie {
+    /// Ob der Linienzug eine Fläche umschließt (mindestens 3 Punkte, erster und letzter Punkt
+    /// höchstens `toleranz` voneinander entfernt)
+    pub fn ist_geschlossen(&self, toleranz: f32) -> bool {
+        match (self.punkte.first(), self.punkte.last()) {
+            (Some(a), Some(b)) if self.punkte.len() >= 3 => {
+                (a.x - b.x).hypot(a.y - b.y) <= toleranz
+            }
+            _ => false,
+        }
+    }
+
+    /// Ob der Punkt innerhalb des geschlossenen Linienzugs liegt (Ray-Casting),
+    /// offene Linien (siehe `LINIE_GESCHLOSSEN_TOLERANZ_MM`) enthalten keine Punkte
+    pub fn enthaelt_punkt(&self, p: &Punkt) -> bool {
+        if !self.ist_geschlossen(LINIE_GESCHLOSSEN_TOLERANZ_MM) {
+            return false;
+        }
+        let mut innen = false;
+        let n = self.punkte.len();
+        for i in 0..n {
+            let a = &self.punkte[i];
+            let b = &self.punkte[(i + 1) % n];
+            if (a.y > p.y) != (b.y > p.y) {
+                let x = a.x + (p.y - a.y) / (b.y - a.y) * (b.x - a.x);
+                if p.x < x {
+                    innen = !innen;
+                }
+            }
+        }
+        innen
+    }
+}
+
 /// Generelle Punkt-Definition
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
 pub struct Punkt {
     /// X-Koordinate in Millimeter vom oberen Rand
+    #[serde(default)]
+    #[serde(skip_serializing_if = "ist_null_f32")]
+    #[cfg_attr(feature = "round-f32", serde(serialize_with = "round_f32"))]
     pub x: f32,
     /// Y-Koordinate in Millimeter vom linken Rand
+    #[serde(default)]
+    #[serde(skip_serializing_if = "ist_null_f32")]
+    #[cfg_attr(feature = "round-f32", serde(serialize_with = "round_f32"))]
     pub y: f32,
 }
 
 /// hOCR Ausgabe
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
 pub struct ParsedHocr {
     /// Bildkoordinaten in Pixeln
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Rect::is_zero")]
     pub bounds: Rect,
     /// Content-Areas (carea) im hOCR XML (Koordinaten in Pixeln)
     pub careas: Vec<HocrArea>,
 }
 
+impl ParsedHocr {
+    /// Nur die Worte, deren `bounds` den Bereich (in Pixeln) überlappen, die Hierarchie
+    /// careas / paragraphs / lines bleibt erhalten, leere Container werden entfernt
+    pub fn crop(&self, bereich: &Rect) -> ParsedHocr {
+        let bereich = bereich.normalized();
+        let careas = self
+            .careas
+            .iter()
+            .filter_map(|carea| {
+                let paragraphs = carea
+                    .paragraphs
+                    .iter()
+                    .filter_map(|par| {
+                        let lines = par
+                            .lines
+                            .iter()
+                            .filter_map(|line| {
+                                let words = line
+                                    .words
+                                    .iter()
+                                    .filter(|w| w.bounds.intersection(&bereich).is_some())
+                                    .cloned()
+                                    .collect::<Vec<_>>();
+                                if words.is_empty() {
+                                    return None;
+                                }
+                                Some(HocrLine {
+                                    bounds: line.bounds.clone(),
+                                    words,
+                                })
+                            })
+                            .collect::<Vec<_>>();
+                        if lines.is_empty() {
+                            return None;
+                        }
+                        Some(HocrParagraph {
+                            bounds: par.bounds.clone(),
+                            lines,
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                if paragraphs.is_empty() {
+                    return None;
+                }
+                Some(HocrArea {
+                    bounds: carea.bounds.clone(),
+                    paragraphs,
+                })
+            })
+            .collect();
+
+        ParsedHocr {
+            bounds: bereich,
+            careas,
+        }
+    }
+
+    /// Begrenzt alle Wort-, Zeilen-, Absatz- und carea-Bounds auf `bounds` der Seite und
+    /// berechnet die Container-Bounds anschließend aus ihrem Inhalt neu
+    pub fn clamp_to_bounds(&mut self) {
+        self.clamp_to_bounds_mit(false);
+    }
+
+    /// Wie `clamp_to_bounds`, mit `ausserhalb_entfernen` werden Worte, die komplett außerhalb
+    /// der Seite liegen, entfernt (und dadurch leere Container ebenfalls)
+    pub fn clamp_to_bounds_mit(&mut self, ausserhalb_entfernen: bool) {
+        fn umschliessend<'a>(rects: impl Iterator<Item = &'a Rect>) -> Option<Rect> {
+            rects.fold(None, |acc: Option<Rect>, r| match acc {
+                Some(a) => Some(a.union(r)),
+                None => Some(r.normalized()),
+            })
+        }
+
+        let seite = self.bounds.normalized();
+        for carea in self.careas.iter_mut() {
+            for par in carea.paragraphs.iter_mut() {
+                for line in par.lines.iter_mut() {
+                    if ausserhalb_entfernen {
+                        line.words
+                            .retain(|w| w.bounds.intersection(&seite).is_some());
+                    }
+                    for word in line.words.iter_mut() {
+                        word.bounds = word.bounds.clamped_to(&seite);
+                    }
+                    line.bounds = umschliessend(line.words.iter().map(|w| &w.bounds))
+                        .unwrap_or_else(|| line.bounds.clamped_to(&seite));
+                }
+                if ausserhalb_entfernen {
+                    par.lines.retain(|l| !l.words.is_empty());
+                }
+                par.bounds = umschliessend(par.lines.iter().map(|l| &l.bounds))
+                    .unwrap_or_else(|| par.bounds.clamped_to(&seite));
+            }
+            if ausserhalb_entfernen {
+                carea.paragraphs.retain(|p| !p.lines.is_empty());
+            }
+            carea.bounds = umschliessend(carea.paragraphs.iter().map(|p| &p.bounds))
+                .unwrap_or_else(|| carea.bounds.clamped_to(&seite));
+        }
+        if ausserhalb_entfernen {
+            self.careas.retain(|c| !c.paragraphs.is_empty());
+        }
+    }
+
+    /// Wie `crop`, verschiebt aber alle Koordinaten relativ zur oberen linken Ecke des Bereichs
+    pub fn crop_and_translate(&self, bereich: &Rect) -> ParsedHocr {
+        let bereich = bereich.normalized();
+        let (dx, dy) = (-bereich.min_x, -bereich.min_y);
+        let mut cropped = self.crop(&bereich);
+        cropped.bounds = cropped.bounds.translated(dx, dy);
+        for carea in cropped.careas.iter_mut() {
+            carea.bounds = carea.bounds.translated(dx, dy);
+            for par in carea.paragraphs.iter_mut() {
+                par.bounds = par.bounds.translated(dx, dy);
+                for line in par.lines.iter_mut() {
+                    line.bounds = line.bounds.translated(dx, dy);
+                    for word in line.words.iter_mut() {
+                        word.bounds = word.bounds.translated(dx, dy);
+                    }
+                }
+            }
+        }
+        cropped
+    }
+}
+
 /// hOCR-carea
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
 pub struct HocrArea {
     /// Koordinaten der carea relativ zur oberen linken Ecke, Angaben in Pixeln
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Rect::is_zero")]
     pub bounds: Rect,
     /// paragraph-Nodes der hOCR-Datei
     pub paragraphs: Vec<HocrParagraph>,
@@ -83,8 +1104,12 @@ pub struct HocrArea {
 
 /// Absatz innerhalb einer Seite
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
 pub struct HocrParagraph {
     /// Koordinaten des Absatzes in Pixeln von der oberen linken Ecke im Bild
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Rect::is_zero")]
     pub bounds: Rect,
     /// Zeilen innerhalb des Absatzes
     pub lines: Vec<HocrLine>,
@@ -92,8 +1117,12 @@ pub struct HocrParagraph {
 
 /// Zeile im hOCR-Absatz
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
 pub struct HocrLine {
     /// Koordinaten der Zeile in Pixeln von der oberen linken Ecke im Bild
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Rect::is_zero")]
     pub bounds: Rect,
     /// Worte innerhalb dieser Zeile
     pub words: Vec<HocrWord>,
@@ -101,52 +1130,710 @@ pub struct HocrLine {
 
 /// hOCR-erkanntes Wort im Bild
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
 pub struct HocrWord {
     /// Koordinaten des Worts in Pixeln von der oberen linken Ecke im Bild
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Rect::is_zero")]
     pub bounds: Rect,
-    /// Wahrscheinlichkeit des Worts, richtig erkannt zu sein
+    /// Wahrscheinlichkeit des Worts, richtig erkannt zu sein (0.0 - 1.0, Prozentwerte werden
+    /// beim Einlesen umgerechnet, siehe `confidence_normalize`)
+    #[serde(deserialize_with = "confidence_normalize")]
     pub confidence: f32,
     /// Erkannter Text
     pub text: String,
 }
 
-/// Benutzerdefinierte Anpassungen der Seite
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
-pub struct AnpassungSeite {
-    /// Überschreibt den automatisch erkannten SeitenTyp
-    #[serde(default)]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub klassifikation_neu: Option<SeitenTyp>,
-    /// Überschreibt die Dimensionen der automatisch erkannten Spalten (indexiert nach Spalten-ID)
-    #[serde(default)]
-    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
-    pub spalten: BTreeMap<String, Rect>,
-    /// Manuell eingefügte Zeilen
-    #[serde(default)]
-    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
-    pub zeilen: BTreeMap<String, f32>,
-    /// Automatisch eingefügte Zeilen
-    #[serde(default)]
-    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
-    pub zeilen_auto: BTreeMap<String, f32>,
+/// Deserialisiert eine einzelne Konfidenz, die entweder als Bruch (0.0 - 1.0) oder als
+/// Prozentwert (0 - 100) vorliegt: Werte > 1.0 werden durch 100 geteilt, das Ergebnis auf
+/// 0.0 - 1.0 begrenzt. Wird beim Einlesen von `HocrWord::confidence` verwendet, die Skala
+/// wird dabei je Wert geraten (1 % wird zu 1.0). Für im Speicher aufgebaute Seiten mit
+/// Prozentwerten gibt es `ParsedHocr::normalisiere_confidence`.
+pub fn confidence_normalize<'de, D>(deserializer: D) -> Result<f32, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let wert = f32::deserialize(deserializer)?;
+    if wert.is_nan() {
+        return Err(serde::de::Error::custom("ungültige Konfidenz: NaN"));
+    }
+    let wert = if wert > 1.0 { wert / 100.0 } else { wert };
+    Ok(wert.clamp(0.0, 1.0))
 }
 
-/// Generelle Struktur für ein Rechteck (üblicherweise Koordinaten in Millimeter von oberer linker Ecke)
-#[derive(Debug, Clone, Default, PartialEq, PartialOrd, Serialize, Deserialize)]
-pub struct Rect {
-    /// Minimum-X-Koordinate des Rechtecks
-    pub min_x: f32,
-    /// Minimum-Y-Koordinate des Rechtecks
+/// Skala, in der die `confidence`-Werte einer hOCR-Ausgabe angegeben sind
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ConfidenceSkala {
+    /// 0.0 - 1.0
+    Bruch,
+    /// 0 - 100
+    Prozent,
+}
+
+impl ParsedHocr {
+    /// Alle Zeilen der Seite (in der Reihenfolge careas / paragraphs / lines des hOCR)
+    pub fn zeilen(&self) -> impl Iterator<Item = &HocrLine> {
+        self.careas
+            .iter()
+            .flat_map(|c| c.paragraphs.iter())
+            .flat_map(|p| p.lines.iter())
+    }
+
+    /// Alle Worte der Seite (in der Reihenfolge careas / paragraphs / lines des hOCR)
+    pub fn worte(&self) -> impl Iterator<Item = &HocrWord> {
+        self.zeilen().flat_map(|l| l.words.iter())
+    }
+
+    /// Wie `worte`, mit veränderbaren Worten
+    pub fn worte_mut(&mut self) -> impl Iterator<Item = &mut HocrWord> {
+        self.careas
+            .iter_mut()
+            .flat_map(|c| c.paragraphs.iter_mut())
+            .flat_map(|p| p.lines.iter_mut())
+            .flat_map(|l| l.words.iter_mut())
+    }
+
+    /// Erkennt heuristisch die Skala der `confidence`-Werte noch nicht normalisierter Daten
+    /// (z.B. direkt aus einer OCR-Ausgabe aufgebaut): sobald ein Wort eine Konfidenz > 1.0
+    /// hat, gelten die Werte als Prozent, `None` wenn keine Worte vorhanden sind. Eingelesene
+    /// Daten sind bereits normalisiert und ergeben immer `Bruch`.
+    pub fn confidence_skala_erkennen(&self) -> Option<ConfidenceSkala> {
+        let mut confidences = self.worte().map(|w| w.confidence).peekable();
+        confidences.peek()?;
+        if confidences.any(|c| c > 1.0) {
+            Some(ConfidenceSkala::Prozent)
+        } else {
+            Some(ConfidenceSkala::Bruch)
+        }
+    }
+
+    /// Erkennt die Skala aller `confidence`-Werte der Seite (`confidence_skala_erkennen`),
+    /// rechnet Prozentwerte in Brüche um und begrenzt alle Werte auf 0.0 - 1.0. Gibt die
+    /// erkannte Skala zurück (`None` ohne Worte).
+    pub fn normalisiere_confidence(&mut self) -> Option<ConfidenceSkala> {
+        let skala = self.confidence_skala_erkennen()?;
+        let faktor = match skala {
+            ConfidenceSkala::Bruch => 1.0,
+            ConfidenceSkala::Prozent => 0.01,
+        };
+        for word in self.worte_mut() {
+            word.confidence = (word.confidence * faktor).clamp(0.0, 1.0);
+        }
+        Some(skala)
+    }
+}
+
+/// Welche Grenzen `HocrWord::split_tokens_mit` als Trennstelle verwendet
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TokenGrenzen {
+    /// Trennen zwischen Ziffern und Buchstaben ("Flur123" → "Flur", "123")
+    pub ziffer_buchstabe: bool,
+    /// Satzzeichen als eigene Tokens abtrennen (Punkt / Komma innerhalb von Zahlen wie
+    /// "1.234,56" bleiben erhalten)
+    pub satzzeichen: bool,
+}
+
+impl Default for TokenGrenzen {
+    fn default() -> Self {
+        TokenGrenzen {
+            ziffer_buchstabe: true,
+            satzzeichen: false,
+        }
+    }
+}
+
+impl HocrWord {
+    /// Zerlegt das Wort an Ziffern / Buchstaben-Grenzen (siehe `TokenGrenzen::default`)
+    pub fn split_tokens(&self) -> Vec<HocrWord> {
+        self.split_tokens_mit(TokenGrenzen::default())
+    }
+
+    /// Zerlegt das Wort an den gewählten Grenzen, die `bounds` werden proportional zur
+    /// Zeichenanzahl aufgeteilt, die `confidence` wird vom Original übernommen
+    pub fn split_tokens_mit(&self, grenzen: TokenGrenzen) -> Vec<HocrWord> {
+        #[derive(PartialEq, Copy, Clone)]
+        enum Klasse {
+            Ziffer,
+            Buchstabe,
+            Sonstiges,
+        }
+
+        let zeichen = self.text.chars().collect::<Vec<_>>();
+        let klasse = |i: usize| -> Klasse {
+            let c = zeichen[i];
+            if c.is_ascii_digit() {
+                Klasse::Ziffer
+            } else if (c == '.' || c == ',')
+                && i > 0
+                && i + 1 < zeichen.len()
+                && zeichen[i - 1].is_ascii_digit()
+                && zeichen[i + 1].is_ascii_digit()
+            {
+                // Tausender- / Dezimaltrennzeichen innerhalb einer Zahl
+                Klasse::Ziffer
+            } else if c.is_alphabetic() {
+                Klasse::Buchstabe
+            } else {
+                Klasse::Sonstiges
+            }
+        };
+
+        let mut tokens: Vec<(usize, usize)> = Vec::new();
+        let mut start = 0;
+        for i in 1..zeichen.len() {
+            let (a, b) = (klasse(i - 1), klasse(i));
+            let trennen = match (a, b) {
+                (Klasse::Ziffer, Klasse::Buchstabe) | (Klasse::Buchstabe, Klasse::Ziffer) => {
+                    grenzen.ziffer_buchstabe
+                }
+                (Klasse::Sonstiges, Klasse::Sonstiges) => false,
+                (Klasse::Sonstiges, _) | (_, Klasse::Sonstiges) => grenzen.satzzeichen,
+                _ => false,
+            };
+            if trennen {
+                tokens.push((start, i));
+                start = i;
+            }
+        }
+        tokens.push((start, zeichen.len()));
+
+        if tokens.len() < 2 {
+            return vec![self.clone()];
+        }
+
+        let bounds = self.bounds.normalized();
+        let breite_pro_zeichen = bounds.width() / zeichen.len() as f32;
+        tokens
+            .into_iter()
+            .map(|(von, bis)| HocrWord {
+                bounds: Rect {
+                    min_x: bounds.min_x + von as f32 * breite_pro_zeichen,
+                    min_y: bounds.min_y,
+                    max_x: bounds.min_x + bis as f32 * breite_pro_zeichen,
+                    max_y: bounds.max_y,
+                },
+                confidence: self.confidence,
+                text: zeichen[von..bis].iter().collect(),
+            })
+            .collect()
+    }
+}
+
+/// Benutzerdefinierte Anpassungen der Seite
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
+pub struct AnpassungSeite {
+    /// Überschreibt den automatisch erkannten SeitenTyp
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "klassifikationNeu")]
+    pub klassifikation_neu: Option<SeitenTyp>,
+    /// Überschreibt die Dimensionen der automatisch erkannten Spalten (indexiert nach Spalten-ID)
+    #[serde(default)]
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub spalten: BTreeMap<String, Rect>,
+    /// Manuell eingefügte Zeilen
+    #[serde(default)]
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub zeilen: BTreeMap<String, f32>,
+    /// Automatisch eingefügte Zeilen
+    #[serde(default)]
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    #[serde(alias = "zeilenAuto")]
+    pub zeilen_auto: BTreeMap<String, f32>,
+}
+
+/// Toleranz in Millimeter, innerhalb der zwei Zeilen als dieselbe Trennlinie gelten
+pub const ZEILEN_TOLERANZ_MM: f32 = 1.0;
+
+impl AnpassungSeite {
+    /// Paare von Spalten-IDs, deren Rechtecke sich stärker als `toleranz` überlappen
+    /// (`Rect::intersection_over_union` > `toleranz`), nach IDs sortiert
+    pub fn pruefe_spalten_ueberlappung(&self, toleranz: f32) -> Vec<(String, String)> {
+        let spalten = self.spalten.iter().collect::<Vec<_>>();
+        let mut paare = Vec::new();
+        for (i, (id_a, a)) in spalten.iter().enumerate() {
+            for (id_b, b) in spalten.iter().skip(i + 1) {
+                if a.intersection_over_union(b) > toleranz {
+                    paare.push((id_a.to_string(), id_b.to_string()));
+                }
+            }
+        }
+        paare
+    }
+
+    /// IDs der Spalten, die vollständig außerhalb der Seite (Maße in mm) liegen
+    pub fn spalten_ausserhalb_der_seite(&self, breite_mm: f32, hoehe_mm: f32) -> Vec<String> {
+        let seite = Rect {
+            min_x: 0.0,
+            min_y: 0.0,
+            max_x: breite_mm,
+            max_y: hoehe_mm,
+        };
+        self.spalten
+            .iter()
+            .filter(|(_, r)| !r.overlaps(&seite))
+            .map(|(id, _)| id.clone())
+            .collect()
+    }
+
+    /// Manuelle und automatische Zeilen als sortierte Liste von y-Werten,
+    /// nahe beieinanderliegende Werte werden zusammengefasst (manuelle Zeilen haben Vorrang)
+    pub fn alle_zeilen_sortiert(&self) -> Vec<f32> {
+        let mut manuell = self.zeilen.values().copied().collect::<Vec<_>>();
+        manuell.sort_by(|a, b| a.total_cmp(b));
+
+        let mut zeilen: Vec<f32> = Vec::new();
+        for y in manuell {
+            if !zeilen.iter().any(|z| (z - y).abs() <= ZEILEN_TOLERANZ_MM) {
+                zeilen.push(y);
+            }
+        }
+
+        let mut auto = self.zeilen_auto.values().copied().collect::<Vec<_>>();
+        auto.sort_by(|a, b| a.total_cmp(b));
+
+        for y in auto {
+            if !zeilen.iter().any(|z| (z - y).abs() <= ZEILEN_TOLERANZ_MM) {
+                zeilen.push(y);
+            }
+        }
+
+        zeilen.sort_by(|a, b| a.total_cmp(b));
+        zeilen
+    }
+
+    /// Tabellenraster der Seite (Maße `(breite, hoehe)` in mm): die Zeilen aus
+    /// `alle_zeilen_sortiert` teilen die Seite in Zeilenbereiche (Index 0 = vom oberen Seitenrand
+    /// bis zur ersten Zeile), jede Zelle ist die Schnittmenge einer Spalte mit einem Zeilenbereich.
+    /// Der Zeilenindex ist damit über alle Spalten gleich, leere Schnittmengen werden ausgelassen.
+    pub fn zellen(&self, seitenmasse: (f32, f32)) -> BTreeMap<(String, usize), Rect> {
+        let (breite, hoehe) = seitenmasse;
+        let mut grenzen = vec![0.0];
+        grenzen.extend(
+            self.alle_zeilen_sortiert()
+                .into_iter()
+                .filter(|y| *y > 0.0 && *y < hoehe),
+        );
+        grenzen.push(hoehe);
+
+        let mut zellen = BTreeMap::new();
+        for (id, spalte) in self.spalten.iter() {
+            let spalte = spalte.normalized();
+            let min_x = spalte.min_x.max(0.0);
+            let max_x = spalte.max_x.min(breite);
+            if min_x >= max_x {
+                continue;
+            }
+            for (i, bereich) in grenzen.windows(2).enumerate() {
+                let min_y = spalte.min_y.max(bereich[0]);
+                let max_y = spalte.max_y.min(bereich[1]);
+                if min_y >= max_y {
+                    continue;
+                }
+                zellen.insert(
+                    (id.clone(), i),
+                    Rect {
+                        min_x,
+                        min_y,
+                        max_x,
+                        max_y,
+                    },
+                );
+            }
+        }
+        zellen
+    }
+
+    /// Fügt eine Zeile bei `y` ein (in `zeilen` wenn `manuell`, sonst in `zeilen_auto`) und gibt
+    /// die neue ID zurück. IDs sind null-aufgefüllte Zähler ("00000001"), eindeutig über beide
+    /// Maps und damit als String sortierbar.
+    pub fn neue_zeile(&mut self, y: f32, manuell: bool) -> String {
+        let mut n = self
+            .zeilen
+            .keys()
+            .chain(self.zeilen_auto.keys())
+            .filter_map(|k| k.parse::<usize>().ok())
+            .max()
+            .map(|n| n + 1)
+            .unwrap_or(1);
+
+        let mut id = format!("{n:08}");
+        while self.zeilen.contains_key(&id) || self.zeilen_auto.contains_key(&id) {
+            n += 1;
+            id = format!("{n:08}");
+        }
+
+        if manuell {
+            self.zeilen.insert(id.clone(), y);
+        } else {
+            self.zeilen_auto.insert(id.clone(), y);
+        }
+        id
+    }
+
+    /// Entfernt die Zeile mit der ID aus `zeilen` bzw. `zeilen_auto`, gibt den y-Wert zurück
+    pub fn entferne_zeile(&mut self, id: &str) -> Option<f32> {
+        let manuell = self.zeilen.remove(id);
+        let auto = self.zeilen_auto.remove(id);
+        manuell.or(auto)
+    }
+}
+
+/// Generelle Struktur für ein Rechteck (üblicherweise Koordinaten in Millimeter von oberer linker Ecke)
+///
+/// Mit dem Feature `rect-as-array` wird das Rechteck kompakt als `[min_x, min_y, max_x, max_y]`
+/// serialisiert, eingelesen werden immer beide Formate.
+#[derive(Debug, Clone, Default, PartialEq, PartialOrd, Serialize)]
+#[cfg_attr(feature = "rect-as-array", serde(into = "RectArray"))]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
+pub struct Rect {
+    /// Minimum-X-Koordinate des Rechtecks
+    #[cfg_attr(feature = "round-f32", serde(serialize_with = "round_f32"))]
+    pub min_x: f32,
+    /// Minimum-Y-Koordinate des Rechtecks
+    #[cfg_attr(feature = "round-f32", serde(serialize_with = "round_f32"))]
     pub min_y: f32,
     /// Maximum-X-Koordinate des Rechtecks
+    #[cfg_attr(feature = "round-f32", serde(serialize_with = "round_f32"))]
     pub max_x: f32,
     /// Maximum-Y-Koordinate des Rechtecks
+    #[cfg_attr(feature = "round-f32", serde(serialize_with = "round_f32"))]
     pub max_y: f32,
 }
 
+/// Schlüssel im Objektformat von `Rect`, unbekannte Schlüssel werden ignoriert
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "snake_case")]
+enum RectFeld {
+    #[serde(alias = "minX")]
+    MinX,
+    #[serde(alias = "minY")]
+    MinY,
+    #[serde(alias = "maxX")]
+    MaxX,
+    #[serde(alias = "maxY")]
+    MaxY,
+    #[serde(other)]
+    Unbekannt,
+}
+
+struct RectVisitor;
+
+impl<'de> serde::de::Visitor<'de> for RectVisitor {
+    type Value = Rect;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("ein Rechteck als Objekt oder als Array [min_x, min_y, max_x, max_y]")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Rect, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        use serde::de::Error;
+
+        let mut werte = [0.0_f32; 4];
+        for (i, wert) in werte.iter_mut().enumerate() {
+            *wert = seq
+                .next_element()?
+                .ok_or_else(|| A::Error::invalid_length(i, &self))?;
+        }
+        if seq.next_element::<serde::de::IgnoredAny>()?.is_some() {
+            return Err(A::Error::invalid_length(5, &self));
+        }
+        let [min_x, min_y, max_x, max_y] = werte;
+        Ok(Rect {
+            min_x,
+            min_y,
+            max_x,
+            max_y,
+        })
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Rect, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        use serde::de::Error;
+
+        let (mut min_x, mut min_y, mut max_x, mut max_y) = (None, None, None, None);
+        while let Some(feld) = map.next_key()? {
+            match feld {
+                RectFeld::MinX => min_x = Some(map.next_value()?),
+                RectFeld::MinY => min_y = Some(map.next_value()?),
+                RectFeld::MaxX => max_x = Some(map.next_value()?),
+                RectFeld::MaxY => max_y = Some(map.next_value()?),
+                RectFeld::Unbekannt => {
+                    map.next_value::<serde::de::IgnoredAny>()?;
+                }
+            }
+        }
+        Ok(Rect {
+            min_x: min_x.ok_or_else(|| A::Error::missing_field("min_x"))?,
+            min_y: min_y.ok_or_else(|| A::Error::missing_field("min_y"))?,
+            max_x: max_x.ok_or_else(|| A::Error::missing_field("max_x"))?,
+            max_y: max_y.ok_or_else(|| A::Error::missing_field("max_y"))?,
+        })
+    }
+}
+
+/// Liest ein `Rect` als Array `[min_x, min_y, max_x, max_y]` oder als Objekt (snake_case oder
+/// camelCase, alle vier Koordinaten sind Pflichtfelder)
+impl<'de> Deserialize<'de> for Rect {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(RectVisitor)
+    }
+}
+
+/// Array-Format von `Rect` beim Serialisieren (Feature `rect-as-array`)
+#[cfg(feature = "rect-as-array")]
+struct RectArray([f32; 4]);
+
+#[cfg(feature = "rect-as-array")]
+impl From<Rect> for RectArray {
+    fn from(r: Rect) -> RectArray {
+        RectArray([r.min_x, r.min_y, r.max_x, r.max_y])
+    }
+}
+
+#[cfg(feature = "rect-as-array")]
+impl Serialize for RectArray {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeTuple;
+
+        let mut tuple = serializer.serialize_tuple(4)?;
+        for wert in self.0 {
+            #[cfg(feature = "round-f32")]
+            tuple.serialize_element(&((wert as f64 * 1000.0).round() / 1000.0))?;
+            #[cfg(not(feature = "round-f32"))]
+            tuple.serialize_element(&wert)?;
+        }
+        tuple.end()
+    }
+}
+
+/// Objektformat von `Rect` für das JSON Schema
+#[cfg(feature = "schema")]
+#[derive(schemars::JsonSchema)]
+#[allow(dead_code)]
+struct RectObjekt {
+    /// Minimum-X-Koordinate des Rechtecks
+    min_x: f32,
+    /// Minimum-Y-Koordinate des Rechtecks
+    min_y: f32,
+    /// Maximum-X-Koordinate des Rechtecks
+    max_x: f32,
+    /// Maximum-Y-Koordinate des Rechtecks
+    max_y: f32,
+}
+
+/// Beide Eingabeformate: Objekt oder Array `[min_x, min_y, max_x, max_y]`
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for Rect {
+    fn schema_name() -> String {
+        "Rect".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        schema_any_of(vec![
+            RectObjekt::json_schema(gen),
+            <[f32; 4]>::json_schema(gen),
+        ])
+    }
+}
+
+impl Rect {
+    /// Ob alle Koordinaten 0 sind (leeres / nicht gesetztes Rechteck)
+    pub fn is_zero(&self) -> bool {
+        self.min_x == 0.0 && self.min_y == 0.0 && self.max_x == 0.0 && self.max_y == 0.0
+    }
+
+    /// Kopie des Rechtecks, bei der `min_x <= max_x` und `min_y <= max_y` gilt
+    pub fn normalized(&self) -> Rect {
+        Rect {
+            min_x: self.min_x.min(self.max_x),
+            min_y: self.min_y.min(self.max_y),
+            max_x: self.min_x.max(self.max_x),
+            max_y: self.min_y.max(self.max_y),
+        }
+    }
+
+    /// Tauscht die Koordinaten so, dass `min_x <= max_x` und `min_y <= max_y` gilt
+    pub fn normalize(&mut self) {
+        *self = self.normalized();
+    }
+
+    /// Ob `min_x > max_x` oder `min_y > max_y` gilt
+    pub fn ist_invertiert(&self) -> bool {
+        self.min_x > self.max_x || self.min_y > self.max_y
+    }
+
+    /// Ob das Rechteck endliche Koordinaten hat und nicht invertiert ist
+    pub fn is_valid(&self) -> bool {
+        self.min_x.is_finite()
+            && self.min_y.is_finite()
+            && self.max_x.is_finite()
+            && self.max_y.is_finite()
+            && self.min_x <= self.max_x
+            && self.min_y <= self.max_y
+    }
+
+    /// Breite des (normalisierten) Rechtecks
+    pub fn width(&self) -> f32 {
+        (self.max_x - self.min_x).abs()
+    }
+
+    /// Höhe des (normalisierten) Rechtecks
+    pub fn height(&self) -> f32 {
+        (self.max_y - self.min_y).abs()
+    }
+
+    /// Fläche des (normalisierten) Rechtecks
+    pub fn area(&self) -> f32 {
+        self.width() * self.height()
+    }
+
+    /// Mittelpunkt des Rechtecks als (x, y)
+    pub fn center(&self) -> (f32, f32) {
+        (
+            (self.min_x + self.max_x) / 2.0,
+            (self.min_y + self.max_y) / 2.0,
+        )
+    }
+
+    /// Ob der Punkt (x, y) innerhalb des (normalisierten) Rechtecks liegt (Ränder inklusive)
+    pub fn contains(&self, x: f32, y: f32) -> bool {
+        let r = self.normalized();
+        x >= r.min_x && x <= r.max_x && y >= r.min_y && y <= r.max_y
+    }
+
+    /// Schnittmenge der beiden (normalisierten) Rechtecke, `None` wenn sie sich nicht berühren
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        let a = self.normalized();
+        let b = other.normalized();
+        let r = Rect {
+            min_x: a.min_x.max(b.min_x),
+            min_y: a.min_y.max(b.min_y),
+            max_x: a.max_x.min(b.max_x),
+            max_y: a.max_y.min(b.max_y),
+        };
+        if r.min_x <= r.max_x && r.min_y <= r.max_y {
+            Some(r)
+        } else {
+            None
+        }
+    }
+
+    /// Verhältnis von Schnittfläche zu Vereinigungsfläche (0.0 - 1.0), 0.0 ohne Überlappung
+    /// oder wenn beide Rechtecke keine Fläche haben
+    pub fn intersection_over_union(&self, other: &Rect) -> f32 {
+        let schnitt = self.intersection(other).map(|r| r.area()).unwrap_or(0.0);
+        let vereinigung = self.area() + other.area() - schnitt;
+        if vereinigung <= 0.0 {
+            0.0
+        } else {
+            schnitt / vereinigung
+        }
+    }
+
+    /// Ob sich die beiden Rechtecke mit einer Fläche > 0 überlappen
+    pub fn overlaps(&self, other: &Rect) -> bool {
+        self.intersection(other)
+            .map(|r| r.area() > 0.0)
+            .unwrap_or(false)
+    }
+
+    /// Um (dx, dy) verschobene Kopie des Rechtecks
+    pub fn translated(&self, dx: f32, dy: f32) -> Rect {
+        Rect {
+            min_x: self.min_x + dx,
+            min_y: self.min_y + dy,
+            max_x: self.max_x + dx,
+            max_y: self.max_y + dy,
+        }
+    }
+
+    /// Nach allen Seiten um `betrag` vergrößertes (normalisiertes) Rechteck,
+    /// ein negativer Betrag entspricht `inset`
+    pub fn expand(&self, betrag: f32) -> Rect {
+        if betrag < 0.0 {
+            return self.inset(-betrag);
+        }
+        let r = self.normalized();
+        Rect {
+            min_x: r.min_x - betrag,
+            min_y: r.min_y - betrag,
+            max_x: r.max_x + betrag,
+            max_y: r.max_y + betrag,
+        }
+    }
+
+    /// Nach allen Seiten um `betrag` verkleinertes (normalisiertes) Rechteck. Kollabiert es dabei,
+    /// wird die betroffene Achse auf ihren Mittelpunkt reduziert (min == max) statt invertiert.
+    pub fn inset(&self, betrag: f32) -> Rect {
+        if betrag < 0.0 {
+            return self.expand(-betrag);
+        }
+        let r = self.normalized();
+        let (cx, cy) = r.center();
+        let (min_x, max_x) = if r.width() > 2.0 * betrag {
+            (r.min_x + betrag, r.max_x - betrag)
+        } else {
+            (cx, cx)
+        };
+        let (min_y, max_y) = if r.height() > 2.0 * betrag {
+            (r.min_y + betrag, r.max_y - betrag)
+        } else {
+            (cy, cy)
+        };
+        Rect {
+            min_x,
+            min_y,
+            max_x,
+            max_y,
+        }
+    }
+
+    /// Auf `grenze` begrenztes (normalisiertes) Rechteck, liegt es komplett außerhalb,
+    /// kollabiert es auf den nächstgelegenen Rand
+    pub fn clamped_to(&self, grenze: &Rect) -> Rect {
+        let r = self.normalized();
+        let g = grenze.normalized();
+        Rect {
+            min_x: r.min_x.clamp(g.min_x, g.max_x),
+            min_y: r.min_y.clamp(g.min_y, g.max_y),
+            max_x: r.max_x.clamp(g.min_x, g.max_x),
+            max_y: r.max_y.clamp(g.min_y, g.max_y),
+        }
+    }
+
+    /// Kleinstes Rechteck, das beide (normalisierten) Rechtecke umschließt
+    pub fn union(&self, other: &Rect) -> Rect {
+        let a = self.normalized();
+        let b = other.normalized();
+        Rect {
+            min_x: a.min_x.min(b.min_x),
+            min_y: a.min_y.min(b.min_y),
+            max_x: a.max_x.max(b.max_x),
+            max_y: a.max_y.max(b.max_y),
+        }
+    }
+}
+
 /// Seitentyp der Seite im Grundbuch-PDF, jeder SeitenTyp hat andere Spalten / ein anderes Formular
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum SeitenTyp {
     #[serde(rename = "bv-horz")]
     BestandsverzeichnisHorz,
@@ -193,8 +1880,252 @@ pub enum SeitenTyp {
     Abt3Vert,
 }
 
+impl SeitenTyp {
+    /// Abteilung, zu der Seiten dieses Typs gehören
+    pub fn abteilung(&self) -> Abteilungsbereich {
+        use self::SeitenTyp::*;
+        match self {
+            BestandsverzeichnisHorz
+            | BestandsverzeichnisHorzZuUndAbschreibungen
+            | BestandsverzeichnisVert
+            | BestandsverzeichnisVertTyp2
+            | BestandsverzeichnisVertZuUndAbschreibungen
+            | BestandsverzeichnisVertZuUndAbschreibungenAlt => {
+                Abteilungsbereich::Bestandsverzeichnis
+            }
+            Abt1Horz | Abt1Vert | Abt1VertTyp2 => Abteilungsbereich::Abt1,
+            Abt2HorzVeraenderungen
+            | Abt2Horz
+            | Abt2VertVeraenderungen
+            | Abt2Vert
+            | Abt2VertTyp2 => Abteilungsbereich::Abt2,
+            Abt3HorzVeraenderungenLoeschungen
+            | Abt3VertVeraenderungenLoeschungen
+            | Abt3Horz
+            | Abt3VertVeraenderungen
+            | Abt3VertLoeschungen
+            | Abt3Vert => Abteilungsbereich::Abt3,
+        }
+    }
+
+    /// Typische Spaltengrenzen dieses Seitentyps in relativen Koordinaten (0.0 - 1.0 der
+    /// Seitenbreite / -höhe), indexiert nach Spalten-ID. Die Werte sind Näherungen als
+    /// Ausgangsbasis, die über `AnpassungSeite::spalten` überschrieben werden können.
+    pub fn default_spalten(&self) -> BTreeMap<String, Rect> {
+        use self::SeitenTyp::*;
+
+        /// Spalten-ID, linke und rechte Grenze
+        type Spalte = (&'static str, f32, f32);
+        const HORZ: (f32, f32) = (0.12, 0.95);
+        const VERT: (f32, f32) = (0.10, 0.95);
+
+        let (y, spalten): ((f32, f32), &[Spalte]) = match self {
+            BestandsverzeichnisHorz => (
+                HORZ,
+                &[
+                    ("lfd_nr", 0.05, 0.09),
+                    ("bisherige_lfd_nr", 0.09, 0.13),
+                    ("gemarkung", 0.13, 0.25),
+                    ("flur", 0.25, 0.30),
+                    ("flurstueck", 0.30, 0.37),
+                    ("bezeichnung", 0.37, 0.80),
+                    ("groesse", 0.80, 0.95),
+                ],
+            ),
+            BestandsverzeichnisVert => (
+                VERT,
+                &[
+                    ("lfd_nr", 0.08, 0.13),
+                    ("bisherige_lfd_nr", 0.13, 0.18),
+                    ("flur", 0.18, 0.25),
+                    ("flurstueck", 0.25, 0.33),
+                    ("bezeichnung", 0.33, 0.78),
+                    ("groesse", 0.78, 0.95),
+                ],
+            ),
+            BestandsverzeichnisVertTyp2 => (
+                VERT,
+                &[
+                    ("lfd_nr", 0.08, 0.12),
+                    ("bisherige_lfd_nr", 0.12, 0.16),
+                    ("gemarkung", 0.16, 0.30),
+                    ("flur", 0.30, 0.36),
+                    ("flurstueck", 0.36, 0.44),
+                    ("bezeichnung", 0.44, 0.80),
+                    ("groesse", 0.80, 0.95),
+                ],
+            ),
+            BestandsverzeichnisHorzZuUndAbschreibungen => (
+                HORZ,
+                &[
+                    ("zuschreibungen_bv_nr", 0.05, 0.12),
+                    ("zuschreibungen_text", 0.12, 0.50),
+                    ("abschreibungen_bv_nr", 0.50, 0.57),
+                    ("abschreibungen_text", 0.57, 0.95),
+                ],
+            ),
+            BestandsverzeichnisVertZuUndAbschreibungen
+            | BestandsverzeichnisVertZuUndAbschreibungenAlt => (
+                VERT,
+                &[
+                    ("zuschreibungen_bv_nr", 0.08, 0.15),
+                    ("zuschreibungen_text", 0.15, 0.50),
+                    ("abschreibungen_bv_nr", 0.50, 0.57),
+                    ("abschreibungen_text", 0.57, 0.95),
+                ],
+            ),
+            Abt1Horz => (
+                HORZ,
+                &[
+                    ("lfd_nr", 0.05, 0.09),
+                    ("eigentuemer", 0.09, 0.45),
+                    ("bv_nr", 0.45, 0.52),
+                    ("grundlage_der_eintragung", 0.52, 0.95),
+                ],
+            ),
+            Abt1Vert | Abt1VertTyp2 => (
+                VERT,
+                &[
+                    ("lfd_nr", 0.08, 0.13),
+                    ("eigentuemer", 0.13, 0.50),
+                    ("bv_nr", 0.50, 0.58),
+                    ("grundlage_der_eintragung", 0.58, 0.95),
+                ],
+            ),
+            Abt2Horz => (
+                HORZ,
+                &[
+                    ("lfd_nr", 0.05, 0.09),
+                    ("bv_nr", 0.09, 0.16),
+                    ("text", 0.16, 0.95),
+                ],
+            ),
+            Abt2Vert | Abt2VertTyp2 => (
+                VERT,
+                &[
+                    ("lfd_nr", 0.08, 0.13),
+                    ("bv_nr", 0.13, 0.21),
+                    ("text", 0.21, 0.95),
+                ],
+            ),
+            Abt2HorzVeraenderungen => (
+                HORZ,
+                &[
+                    ("veraenderungen_lfd_nr", 0.05, 0.12),
+                    ("veraenderungen_text", 0.12, 0.50),
+                    ("loeschungen_lfd_nr", 0.50, 0.57),
+                    ("loeschungen_text", 0.57, 0.95),
+                ],
+            ),
+            Abt2VertVeraenderungen => (
+                VERT,
+                &[
+                    ("veraenderungen_lfd_nr", 0.08, 0.15),
+                    ("veraenderungen_text", 0.15, 0.95),
+                ],
+            ),
+            Abt3Horz => (
+                HORZ,
+                &[
+                    ("lfd_nr", 0.05, 0.09),
+                    ("bv_nr", 0.09, 0.16),
+                    ("betrag", 0.16, 0.30),
+                    ("text", 0.30, 0.95),
+                ],
+            ),
+            Abt3Vert => (
+                VERT,
+                &[
+                    ("lfd_nr", 0.08, 0.13),
+                    ("bv_nr", 0.13, 0.21),
+                    ("betrag", 0.21, 0.36),
+                    ("text", 0.36, 0.95),
+                ],
+            ),
+            Abt3HorzVeraenderungenLoeschungen | Abt3VertVeraenderungenLoeschungen => (
+                if *self == Abt3HorzVeraenderungenLoeschungen {
+                    HORZ
+                } else {
+                    VERT
+                },
+                &[
+                    ("veraenderungen_lfd_nr", 0.05, 0.10),
+                    ("veraenderungen_betrag", 0.10, 0.20),
+                    ("veraenderungen_text", 0.20, 0.50),
+                    ("loeschungen_lfd_nr", 0.50, 0.55),
+                    ("loeschungen_betrag", 0.55, 0.65),
+                    ("loeschungen_text", 0.65, 0.95),
+                ],
+            ),
+            Abt3VertVeraenderungen => (
+                VERT,
+                &[
+                    ("veraenderungen_lfd_nr", 0.08, 0.13),
+                    ("veraenderungen_betrag", 0.13, 0.28),
+                    ("veraenderungen_text", 0.28, 0.95),
+                ],
+            ),
+            Abt3VertLoeschungen => (
+                VERT,
+                &[
+                    ("loeschungen_lfd_nr", 0.08, 0.13),
+                    ("loeschungen_betrag", 0.13, 0.28),
+                    ("loeschungen_text", 0.28, 0.95),
+                ],
+            ),
+        };
+
+        spalten
+            .iter()
+            .map(|(id, min_x, max_x)| {
+                (
+                    id.to_string(),
+                    Rect {
+                        min_x: *min_x,
+                        min_y: y.0,
+                        max_x: *max_x,
+                        max_y: y.1,
+                    },
+                )
+            })
+            .collect()
+    }
+
+    /// `default_spalten` skaliert auf die Seitengröße in mm
+    pub fn default_spalten_mm(&self, breite_mm: f32, hoehe_mm: f32) -> BTreeMap<String, Rect> {
+        self.default_spalten()
+            .into_iter()
+            .map(|(id, r)| {
+                let rect = Rect {
+                    min_x: r.min_x * breite_mm,
+                    min_y: r.min_y * hoehe_mm,
+                    max_x: r.max_x * breite_mm,
+                    max_y: r.max_y * hoehe_mm,
+                };
+                (id, rect)
+            })
+            .collect()
+    }
+}
+
+/// Abteilung des Grundbuchs, in der Reihenfolge, in der sie im Grundbuch erscheinen
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Abteilungsbereich {
+    #[serde(rename = "bv")]
+    Bestandsverzeichnis,
+    #[serde(rename = "abt1")]
+    Abt1,
+    #[serde(rename = "abt2")]
+    Abt2,
+    #[serde(rename = "abt3")]
+    Abt3,
+}
+
 /// Analysiertes Grundbuch mit manuellen Änderungen
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
 pub struct Grundbuch {
     /// Titelblatt des Grundbuchs
     pub titelblatt: Titelblatt,
@@ -216,40 +2147,478 @@ pub struct Grundbuch {
     pub abt3: Abteilung3,
 }
 
+/// Unveränderlicher, günstig klonbarer Stand eines Grundbuchs zum Teilen zwischen Threads
+/// (z.B. Renderer und Suche). Lesender Zugriff über `Deref`, für Änderungen muss mit
+/// `into_owned` explizit eine eigene Kopie angelegt werden.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GrundbuchSnapshot(std::sync::Arc<Grundbuch>);
+
+impl Grundbuch {
+    /// Kopiert das Grundbuch einmalig in einen `GrundbuchSnapshot`, weitere Klone des
+    /// Snapshots teilen sich dieselben Daten
+    pub fn snapshot(&self) -> GrundbuchSnapshot {
+        GrundbuchSnapshot(std::sync::Arc::new(self.clone()))
+    }
+}
+
+impl GrundbuchSnapshot {
+    /// Veränderbares Grundbuch, ohne Kopie wenn dies der letzte Klon des Snapshots ist
+    pub fn into_owned(self) -> Grundbuch {
+        std::sync::Arc::try_unwrap(self.0).unwrap_or_else(|gb| (*gb).clone())
+    }
+}
+
+impl From<Grundbuch> for GrundbuchSnapshot {
+    fn from(gb: Grundbuch) -> GrundbuchSnapshot {
+        GrundbuchSnapshot(std::sync::Arc::new(gb))
+    }
+}
+
+impl std::ops::Deref for GrundbuchSnapshot {
+    type Target = Grundbuch;
+
+    fn deref(&self) -> &Grundbuch {
+        &self.0
+    }
+}
+
+impl Serialize for GrundbuchSnapshot {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+impl Grundbuch {
+    /// Kopie mit Titelblatt und nur der gewählten Abteilung, alle anderen Abteilungen
+    /// sind leer (und werden beim Serialisieren übersprungen)
+    pub fn nur_abteilung(&self, bereich: Abteilungsbereich) -> Grundbuch {
+        let mut gb = Grundbuch {
+            titelblatt: self.titelblatt.clone(),
+            bestandsverzeichnis: Bestandsverzeichnis::default(),
+            abt1: Abteilung1::default(),
+            abt2: Abteilung2::default(),
+            abt3: Abteilung3::default(),
+        };
+        match bereich {
+            Abteilungsbereich::Bestandsverzeichnis => {
+                gb.bestandsverzeichnis = self.bestandsverzeichnis.clone();
+            }
+            Abteilungsbereich::Abt1 => gb.abt1 = self.abt1.clone(),
+            Abteilungsbereich::Abt2 => gb.abt2 = self.abt2.clone(),
+            Abteilungsbereich::Abt3 => gb.abt3 = self.abt3.clone(),
+        }
+        gb
+    }
+
+    /// Iteriert über alle Einträge aller Abteilungen (inkl. Veränderungen und Löschungen)
+    pub fn alle_eintraege(&self) -> impl Iterator<Item = GrundbuchEintragRef<'_>> {
+        let bv = &self.bestandsverzeichnis;
+        bv.eintraege
+            .iter()
+            .map(GrundbuchEintragRef::Bv)
+            .chain(
+                bv.zuschreibungen
+                    .iter()
+                    .map(GrundbuchEintragRef::BvZuschreibung),
+            )
+            .chain(
+                bv.abschreibungen
+                    .iter()
+                    .map(GrundbuchEintragRef::BvAbschreibung),
+            )
+            .chain(self.abt1.eintraege.iter().map(GrundbuchEintragRef::Abt1))
+            .chain(
+                self.abt1
+                    .grundlagen_eintragungen
+                    .iter()
+                    .map(GrundbuchEintragRef::Abt1GrundEintragung),
+            )
+            .chain(
+                self.abt1
+                    .veraenderungen
+                    .iter()
+                    .map(GrundbuchEintragRef::Abt1Veraenderung),
+            )
+            .chain(
+                self.abt1
+                    .loeschungen
+                    .iter()
+                    .map(GrundbuchEintragRef::Abt1Loeschung),
+            )
+            .chain(self.abt2.eintraege.iter().map(GrundbuchEintragRef::Abt2))
+            .chain(
+                self.abt2
+                    .veraenderungen
+                    .iter()
+                    .map(GrundbuchEintragRef::Abt2Veraenderung),
+            )
+            .chain(
+                self.abt2
+                    .loeschungen
+                    .iter()
+                    .map(GrundbuchEintragRef::Abt2Loeschung),
+            )
+            .chain(self.abt3.eintraege.iter().map(GrundbuchEintragRef::Abt3))
+            .chain(
+                self.abt3
+                    .veraenderungen
+                    .iter()
+                    .map(GrundbuchEintragRef::Abt3Veraenderung),
+            )
+            .chain(
+                self.abt3
+                    .loeschungen
+                    .iter()
+                    .map(GrundbuchEintragRef::Abt3Loeschung),
+            )
+    }
+
+    /// Wie `alle_eintraege`, aber nur die Einträge (inkl. Veränderungen und Löschungen)
+    /// der gewählten Abteilung
+    pub fn eintraege_in(
+        &self,
+        bereich: Abteilungsbereich,
+    ) -> impl Iterator<Item = GrundbuchEintragRef<'_>> {
+        EintragsListe::ALLE
+            .into_iter()
+            .filter(move |liste| liste.abteilung() == bereich)
+            .flat_map(move |liste| {
+                (0..self.anzahl(liste))
+                    .filter_map(move |index| self.eintrag(&EintragPfad { liste, index }))
+            })
+    }
+
+    /// Gruppiert die Einträge einer Seite nach überlappenden y-Bereichen ihrer `position_in_pdf`
+    /// zu logischen Tabellenzeilen (von oben nach unten sortiert)
+    pub fn gruppiere_nach_zeilen(
+        &self,
+        seite: &str,
+        toleranz_mm: f32,
+    ) -> Vec<Vec<GrundbuchEintragRef<'_>>> {
+        let mut eintraege = self
+            .alle_eintraege()
+            .filter_map(|e| {
+                let pos = e.position_in_pdf()?;
+                if pos.seite != seite {
+                    return None;
+                }
+                let min_y = pos.rect.min_y.min(pos.rect.max_y);
+                let max_y = pos.rect.min_y.max(pos.rect.max_y);
+                Some((min_y, max_y, e))
+            })
+            .collect::<Vec<_>>();
+
+        eintraege.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut zeilen: Vec<(f32, Vec<GrundbuchEintragRef<'_>>)> = Vec::new();
+        for (min_y, max_y, e) in eintraege {
+            match zeilen.last_mut() {
+                Some((zeile_max_y, zeile)) if min_y <= *zeile_max_y + toleranz_mm => {
+                    *zeile_max_y = zeile_max_y.max(max_y);
+                    zeile.push(e);
+                }
+                _ => zeilen.push((max_y, vec![e])),
+            }
+        }
+
+        zeilen.into_iter().map(|(_, z)| z).collect()
+    }
+}
+
+/// Referenz auf einen beliebigen Eintrag im Grundbuch, unabhängig von Abteilung und Eintragstyp
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum GrundbuchEintragRef<'a> {
+    Bv(&'a BvEintrag),
+    BvZuschreibung(&'a BvZuschreibung),
+    BvAbschreibung(&'a BvAbschreibung),
+    Abt1(&'a Abt1Eintrag),
+    Abt1GrundEintragung(&'a Abt1GrundEintragung),
+    Abt1Veraenderung(&'a Abt1Veraenderung),
+    Abt1Loeschung(&'a Abt1Loeschung),
+    Abt2(&'a Abt2Eintrag),
+    Abt2Veraenderung(&'a Abt2Veraenderung),
+    Abt2Loeschung(&'a Abt2Loeschung),
+    Abt3(&'a Abt3Eintrag),
+    Abt3Veraenderung(&'a Abt3Veraenderung),
+    Abt3Loeschung(&'a Abt3Loeschung),
+}
+
+impl<'a> GrundbuchEintragRef<'a> {
+    /// Position des Eintrags im PDF, falls bekannt
+    pub fn position_in_pdf(&self) -> Option<&'a PositionInPdf> {
+        match self {
+            GrundbuchEintragRef::Bv(BvEintrag::Flurstueck(e)) => e.position_in_pdf.as_ref(),
+            GrundbuchEintragRef::Bv(BvEintrag::Recht(e)) => e.position_in_pdf.as_ref(),
+            GrundbuchEintragRef::BvZuschreibung(e) => e.position_in_pdf.as_ref(),
+            GrundbuchEintragRef::BvAbschreibung(e) => e.position_in_pdf.as_ref(),
+            GrundbuchEintragRef::Abt1(Abt1Eintrag::V1(e)) => e.position_in_pdf.as_ref(),
+            GrundbuchEintragRef::Abt1(Abt1Eintrag::V2(e)) => e.position_in_pdf.as_ref(),
+            GrundbuchEintragRef::Abt1GrundEintragung(e) => e.position_in_pdf.as_ref(),
+            GrundbuchEintragRef::Abt1Veraenderung(e) => e.position_in_pdf.as_ref(),
+            GrundbuchEintragRef::Abt1Loeschung(e) => e.position_in_pdf.as_ref(),
+            GrundbuchEintragRef::Abt2(e) => e.position_in_pdf.as_ref(),
+            GrundbuchEintragRef::Abt2Veraenderung(e) => e.position_in_pdf.as_ref(),
+            GrundbuchEintragRef::Abt2Loeschung(e) => e.position_in_pdf.as_ref(),
+            GrundbuchEintragRef::Abt3(e) => e.position_in_pdf.as_ref(),
+            GrundbuchEintragRef::Abt3Veraenderung(e) => e.position_in_pdf.as_ref(),
+            GrundbuchEintragRef::Abt3Loeschung(e) => e.position_in_pdf.as_ref(),
+        }
+    }
+}
+
 /// Titelblatt des Grundbuchs
 #[derive(Debug, Default, Clone, PartialEq, Eq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
 pub struct Titelblatt {
     /// Amtsgericht
     pub amtsgericht: String,
     /// Grundbuch von ...
+    #[serde(alias = "grundbuch")]
+    #[serde(alias = "grundbuchVon")]
     pub grundbuch_von: String,
     /// Blatt ...
     pub blatt: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Bestandsverzeichnis {
-    #[serde(default)]
-    #[serde(skip_serializing_if = "Vec::is_empty")]
-    pub eintraege: Vec<BvEintrag>,
-    #[serde(default)]
-    #[serde(skip_serializing_if = "Vec::is_empty")]
-    pub zuschreibungen: Vec<BvZuschreibung>,
-    #[serde(default)]
-    #[serde(skip_serializing_if = "Vec::is_empty")]
-    pub abschreibungen: Vec<BvAbschreibung>,
-}
+impl Titelblatt {
+    /// Normalisierte Kopie zum Vergleich (siehe `normalisiere_suchtext`: Kleinschreibung,
+    /// aufgelöste Umlaute, zusammengefasste Leerzeichen) und ohne führende Nullen in `blatt`
+    pub fn normalisiert(&self) -> Titelblatt {
+        let norm = normalisiere_suchtext;
+
+        let blatt = norm(&self.blatt);
+        let ohne_nullen = blatt.trim_start_matches('0');
+        let blatt = if ohne_nullen.starts_with(|c: char| c.is_ascii_digit()) {
+            ohne_nullen.to_string()
+        } else if blatt.starts_with('0') {
+            // nur Nullen oder Null vor einem Zusatz ("0", "0a")
+            format!("0{ohne_nullen}")
+        } else {
+            blatt
+        };
+
+        Titelblatt {
+            amtsgericht: norm(&self.amtsgericht),
+            grundbuch_von: norm(&self.grundbuch_von),
+            blatt,
+        }
+    }
+
+    /// Ob Amtsgericht, Grundbuchbezirk oder Blatt den Suchtext enthalten (unabhängig von
+    /// Groß- / Kleinschreibung und Umlautschreibweise, "koenigstein" findet "Königstein").
+    /// Ein leerer Suchtext passt immer (wie bei `Grundbuch::suche_eintraege`).
+    pub fn passt_zu_suche(&self, suche: &str) -> bool {
+        let suche = normalisiere_suchtext(suche);
+        if suche.is_empty() {
+            return true;
+        }
+        let n = self.normalisiert();
+        [n.amtsgericht, n.grundbuch_von, n.blatt]
+            .iter()
+            .any(|feld| feld.contains(&suche))
+    }
+}
+
+/// Normalisiert einen Text für Suche und Vergleich: Kleinschreibung, Umlaute und ß aufgelöst
+/// ("ä" → "ae", "ß" → "ss", auch zerlegte Umlaute aus Vokal + U+0308), Leerzeichen am Rand
+/// entfernt und mehrfache Leerzeichen zusammengefasst
+pub fn normalisiere_suchtext(s: &str) -> String {
+    /// Kombinierendes Trema, z.B. in "a\u{308}" (zerlegtes "ä")
+    const TREMA: char = '\u{308}';
+
+    let mut out = String::with_capacity(s.len());
+    let zusammengefasst = s.split_whitespace().collect::<Vec<_>>().join(" ");
+    let mut zeichen = zusammengefasst.chars().peekable();
+    while let Some(c) = zeichen.next() {
+        match c {
+            'a' | 'A' | 'o' | 'O' | 'u' | 'U' if zeichen.peek() == Some(&TREMA) => {
+                zeichen.next();
+                out.extend(c.to_lowercase());
+                out.push('e');
+            }
+            'ä' | 'Ä' => out.push_str("ae"),
+            'ö' | 'Ö' => out.push_str("oe"),
+            'ü' | 'Ü' => out.push_str("ue"),
+            'ß' | 'ẞ' => out.push_str("ss"),
+            c => out.extend(c.to_lowercase()),
+        }
+    }
+    out
+}
+
+/// Bestandteile des `blatt`-Felds, z.B. "123a Band 4"
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct BlattNummer {
+    pub nummer: u32,
+    /// Zusatz direkt hinter der Nummer ("a" bei "123a")
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zusatz: Option<String>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub band: Option<u32>,
+}
+
+impl Titelblatt {
+    /// Zerlegt `blatt` in Nummer, Zusatz und Band ("123", "123a", "123 Band 4", "Bd. 4 Blatt 123"),
+    /// `None` wenn keine Blattnummer erkennbar ist
+    pub fn blatt_parsed(&self) -> Option<BlattNummer> {
+        let band = REGEX_BLATT_BAND
+            .captures(&self.blatt)
+            .and_then(|c| c.get(1)?.as_str().parse::<u32>().ok());
+        let rest = REGEX_BLATT_BAND.replace_all(&self.blatt, " ");
+        let cap = REGEX_BLATT_NUMMER.captures(&rest)?;
+        let nummer = cap.get(1)?.as_str().parse::<u32>().ok()?;
+        let zusatz = cap
+            .get(2)
+            .map(|z| {
+                z.as_str()
+                    .trim_matches(|c: char| c.is_whitespace() || c == ',' || c == '-')
+                    .to_string()
+            })
+            .filter(|z| !z.is_empty());
+        Some(BlattNummer {
+            nummer,
+            zusatz,
+            band,
+        })
+    }
+
+    /// Sortierschlüssel für Blätter eines Grundbuchbezirks: numerisch nach Blattnummer
+    /// ("2" vor "10"), dann nach Zusatz und Band, nicht erkennbare Blätter zuletzt
+    pub fn sort_key(&self) -> (u32, String, Option<u32>, String) {
+        match self.blatt_parsed() {
+            Some(b) => (
+                b.nummer,
+                b.zusatz.unwrap_or_default().to_lowercase(),
+                b.band,
+                String::new(),
+            ),
+            None => (u32::MAX, String::new(), None, self.normalisiert().blatt),
+        }
+    }
+}
+
+lazy_static::lazy_static! {
+    static ref REGEX_BLATT_BAND: regex::Regex = {
+        regex::Regex::new("(?i),?\\s*\\b(?:band|bd\\.?)\\s*(\\d+)").unwrap()
+    };
+    static ref REGEX_BLATT_NUMMER: regex::Regex = {
+        regex::Regex::new("(?i)^\\s*(?:blatt|bl\\.)?\\s*0*(\\d+)\\s*(.*?)\\s*$").unwrap()
+    };
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
+pub struct Bestandsverzeichnis {
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub eintraege: Vec<BvEintrag>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub zuschreibungen: Vec<BvZuschreibung>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub abschreibungen: Vec<BvAbschreibung>,
+}
+
+impl Bestandsverzeichnis {
+    /// Konvertiert die Größen aller Flurstücke in die Zieldarstellung
+    pub fn vereinheitliche_groessen(&mut self, ziel: GroessenDarstellung) {
+        for e in self.eintraege.iter_mut() {
+            if let BvEintrag::Flurstueck(f) = e {
+                f.groesse = f.groesse.in_darstellung(ziel);
+            }
+        }
+    }
+
+    /// Setzt leere Gemarkungen der Flurstücke auf `grundbuch_von` aus dem Titelblatt
+    pub fn fuelle_gemarkungen(&mut self, titel: &Titelblatt) {
+        for e in self.eintraege.iter_mut() {
+            if let BvEintrag::Flurstueck(f) = e {
+                let leer = f.gemarkung.as_deref().is_none_or(|g| g.trim().is_empty());
+                if leer && !titel.grundbuch_von.trim().is_empty() {
+                    f.gemarkung = Some(f.effektive_gemarkung(titel));
+                }
+            }
+        }
+    }
+
+    /// Ob nicht-leere Flurstücksgrößen sowohl metrisch als auch in ha angegeben sind
+    pub fn groessen_gemischt(&self) -> bool {
+        let mut darstellungen = self.eintraege.iter().filter_map(|e| match e {
+            BvEintrag::Flurstueck(f) if !f.groesse.ist_leer() => Some(f.groesse.darstellung()),
+            _ => None,
+        });
+        match darstellungen.next() {
+            Some(erste) => darstellungen.any(|d| d != erste),
+            None => false,
+        }
+    }
 
-impl Bestandsverzeichnis {
     pub fn is_empty(&self) -> bool {
         self.eintraege.is_empty()
             && self.zuschreibungen.is_empty()
             && self.abschreibungen.is_empty()
     }
+
+    /// Alle Zuschreibungen, deren `bv_nr` (inkl. Bereiche) die lfd. Nr. nennt
+    pub fn zuschreibungen_fuer(&self, lfd_nr: usize) -> Vec<&BvZuschreibung> {
+        self.zuschreibungen
+            .iter()
+            .filter(|z| z.bv_nr.nummern().contains(&lfd_nr))
+            .collect()
+    }
+
+    /// Alle Abschreibungen, deren `bv_nr` (inkl. Bereiche) die lfd. Nr. nennt
+    pub fn abschreibungen_fuer(&self, lfd_nr: usize) -> Vec<&BvAbschreibung> {
+        self.abschreibungen
+            .iter()
+            .filter(|a| a.bv_nr.nummern().contains(&lfd_nr))
+            .collect()
+    }
+
+    /// Alle grundstücksgleichen Rechte, deren `zu_nr` (inkl. Bereiche) die lfd. Nr. nennt
+    pub fn rechte_fuer_flurstueck(&self, lfd_nr: usize) -> Vec<&BvEintragRecht> {
+        self.eintraege
+            .iter()
+            .filter_map(|e| match e {
+                BvEintrag::Recht(r) if r.zu_nr.nummern().contains(&lfd_nr) => Some(r),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Alle Flurstücke, die in `zu_nr` der Rechte mit der lfd. Nr. genannt sind
+    pub fn flurstuecke_fuer_recht(&self, recht_lfd_nr: usize) -> Vec<&BvEintragFlurstueck> {
+        let zu_nr = self
+            .eintraege
+            .iter()
+            .filter_map(|e| match e {
+                BvEintrag::Recht(r) if r.lfd_nr == recht_lfd_nr => Some(r.zu_nr.nummern()),
+                _ => None,
+            })
+            .flatten()
+            .collect::<std::collections::BTreeSet<_>>();
+
+        self.eintraege
+            .iter()
+            .filter_map(|e| match e {
+                BvEintrag::Flurstueck(f) if zu_nr.contains(&f.lfd_nr) => Some(f),
+                _ => None,
+            })
+            .collect()
+    }
 }
 
 /// Eintrag im Bestandsverzeichnis
-#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
 #[serde(untagged)]
 pub enum BvEintrag {
     /// Flurstück
@@ -258,36 +2627,187 @@ pub enum BvEintrag {
     Recht(BvEintragRecht),
 }
 
+/// Flache Darstellung eines `BvEintrag` beim Einlesen, aus der anhand der
+/// vorhandenen Felder die Variante bestimmt wird
+#[derive(Deserialize)]
+struct BvEintragRoh {
+    #[cfg_attr(feature = "skip-zero-lfd-nr", serde(default))]
+    #[serde(deserialize_with = "usize_from_str_or_num")]
+    #[serde(alias = "lfdNr")]
+    lfd_nr: usize,
+    #[serde(default)]
+    #[serde(deserialize_with = "option_usize_from_str_or_num")]
+    #[serde(alias = "bisherigeLfdNr")]
+    bisherige_lfd_nr: Option<usize>,
+    #[serde(default)]
+    #[serde(deserialize_with = "option_usize_from_str_or_num")]
+    flur: Option<usize>,
+    #[serde(default)]
+    flurstueck: Option<String>,
+    #[serde(default)]
+    gemarkung: Option<String>,
+    #[serde(default)]
+    bezeichnung: Option<StringOrLines>,
+    #[serde(default)]
+    #[serde(deserialize_with = "option_groesse_from_tagged_or_num")]
+    groesse: Option<FlurstueckGroesse>,
+    #[serde(default)]
+    #[serde(alias = "zuNr")]
+    zu_nr: Option<StringOrLines>,
+    #[serde(default)]
+    text: Option<StringOrLines>,
+    #[serde(default)]
+    #[serde(alias = "automatischGeroetet")]
+    automatisch_geroetet: Option<bool>,
+    #[serde(default)]
+    #[serde(alias = "manuellGeroetet")]
+    manuell_geroetet: Option<bool>,
+    #[serde(default)]
+    #[serde(alias = "positionInPdf")]
+    position_in_pdf: Option<PositionInPdf>,
+}
+
+/// Liest einen `BvEintrag` ein: `flur` / `flurstueck` kennzeichnen ein Flurstück,
+/// `zu_nr` / `text` ein Recht. Enthält ein Eintrag Felder beider Varianten, ist er
+/// mehrdeutig und wird mit einem Fehler abgelehnt (statt wie bei `untagged` still die
+/// erste passende Variante zu wählen).
+impl<'de> Deserialize<'de> for BvEintrag {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        let roh = BvEintragRoh::deserialize(deserializer)?;
+
+        let ist_flurstueck = roh.flur.is_some() || roh.flurstueck.is_some();
+        let ist_recht = roh.zu_nr.is_some() || roh.text.is_some();
+
+        if ist_flurstueck && ist_recht {
+            return Err(D::Error::custom(format!(
+                "BV-Eintrag lfd. Nr. {} ist mehrdeutig: enthält sowohl flur / flurstueck \
+                 (Flurstück) als auch zu_nr / text (Recht)",
+                roh.lfd_nr
+            )));
+        }
+
+        if ist_flurstueck {
+            let flur = roh.flur.ok_or_else(|| {
+                D::Error::custom(format!(
+                    "BV-Eintrag lfd. Nr. {}: Flurstück ohne flur",
+                    roh.lfd_nr
+                ))
+            })?;
+            Ok(BvEintrag::Flurstueck(BvEintragFlurstueck {
+                lfd_nr: roh.lfd_nr,
+                bisherige_lfd_nr: roh.bisherige_lfd_nr,
+                flur,
+                flurstueck: roh.flurstueck.unwrap_or_default(),
+                gemarkung: roh.gemarkung,
+                bezeichnung: roh.bezeichnung,
+                groesse: roh.groesse.unwrap_or_default(),
+                automatisch_geroetet: roh.automatisch_geroetet,
+                manuell_geroetet: roh.manuell_geroetet,
+                position_in_pdf: roh.position_in_pdf,
+            }))
+        } else {
+            if roh.gemarkung.is_some() || roh.bezeichnung.is_some() || roh.groesse.is_some() {
+                return Err(D::Error::custom(format!(
+                    "BV-Eintrag lfd. Nr. {}: Flurstücksangaben (gemarkung / bezeichnung / \
+                     groesse) ohne flur",
+                    roh.lfd_nr
+                )));
+            }
+            Ok(BvEintrag::Recht(BvEintragRecht {
+                lfd_nr: roh.lfd_nr,
+                zu_nr: roh.zu_nr.unwrap_or_default(),
+                bisherige_lfd_nr: roh.bisherige_lfd_nr,
+                text: roh.text.unwrap_or_default(),
+                automatisch_geroetet: roh.automatisch_geroetet,
+                manuell_geroetet: roh.manuell_geroetet,
+                position_in_pdf: roh.position_in_pdf,
+            }))
+        }
+    }
+}
+
 /// Eintrag für ein grundstücksgleiches Recht
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
 pub struct BvEintragRecht {
+    #[cfg_attr(feature = "skip-zero-lfd-nr", serde(default))]
+    #[cfg_attr(
+        feature = "skip-zero-lfd-nr",
+        serde(skip_serializing_if = "ist_null_usize")
+    )]
+    #[serde(deserialize_with = "usize_from_str_or_num")]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(schema_with = "schema_usize_from_str_or_num")
+    )]
+    #[serde(alias = "lfdNr")]
     pub lfd_nr: usize,
     #[serde(default)]
     #[serde(skip_serializing_if = "StringOrLines::is_empty")]
+    #[serde(alias = "zuNr")]
     pub zu_nr: StringOrLines,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(deserialize_with = "option_usize_from_str_or_num")]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(schema_with = "schema_option_usize_from_str_or_num")
+    )]
+    #[serde(alias = "bisherigeLfdNr")]
     pub bisherige_lfd_nr: Option<usize>,
     #[serde(default)]
     #[serde(skip_serializing_if = "StringOrLines::is_empty")]
     pub text: StringOrLines,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "automatischGeroetet")]
     pub automatisch_geroetet: Option<bool>,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "manuellGeroetet")]
     pub manuell_geroetet: Option<bool>,
     #[serde(default)]
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "PositionInPdf::option_ist_leer")]
+    #[serde(alias = "positionInPdf")]
     pub position_in_pdf: Option<PositionInPdf>,
 }
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
 pub struct BvEintragFlurstueck {
+    #[cfg_attr(feature = "skip-zero-lfd-nr", serde(default))]
+    #[cfg_attr(
+        feature = "skip-zero-lfd-nr",
+        serde(skip_serializing_if = "ist_null_usize")
+    )]
+    #[serde(deserialize_with = "usize_from_str_or_num")]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(schema_with = "schema_usize_from_str_or_num")
+    )]
+    #[serde(alias = "lfdNr")]
     pub lfd_nr: usize,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(deserialize_with = "option_usize_from_str_or_num")]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(schema_with = "schema_option_usize_from_str_or_num")
+    )]
+    #[serde(alias = "bisherigeLfdNr")]
     pub bisherige_lfd_nr: Option<usize>,
+    #[serde(deserialize_with = "usize_from_str_or_num")]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(schema_with = "schema_usize_from_str_or_num")
+    )]
     pub flur: usize,
     #[serde(default)]
     #[serde(skip_serializing_if = "String::is_empty")]
@@ -300,20 +2820,41 @@ pub struct BvEintragFlurstueck {
     pub bezeichnung: Option<StringOrLines>,
     #[serde(default)]
     #[serde(skip_serializing_if = "FlurstueckGroesse::ist_leer")]
+    #[serde(deserialize_with = "groesse_from_tagged_or_num")]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(schema_with = "schema_groesse_from_tagged_or_num")
+    )]
     pub groesse: FlurstueckGroesse,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "automatischGeroetet")]
     pub automatisch_geroetet: Option<bool>,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "manuellGeroetet")]
     pub manuell_geroetet: Option<bool>,
     #[serde(default)]
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "PositionInPdf::option_ist_leer")]
+    #[serde(alias = "positionInPdf")]
     pub position_in_pdf: Option<PositionInPdf>,
 }
 
+/// Darstellung einer `FlurstueckGroesse`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum GroessenDarstellung {
+    /// Nur Quadratmeter
+    #[serde(rename = "m")]
+    Metrisch,
+    /// Hektar / Ar / Quadratmeter
+    #[serde(rename = "ha")]
+    Hektar,
+}
+
 /// Größe des Flurstücks in m2
-#[derive(Debug, Clone, PartialEq, PartialOrd, Ord, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Ord, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(tag = "typ", content = "wert")]
 pub enum FlurstueckGroesse {
     #[serde(rename = "m")]
@@ -332,22 +2873,235 @@ impl Default for FlurstueckGroesse {
     }
 }
 
+/// Hilfstyp für `groesse_from_tagged_or_num`: getaggtes Objekt oder blanke Zahl in m²
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum GroesseOderZahl {
+    Zahl(u64),
+    Getaggt(FlurstueckGroesse),
+}
+
+impl From<GroesseOderZahl> for FlurstueckGroesse {
+    fn from(g: GroesseOderZahl) -> FlurstueckGroesse {
+        match g {
+            GroesseOderZahl::Zahl(m2) => FlurstueckGroesse::Metrisch { m2: Some(m2) },
+            GroesseOderZahl::Getaggt(g) => g,
+        }
+    }
+}
+
+/// Deserialisiert eine `FlurstueckGroesse`, die entweder getaggt
+/// (`{"typ":"m","wert":{"m2":1234}}`) oder als blanke Zahl in m² (`1234`) vorliegt.
+/// Serialisiert wird weiterhin im getaggten Format.
+pub fn groesse_from_tagged_or_num<'de, D>(deserializer: D) -> Result<FlurstueckGroesse, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    GroesseOderZahl::deserialize(deserializer).map(FlurstueckGroesse::from)
+}
+
+/// Wie `groesse_from_tagged_or_num`, aber für optionale Felder (`null` ergibt `None`)
+pub fn option_groesse_from_tagged_or_num<'de, D>(
+    deserializer: D,
+) -> Result<Option<FlurstueckGroesse>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Option::<GroesseOderZahl>::deserialize(deserializer).map(|o| o.map(FlurstueckGroesse::from))
+}
+
+lazy_static::lazy_static! {
+    static ref REGEX_GROESSE_HA_DEZIMAL: regex::Regex = {
+        regex::Regex::new("^(\\d{1,3}(?:\\.\\d{3})+|\\d+),(\\d{1,4})\\s*ha$").unwrap()
+    };
+    static ref REGEX_GROESSE_TEILE: regex::Regex = {
+        regex::Regex::new(
+            "^(?:(\\d{1,3}(?:\\.\\d{3})+|\\d+)\\s*ha)?\\s*(?:(\\d+)\\s*a)?\\s*\
+             (?:(\\d{1,3}(?:\\.\\d{3})+|\\d+)\\s*(?:m²|m2|qm)?)?$",
+        )
+        .unwrap()
+    };
+}
+
+/// Größte plausible Fläche eines Flurstücks in m² (10.000 ha), größere Werte
+/// stammen in der Regel aus OCR-Fehlern
+pub const FLURSTUECK_MAX_M2: u64 = 100_000_000;
+
+/// Fehler beim Setzen einer `FlurstueckGroesse`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum GroesseFehler {
+    /// Fläche passt nicht in `u64` m²
+    Ueberlauf,
+    /// Fläche größer als `FLURSTUECK_MAX_M2`
+    Unplausibel { m2: u64 },
+}
+
+impl std::fmt::Display for GroesseFehler {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            GroesseFehler::Ueberlauf => write!(f, "Fläche ist zu groß (Überlauf)"),
+            GroesseFehler::Unplausibel { m2 } => write!(
+                f,
+                "Fläche von {} m² ist unplausibel (max. {} m²)",
+                tausender_trennung(*m2),
+                tausender_trennung(FLURSTUECK_MAX_M2)
+            ),
+        }
+    }
+}
+
+impl std::error::Error for GroesseFehler {}
+
+fn pruefe_groesse(m2: Option<u64>) -> Result<(), GroesseFehler> {
+    match m2 {
+        None => Err(GroesseFehler::Ueberlauf),
+        Some(m2) if m2 > FLURSTUECK_MAX_M2 => Err(GroesseFehler::Unplausibel { m2 }),
+        Some(_) => Ok(()),
+    }
+}
+
+/// Anzeigeformat für `FlurstueckGroesse::anzeige`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum GroesseFormat {
+    /// "1.234 m²" bzw. ab einem Hektar "1,2345 ha"
+    Kompakt,
+    /// "1 ha 23 a 45 m²"
+    HektarAusgeschrieben,
+    /// "12.345 m²"
+    NurQuadratmeter,
+}
+
+/// Platzhalter für die Anzeige einer leeren Flurstücksgröße
+pub const GROESSE_PLATZHALTER: &str = "—";
+
 impl FlurstueckGroesse {
+    /// Formatierte Größe für die Anzeige (deutsche Tausendertrennung),
+    /// `GROESSE_PLATZHALTER` bei leerer Größe
+    pub fn anzeige(&self, format: GroesseFormat) -> String {
+        if self.ist_leer() {
+            return GROESSE_PLATZHALTER.to_string();
+        }
+        let m2 = self.get_m2();
+        match format {
+            GroesseFormat::NurQuadratmeter => format!("{} m²", tausender_trennung(m2)),
+            GroesseFormat::Kompakt if m2 < 10_000 => format!("{} m²", tausender_trennung(m2)),
+            GroesseFormat::Kompakt => {
+                format!("{},{:04} ha", tausender_trennung(m2 / 10_000), m2 % 10_000)
+            }
+            GroesseFormat::HektarAusgeschrieben => {
+                let ha = m2 / 10_000;
+                let a = (m2 % 10_000) / 100;
+                let m = m2 % 100;
+                let mut teile = Vec::new();
+                if ha > 0 {
+                    teile.push(format!("{} ha", tausender_trennung(ha)));
+                }
+                if ha > 0 || a > 0 {
+                    teile.push(format!("{a} a"));
+                }
+                teile.push(format!("{m} m²"));
+                teile.join(" ")
+            }
+        }
+    }
+
     pub fn ist_leer(&self) -> bool {
         match self {
             FlurstueckGroesse::Metrisch { m2 } => m2.is_none(),
             FlurstueckGroesse::Hektar { ha, a, m2 } => m2.is_none() && ha.is_none() && a.is_none(),
         }
     }
+
+    /// Fläche in m², bei Überlauf (z.B. durch OCR-Fehler) `u64::MAX`
     pub fn get_m2(&self) -> u64 {
         match self {
             FlurstueckGroesse::Metrisch { m2 } => m2.unwrap_or(0),
-            FlurstueckGroesse::Hektar { ha, a, m2 } => {
-                ha.unwrap_or(0) * 100_000 + a.unwrap_or(0) * 100 + m2.unwrap_or(0)
+            FlurstueckGroesse::Hektar { ha, a, m2 } => ha
+                .unwrap_or(0)
+                .saturating_mul(10_000)
+                .saturating_add(a.unwrap_or(0).saturating_mul(100))
+                .saturating_add(m2.unwrap_or(0)),
+        }
+    }
+
+    /// Parst eine Größe in den Formaten von `anzeige` ("1.234 m²", "1,2345 ha",
+    /// "1 ha 23 a 45 m²") oder als blanke Zahl in m². Mit "ha" / "a" ergibt sich die
+    /// Darstellung `Hektar`, sonst `Metrisch`. Unplausible Flächen (siehe `set_m2`) ergeben
+    /// `GbxError::Groesse`.
+    pub fn parse(text: &str) -> Result<FlurstueckGroesse, GbxError> {
+        let text = text.trim();
+        let fehler = || GbxError::GroesseParse(text.to_string());
+        let zahl = |m: Option<regex::Match>| -> Result<Option<u64>, GbxError> {
+            m.map(|m| m.as_str().replace('.', "").parse::<u64>())
+                .transpose()
+                .map_err(|_| fehler())
+        };
+
+        let groesse = if let Some(cap) = REGEX_GROESSE_HA_DEZIMAL.captures(text) {
+            let ha = zahl(cap.get(1))?;
+            let rest = zahl(cap.get(2))?.unwrap_or(0)
+                * 10_u64.pow(4 - cap.get(2).map_or(0, |m| m.len()) as u32);
+            FlurstueckGroesse::Hektar {
+                ha,
+                a: Some(rest / 100),
+                m2: Some(rest % 100),
+            }
+        } else {
+            let cap = REGEX_GROESSE_TEILE
+                .captures(text)
+                .filter(|_| !text.is_empty())
+                .ok_or_else(fehler)?;
+            let (ha, a, m2) = (zahl(cap.get(1))?, zahl(cap.get(2))?, zahl(cap.get(3))?);
+            if ha.is_some() || a.is_some() {
+                FlurstueckGroesse::Hektar { ha, a, m2 }
+            } else {
+                FlurstueckGroesse::Metrisch { m2 }
             }
+        };
+
+        pruefe_groesse(groesse.checked_get_m2())?;
+        Ok(groesse)
+    }
+
+    /// Fläche in m², `None` bei Überlauf
+    pub fn checked_get_m2(&self) -> Option<u64> {
+        match self {
+            FlurstueckGroesse::Metrisch { m2 } => Some(m2.unwrap_or(0)),
+            FlurstueckGroesse::Hektar { ha, a, m2 } => ha
+                .unwrap_or(0)
+                .checked_mul(10_000)?
+                .checked_add(a.unwrap_or(0).checked_mul(100)?)?
+                .checked_add(m2.unwrap_or(0)),
         }
     }
 
+    /// Setzt die Fläche in m² (Variante bleibt erhalten), lehnt Flächen über
+    /// `FLURSTUECK_MAX_M2` ab
+    pub fn set_m2(&mut self, m2: u64) -> Result<(), GroesseFehler> {
+        pruefe_groesse(Some(m2))?;
+        *self = match self {
+            FlurstueckGroesse::Metrisch { .. } => FlurstueckGroesse::Metrisch { m2: Some(m2) },
+            FlurstueckGroesse::Hektar { .. } => FlurstueckGroesse::hektar_aus_m2(m2),
+        };
+        Ok(())
+    }
+
+    /// Setzt die Fläche als ha / a / m², lehnt Überläufe und Flächen über
+    /// `FLURSTUECK_MAX_M2` ab
+    pub fn set_hektar(
+        &mut self,
+        ha: Option<u64>,
+        a: Option<u64>,
+        m2: Option<u64>,
+    ) -> Result<(), GroesseFehler> {
+        let neu = FlurstueckGroesse::Hektar { ha, a, m2 };
+        pruefe_groesse(neu.checked_get_m2())?;
+        *self = neu;
+        Ok(())
+    }
+
     pub fn get_ha_string(&self) -> String {
         let m2_string = format!("{}", self.get_m2());
         let mut m2_string_chars: Vec<char> = m2_string.chars().collect();
@@ -371,6 +3125,73 @@ impl FlurstueckGroesse {
         m2_string_chars.iter().collect()
     }
 
+    /// Größe auf volle Quadratmeter (gleiche Variante, Hektar-Angaben normalisiert)
+    pub fn gerundet_auf_m2(&self) -> FlurstueckGroesse {
+        self.mit_m2(self.get_m2())
+    }
+
+    /// Größe auf volle Ar (100 m²) abgeschnitten, die Quadratmeter unter 100 entfallen
+    pub fn gerundet_auf_ar(&self) -> FlurstueckGroesse {
+        self.mit_m2(self.get_m2() / 100 * 100)
+    }
+
+    /// Darstellung (metrisch / ha) dieser Größe
+    pub fn darstellung(&self) -> GroessenDarstellung {
+        match self {
+            FlurstueckGroesse::Metrisch { .. } => GroessenDarstellung::Metrisch,
+            FlurstueckGroesse::Hektar { .. } => GroessenDarstellung::Hektar,
+        }
+    }
+
+    /// Gleiche Fläche als `Metrisch`, leere Größen bleiben leer
+    pub fn to_metrisch(&self) -> FlurstueckGroesse {
+        if self.ist_leer() {
+            return FlurstueckGroesse::Metrisch { m2: None };
+        }
+        FlurstueckGroesse::Metrisch {
+            m2: Some(self.get_m2()),
+        }
+    }
+
+    /// Gleiche Fläche als `Hektar` (ha / a / m²), leere Größen bleiben leer
+    pub fn to_hektar(&self) -> FlurstueckGroesse {
+        if self.ist_leer() {
+            return FlurstueckGroesse::Hektar {
+                ha: None,
+                a: None,
+                m2: None,
+            };
+        }
+        FlurstueckGroesse::hektar_aus_m2(self.get_m2())
+    }
+
+    /// Gleiche Fläche in der gewünschten Darstellung
+    pub fn in_darstellung(&self, ziel: GroessenDarstellung) -> FlurstueckGroesse {
+        match ziel {
+            GroessenDarstellung::Metrisch => self.to_metrisch(),
+            GroessenDarstellung::Hektar => self.to_hektar(),
+        }
+    }
+
+    /// Neue Größe derselben Variante mit `m2` Quadratmetern, leere Größen bleiben leer
+    fn mit_m2(&self, m2: u64) -> FlurstueckGroesse {
+        if self.ist_leer() {
+            return self.clone();
+        }
+        match self {
+            FlurstueckGroesse::Metrisch { .. } => FlurstueckGroesse::Metrisch { m2: Some(m2) },
+            FlurstueckGroesse::Hektar { .. } => FlurstueckGroesse::hektar_aus_m2(m2),
+        }
+    }
+
+    fn hektar_aus_m2(m2: u64) -> FlurstueckGroesse {
+        FlurstueckGroesse::Hektar {
+            ha: Some(m2 / 10_000),
+            a: Some(m2 % 10_000 / 100),
+            m2: Some(m2 % 100),
+        }
+    }
+
     pub fn get_m2_string(&self) -> String {
         let m2_string = format!("{}", self.get_m2());
         let mut m2_string_chars: Vec<char> = m2_string.chars().collect();
@@ -381,7 +3202,7 @@ impl FlurstueckGroesse {
         m2_string_chars.reverse();
         let fi: String = m2_string_chars.iter().collect();
         if fi.is_empty() {
-            format!("0")
+            "0".to_string()
         } else {
             fi
         }
@@ -390,58 +3211,122 @@ impl FlurstueckGroesse {
 
 /// Position eines Textblocks im PDF
 #[derive(Debug, Default, Clone, PartialOrd, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
 pub struct PositionInPdf {
     /// Seite, auf der der Text gefunden wurde
+    #[serde(default)]
+    #[serde(skip_serializing_if = "String::is_empty")]
     pub seite: String,
     /// Koordinaten in Millimeter
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Rect::is_zero")]
     pub rect: Rect,
+    /// Weitere Rechtecke (Seite + Koordinaten in Millimeter) bei Einträgen, die über einen
+    /// Seitenumbruch oder mehrere Spalten verteilt sind
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(alias = "weitereRects")]
+    pub weitere_rects: Vec<(String, Rect)>,
+}
+
+impl PositionInPdf {
+    /// Ob weder Seite noch Koordinaten gesetzt sind
+    pub fn ist_leer(&self) -> bool {
+        self.seite.trim().is_empty() && self.rect.is_zero() && self.weitere_rects.is_empty()
+    }
+
+    /// Ob das Haupt-Rechteck oder eines der weiteren Rechtecke invertiert ist
+    pub fn ist_invertiert(&self) -> bool {
+        self.rect.ist_invertiert() || self.weitere_rects.iter().any(|(_, r)| r.ist_invertiert())
+    }
+
+    /// Normalisiert alle Rechtecke (siehe `Rect::normalize`), gibt zurück, ob mindestens
+    /// ein Rechteck invertiert war
+    pub fn normalisiere_rects(&mut self) -> bool {
+        let invertiert = self.ist_invertiert();
+        self.rect.normalize();
+        for (_, rect) in self.weitere_rects.iter_mut() {
+            rect.normalize();
+        }
+        invertiert
+    }
+
+    /// Haupt-Rechteck und weitere Rechtecke als (Seite, Rechteck), leere Rechtecke werden
+    /// übersprungen
+    pub fn alle_rects(&self) -> Vec<(&str, &Rect)> {
+        std::iter::once((self.seite.as_str(), &self.rect))
+            .chain(self.weitere_rects.iter().map(|(s, r)| (s.as_str(), r)))
+            .filter(|(_, r)| !r.is_zero())
+            .collect()
+    }
+
+    /// Für `skip_serializing_if`: `None` oder leere Position (siehe `ist_leer`)
+    pub fn option_ist_leer(position: &Option<PositionInPdf>) -> bool {
+        position.as_ref().is_none_or(PositionInPdf::ist_leer)
+    }
 }
 
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
 pub struct BvZuschreibung {
     #[serde(default)]
     #[serde(skip_serializing_if = "StringOrLines::is_empty")]
+    #[serde(alias = "bvNr")]
     pub bv_nr: StringOrLines,
     #[serde(default)]
     #[serde(skip_serializing_if = "StringOrLines::is_empty")]
     pub text: StringOrLines,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "automatischGeroetet")]
     pub automatisch_geroetet: Option<bool>,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "manuellGeroetet")]
     pub manuell_geroetet: Option<bool>,
     #[serde(default)]
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "PositionInPdf::option_ist_leer")]
+    #[serde(alias = "positionInPdf")]
     pub position_in_pdf: Option<PositionInPdf>,
 }
 
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
 pub struct BvAbschreibung {
     #[serde(default)]
     #[serde(skip_serializing_if = "StringOrLines::is_empty")]
+    #[serde(alias = "bvNr")]
     pub bv_nr: StringOrLines,
     #[serde(default)]
     #[serde(skip_serializing_if = "StringOrLines::is_empty")]
     pub text: StringOrLines,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "automatischGeroetet")]
     pub automatisch_geroetet: Option<bool>,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "manuellGeroetet")]
     pub manuell_geroetet: Option<bool>,
     #[serde(default)]
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "PositionInPdf::option_ist_leer")]
+    #[serde(alias = "positionInPdf")]
     pub position_in_pdf: Option<PositionInPdf>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
 pub struct Abteilung1 {
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub eintraege: Vec<Abt1Eintrag>,
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(alias = "grundlagenEintragungen")]
     pub grundlagen_eintragungen: Vec<Abt1GrundEintragung>,
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
@@ -451,7 +3336,7 @@ pub struct Abteilung1 {
     pub loeschungen: Vec<Abt1Loeschung>,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 #[repr(C)]
 pub enum Abt1Eintrag {
@@ -459,58 +3344,181 @@ pub enum Abt1Eintrag {
     V2(Abt1EintragV2),
 }
 
+/// Flache Darstellung eines `Abt1Eintrag` beim Einlesen, aus der anhand von `version`
+/// die Variante bestimmt wird
+#[derive(Deserialize)]
+struct Abt1EintragRoh {
+    /// Pflichtfeld, nur mit `skip-zero-lfd-nr` (wo `0` weggelassen wird) optional
+    #[cfg_attr(feature = "skip-zero-lfd-nr", serde(default))]
+    #[serde(deserialize_with = "usize_from_str_or_num")]
+    #[serde(alias = "lfdNr")]
+    lfd_nr: usize,
+    #[serde(default)]
+    #[serde(alias = "eigentümer")]
+    eigentuemer: StringOrLines,
+    #[serde(default)]
+    #[serde(alias = "bvNr")]
+    bv_nr: Option<StringOrLines>,
+    #[serde(default)]
+    #[serde(alias = "grundlageDerEintragung")]
+    grundlage_der_eintragung: Option<StringOrLines>,
+    #[serde(default)]
+    #[serde(deserialize_with = "option_usize_from_str_or_num")]
+    version: Option<usize>,
+    #[serde(default)]
+    #[serde(alias = "automatischGeroetet")]
+    automatisch_geroetet: Option<bool>,
+    #[serde(default)]
+    #[serde(alias = "manuellGeroetet")]
+    manuell_geroetet: Option<bool>,
+    #[serde(default)]
+    #[serde(alias = "positionInPdf")]
+    position_in_pdf: Option<PositionInPdf>,
+}
+
+/// Liest einen `Abt1Eintrag` ein: ohne `version` (oder mit `version: 1`) als
+/// `Abt1EintragV1`, ab `version: 2` als `Abt1EintragV2`. Ein V2-Eintrag mit den nur in V1
+/// vorhandenen Feldern `bv_nr` / `grundlage_der_eintragung` ist widersprüchlich und wird mit
+/// einem Fehler abgelehnt (statt wie bei `untagged` still die erste passende Variante zu wählen).
+impl<'de> Deserialize<'de> for Abt1Eintrag {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        let roh = Abt1EintragRoh::deserialize(deserializer)?;
+
+        match roh.version {
+            None | Some(1) => Ok(Abt1Eintrag::V1(Abt1EintragV1 {
+                lfd_nr: roh.lfd_nr,
+                eigentuemer: roh.eigentuemer,
+                bv_nr: roh.bv_nr.unwrap_or_default(),
+                grundlage_der_eintragung: roh.grundlage_der_eintragung.unwrap_or_default(),
+                automatisch_geroetet: roh.automatisch_geroetet,
+                manuell_geroetet: roh.manuell_geroetet,
+                position_in_pdf: roh.position_in_pdf,
+            })),
+            Some(0) => Err(D::Error::custom(format!(
+                "Abt. 1 Eintrag lfd. Nr. {}: ungültige version 0",
+                roh.lfd_nr
+            ))),
+            Some(version) => {
+                if roh.bv_nr.is_some() || roh.grundlage_der_eintragung.is_some() {
+                    return Err(D::Error::custom(format!(
+                        "Abt. 1 Eintrag lfd. Nr. {} ist widersprüchlich: version {version}, \
+                         aber bv_nr / grundlage_der_eintragung (nur in Version 1)",
+                        roh.lfd_nr
+                    )));
+                }
+                Ok(Abt1Eintrag::V2(Abt1EintragV2 {
+                    lfd_nr: roh.lfd_nr,
+                    eigentuemer: roh.eigentuemer,
+                    version,
+                    automatisch_geroetet: roh.automatisch_geroetet,
+                    manuell_geroetet: roh.manuell_geroetet,
+                    position_in_pdf: roh.position_in_pdf,
+                }))
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
 pub struct Abt1EintragV2 {
     // lfd. Nr. der Eintragung
+    #[cfg_attr(feature = "skip-zero-lfd-nr", serde(default))]
+    #[cfg_attr(
+        feature = "skip-zero-lfd-nr",
+        serde(skip_serializing_if = "ist_null_usize")
+    )]
+    #[serde(deserialize_with = "usize_from_str_or_num")]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(schema_with = "schema_usize_from_str_or_num")
+    )]
+    #[serde(alias = "lfdNr")]
     pub lfd_nr: usize,
     // Rechtstext
     #[serde(default)]
     #[serde(skip_serializing_if = "StringOrLines::is_empty")]
+    #[serde(alias = "eigentümer")]
     pub eigentuemer: StringOrLines,
     // Used to distinguish from Abt1EintragV1
+    #[cfg_attr(
+        feature = "schema",
+        schemars(schema_with = "schema_usize_from_str_or_num")
+    )]
     pub version: usize,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "automatischGeroetet")]
     pub automatisch_geroetet: Option<bool>,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "manuellGeroetet")]
     pub manuell_geroetet: Option<bool>,
     #[serde(default)]
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "PositionInPdf::option_ist_leer")]
+    #[serde(alias = "positionInPdf")]
     pub position_in_pdf: Option<PositionInPdf>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
 pub struct Abt1EintragV1 {
     // lfd. Nr. der Eintragung
+    #[cfg_attr(feature = "skip-zero-lfd-nr", serde(default))]
+    #[cfg_attr(
+        feature = "skip-zero-lfd-nr",
+        serde(skip_serializing_if = "ist_null_usize")
+    )]
+    #[serde(deserialize_with = "usize_from_str_or_num")]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(schema_with = "schema_usize_from_str_or_num")
+    )]
+    #[serde(alias = "lfdNr")]
     pub lfd_nr: usize,
     // Rechtstext
     #[serde(default)]
     #[serde(skip_serializing_if = "StringOrLines::is_empty")]
+    #[serde(alias = "eigentümer")]
     pub eigentuemer: StringOrLines,
     // lfd. Nr der betroffenen Grundstücke im Bestandsverzeichnis
     #[serde(default)]
     #[serde(skip_serializing_if = "StringOrLines::is_empty")]
+    #[serde(alias = "bvNr")]
     pub bv_nr: StringOrLines,
     // Vec<BvNr>,
     #[serde(default)]
     #[serde(skip_serializing_if = "StringOrLines::is_empty")]
+    #[serde(alias = "grundlageDerEintragung")]
     pub grundlage_der_eintragung: StringOrLines,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "automatischGeroetet")]
     pub automatisch_geroetet: Option<bool>,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "manuellGeroetet")]
     pub manuell_geroetet: Option<bool>,
     #[serde(default)]
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "PositionInPdf::option_ist_leer")]
+    #[serde(alias = "positionInPdf")]
     pub position_in_pdf: Option<PositionInPdf>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
 pub struct Abt1GrundEintragung {
     // lfd. Nr. der Eintragung
     #[serde(default)]
     #[serde(skip_serializing_if = "StringOrLines::is_empty")]
+    #[serde(alias = "bvNr")]
     pub bv_nr: StringOrLines,
     // Grundlage der Eintragung
     #[serde(default)]
@@ -518,23 +3526,71 @@ pub struct Abt1GrundEintragung {
     pub text: StringOrLines,
 
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "automatischGeroetet")]
     pub automatisch_geroetet: Option<bool>,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "manuellGeroetet")]
     pub manuell_geroetet: Option<bool>,
     #[serde(default)]
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "PositionInPdf::option_ist_leer")]
+    #[serde(alias = "positionInPdf")]
     pub position_in_pdf: Option<PositionInPdf>,
 }
 
-/// String mit Option für mehreren Zeilen, zur Vermeidung von Problemen mit Zeilenumbrüchen
-#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+/// String mit Option für mehreren Zeilen, zur Vermeidung von Problemen mit Zeilenumbrüchen.
+/// Beim Einlesen werden Zeilenenden (`\r\n`, `\n`, `\r`) normalisiert: `SingleLine` enthält
+/// nur `\n`, die Zeilen von `MultiLine` enthalten keine Zeilenende-Zeichen.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
 #[serde(untagged)]
 pub enum StringOrLines {
     SingleLine(String),
     MultiLine(Vec<String>),
 }
 
+impl<'de> Deserialize<'de> for StringOrLines {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Roh {
+            SingleLine(String),
+            MultiLine(Vec<String>),
+        }
+
+        Ok(match Roh::deserialize(deserializer)? {
+            Roh::SingleLine(s) => StringOrLines::SingleLine(zeilenenden_normalisieren(&s)),
+            Roh::MultiLine(ml) => {
+                StringOrLines::MultiLine(ml.iter().flat_map(|z| zeilen_von(z, true)).collect())
+            }
+        })
+    }
+}
+
+/// Ersetzt `\r\n` und einzelne `\r` durch `\n`
+fn zeilenenden_normalisieren(s: &str) -> String {
+    s.replace("\r\n", "\n").replace('\r', "\n")
+}
+
+/// Zerlegt `s` an `\r\n`, `\n` und `\r` in Zeilen (ohne Zeilenende-Zeichen), ein
+/// abschließender Zeilenumbruch ergibt keine zusätzliche leere Zeile. Mit `leer_als_zeile`
+/// ergibt ein leerer String eine leere Zeile statt keiner.
+fn zeilen_von(s: &str, leer_als_zeile: bool) -> Vec<String> {
+    if s.is_empty() {
+        return if leer_als_zeile {
+            vec![String::new()]
+        } else {
+            Vec::new()
+        };
+    }
+    zeilenenden_normalisieren(s)
+        .lines()
+        .map(|l| l.to_string())
+        .collect()
+}
+
 impl StringOrLines {
     pub fn is_empty(&self) -> bool {
         match self {
@@ -544,64 +3600,516 @@ impl StringOrLines {
     }
 
     pub fn text(&self) -> String {
-        self.lines().join("\r\n")
+        self.text_mit("\r\n")
+    }
+
+    /// Text mit `trenner` zwischen den Zeilen (z.B. `"\n"` statt `"\r\n"` wie bei `text`)
+    pub fn text_mit(&self, trenner: &str) -> String {
+        self.lines().join(trenner)
     }
 
     pub fn text_clean(&self) -> String {
         unhyphenate(&self.lines().join("\r\n"))
     }
 
+    /// Zeilen ohne Zeilenende-Zeichen (`\r\n`, `\n` und `\r` gelten als Zeilenende, auch
+    /// innerhalb einzelner Zeilen von `MultiLine`)
     pub fn lines(&self) -> Vec<String> {
         match self {
-            StringOrLines::SingleLine(s) => s.lines().map(|s| s.to_string()).collect(),
-            StringOrLines::MultiLine(ml) => ml.clone(),
+            StringOrLines::SingleLine(s) => zeilen_von(s, false),
+            StringOrLines::MultiLine(ml) => ml.iter().flat_map(|z| zeilen_von(z, true)).collect(),
         }
     }
-}
 
-lazy_static::lazy_static! {
-    static ref REGEX_UNHYPHENATE: regex::Regex = {
-        regex::RegexBuilder::new("(.*)-\\s([a-züäö])(.*)")
-                .multi_line(true)
-                .case_insensitive(false)
-                .build().unwrap()
-    };
-}
+    /// Alle im Text genannten laufenden Nummern, z.B. "1, 2, 4-6" oder "1 bis 3"
+    pub fn nummern(&self) -> Vec<usize> {
+        parse_nummern(&self.text())
+    }
 
-impl Abt1EintragV1 {
-    pub fn ist_geroetet(&self) -> bool {
-        self.manuell_geroetet
-            .or(self.automatisch_geroetet.clone())
-            .unwrap_or(false)
+    /// Alle im Text genannten laufenden Nummern einschließlich Buchstabenzusatz, z.B. "1a, 2"
+    /// (siehe `parse_laufende_nummern`)
+    pub fn laufende_nummern(&self) -> Vec<LaufendeNummer> {
+        parse_laufende_nummern(&self.text())
     }
-}
 
-impl BvEintrag {
-    pub fn ist_geroetet(&self) -> bool {
-        match self {
-            BvEintrag::Flurstueck(flst) => flst
-                .manuell_geroetet
-                .unwrap_or(flst.automatisch_geroetet.unwrap_or(false)),
-            BvEintrag::Recht(recht) => recht
-                .manuell_geroetet
-                .unwrap_or(recht.automatisch_geroetet.unwrap_or(false)),
-        }
+    /// Trimmt jede Zeile und entfernt leere Zeilen am Anfang und Ende. Leere Zeilen innerhalb
+    /// des Texts werden nur erhalten, wenn `innere_leerzeilen_behalten` gesetzt ist.
+    pub fn trimmed(&self, innere_leerzeilen_behalten: bool) -> StringOrLines {
+        let zeilen = self
+            .lines()
+            .iter()
+            .map(|l| l.trim().to_string())
+            .collect::<Vec<_>>();
+        let start = zeilen.iter().position(|l| !l.is_empty());
+        let ende = zeilen.iter().rposition(|l| !l.is_empty());
+        let zeilen = match (start, ende) {
+            (Some(start), Some(ende)) => zeilen[start..=ende]
+                .iter()
+                .filter(|l| innere_leerzeilen_behalten || !l.is_empty())
+                .cloned()
+                .collect(),
+            _ => Vec::new(),
+        };
+        self.mit_zeilen(zeilen)
     }
-}
 
-impl Abt1GrundEintragung {
-    pub fn ist_geroetet(&self) -> bool {
-        self.manuell_geroetet
-            .or(self.automatisch_geroetet.clone())
-            .unwrap_or(false)
+    /// Gleicher Text als `MultiLine`
+    pub fn to_multiline(&self) -> StringOrLines {
+        StringOrLines::MultiLine(self.lines())
+    }
+
+    /// Einzeiliger Text: die (getrimmten) nicht-leeren Zeilen werden mit einem Leerzeichen
+    /// verbunden, z.B. für `lfd_nr` der Veränderungen
+    pub fn into_single_line(self) -> StringOrLines {
+        StringOrLines::SingleLine(zeilen_verbinden(&self.lines()))
+    }
+
+    /// Gleicher Text als `MultiLine`, z.B. für Rechtstexte
+    pub fn into_multi_line(self) -> StringOrLines {
+        match self {
+            StringOrLines::SingleLine(_) => self.to_multiline(),
+            StringOrLines::MultiLine(_) => self,
+        }
+    }
+
+    /// Fasst mehrfache Leerzeichen / Tabs innerhalb jeder Zeile zu einem Leerzeichen zusammen
+    pub fn collapse_whitespace(&self) -> StringOrLines {
+        let zeilen = self
+            .lines()
+            .iter()
+            .map(|l| {
+                let mut out = String::with_capacity(l.len());
+                let mut letztes_leer = false;
+                for c in l.chars() {
+                    if c.is_whitespace() {
+                        if !letztes_leer {
+                            out.push(' ');
+                        }
+                        letztes_leer = true;
+                    } else {
+                        out.push(c);
+                        letztes_leer = false;
+                    }
+                }
+                out
+            })
+            .collect();
+        self.mit_zeilen(zeilen)
+    }
+
+    /// Baut aus `zeilen` wieder einen Wert derselben Variante (einzeilig / mehrzeilig)
+    fn mit_zeilen(&self, zeilen: Vec<String>) -> StringOrLines {
+        match self {
+            StringOrLines::SingleLine(s) => {
+                let trenner = if s.contains("\r\n") { "\r\n" } else { "\n" };
+                StringOrLines::SingleLine(zeilen.join(trenner))
+            }
+            StringOrLines::MultiLine(_) => StringOrLines::MultiLine(zeilen),
+        }
+    }
+}
+
+/// Parst Nummernangaben wie "1, 2, 4-6", "1 u. 3" oder "1 bis 3" in eine
+/// sortierte, deduplizierte Liste von Nummern
+pub fn parse_nummern(text: &str) -> Vec<usize> {
+    let mut nummern = Vec::new();
+    for cap in REGEX_NUMMERN.captures_iter(text) {
+        let von = match cap.get(1).and_then(|m| m.as_str().parse::<usize>().ok()) {
+            Some(s) => s,
+            None => continue,
+        };
+        let bis = cap.get(2).and_then(|m| m.as_str().parse::<usize>().ok());
+        match bis {
+            Some(bis) if bis >= von && bis - von <= MAX_NUMMERN_BEREICH => {
+                nummern.extend(von..=bis);
+            }
+            Some(bis) => {
+                nummern.push(von);
+                nummern.push(bis);
+            }
+            None => nummern.push(von),
+        }
+    }
+    nummern.sort_unstable();
+    nummern.dedup();
+    nummern
+}
+
+/// Maximale Länge eines Bereichs ("1-5") in `parse_nummern`, längere Bereiche
+/// sind meist OCR-Fehler und werden nicht aufgefüllt
+const MAX_NUMMERN_BEREICH: usize = 1000;
+
+lazy_static::lazy_static! {
+    static ref REGEX_UNHYPHENATE: regex::Regex = {
+        regex::RegexBuilder::new("(.*)-\\s([a-züäö])(.*)")
+                .multi_line(true)
+                .case_insensitive(false)
+                .build().unwrap()
+    };
+    static ref REGEX_NUMMERN: regex::Regex = {
+        regex::Regex::new("(\\d+)(?:\\s*(?:-|–|bis)\\s*(\\d+))?").unwrap()
+    };
+}
+
+/// Verbindet die getrimmten, nicht-leeren Zeilen mit einem Leerzeichen
+fn zeilen_verbinden(zeilen: &[String]) -> String {
+    zeilen
+        .iter()
+        .map(|l| l.trim())
+        .filter(|l| !l.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// String, der garantiert keine Zeilenumbrüche enthält: wird als einfacher String serialisiert,
+/// beim Einlesen werden mehrzeilige Strings oder Zeilen-Arrays (wie bei `StringOrLines`)
+/// mit Leerzeichen verbunden
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(transparent)]
+pub struct SingleLineString(String);
+
+impl SingleLineString {
+    /// Verbindet die Zeilen von `text` mit Leerzeichen
+    pub fn new(text: &str) -> SingleLineString {
+        SingleLineString(zeilen_verbinden(
+            &text.lines().map(|l| l.to_string()).collect::<Vec<_>>(),
+        ))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl<'de> Deserialize<'de> for SingleLineString {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let text = StringOrLines::deserialize(deserializer)?;
+        Ok(SingleLineString(zeilen_verbinden(&text.lines())))
+    }
+}
+
+impl std::fmt::Display for SingleLineString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<StringOrLines> for SingleLineString {
+    fn from(s: StringOrLines) -> SingleLineString {
+        SingleLineString(zeilen_verbinden(&s.lines()))
+    }
+}
+
+impl From<SingleLineString> for StringOrLines {
+    fn from(s: SingleLineString) -> StringOrLines {
+        StringOrLines::SingleLine(s.0)
+    }
+}
+
+/// Deserialisiert ein `usize`-Feld, das entweder als Zahl (`12`) oder als Zahlstring (`"12"`) vorliegt
+pub fn usize_from_str_or_num<'de, D>(deserializer: D) -> Result<usize, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    UsizeOderString::deserialize(deserializer)?
+        .0
+        .ok_or_else(|| serde::de::Error::custom("leere Zahl"))
+}
+
+/// Wie `usize_from_str_or_num`, aber für optionale Felder (`null` und `""` ergeben `None`)
+pub fn option_usize_from_str_or_num<'de, D>(deserializer: D) -> Result<Option<usize>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Option::<UsizeOderString>::deserialize(deserializer).map(|o| o.and_then(|u| u.0))
+}
+
+/// Hilfstyp für `usize_from_str_or_num`, `None` bei leerem String
+struct UsizeOderString(Option<usize>);
+
+impl<'de> Deserialize<'de> for UsizeOderString {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct UsizeVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for UsizeVisitor {
+            type Value = UsizeOderString;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("eine positive Ganzzahl oder einen Zahlstring")
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                let u = usize::try_from(v).map_err(E::custom)?;
+                Ok(UsizeOderString(Some(u)))
+            }
+
+            fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<Self::Value, E> {
+                let u = usize::try_from(v).map_err(E::custom)?;
+                Ok(UsizeOderString(Some(u)))
+            }
+
+            fn visit_f64<E: serde::de::Error>(self, v: f64) -> Result<Self::Value, E> {
+                if v.fract() != 0.0 || v < 0.0 || v > usize::MAX as f64 {
+                    return Err(E::custom(format!("ungültige laufende Nummer: {v}")));
+                }
+                Ok(UsizeOderString(Some(v as usize)))
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                let v = v.trim();
+                if v.is_empty() {
+                    return Ok(UsizeOderString(None));
+                }
+                let u = v
+                    .parse::<usize>()
+                    .map_err(|e| E::custom(format!("ungültige Zahl \"{v}\": {e}")))?;
+                Ok(UsizeOderString(Some(u)))
+            }
+        }
+
+        deserializer.deserialize_any(UsizeVisitor)
+    }
+}
+
+/// Eindeutige Kombination aus `automatisch_geroetet` und `manuell_geroetet`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Roetungsstatus {
+    /// Nicht gerötet
+    Aktiv,
+    /// Automatisch als gerötet erkannt, ohne manuelle Angabe
+    AutomatischGeroetet,
+    /// Manuell als gerötet markiert
+    ManuellGeroetet,
+    /// Automatisch als gerötet erkannt, vom Nutzer aber zurückgenommen
+    ManuellEntroetet,
+}
+
+impl Roetungsstatus {
+    pub fn aus_flags(automatisch_geroetet: Option<bool>, manuell_geroetet: Option<bool>) -> Self {
+        match (manuell_geroetet, automatisch_geroetet) {
+            (Some(true), _) => Roetungsstatus::ManuellGeroetet,
+            (Some(false), Some(true)) => Roetungsstatus::ManuellEntroetet,
+            (Some(false), _) => Roetungsstatus::Aktiv,
+            (None, Some(true)) => Roetungsstatus::AutomatischGeroetet,
+            (None, _) => Roetungsstatus::Aktiv,
+        }
+    }
+
+    pub fn ist_geroetet(&self) -> bool {
+        match self {
+            Roetungsstatus::ManuellGeroetet | Roetungsstatus::AutomatischGeroetet => true,
+            Roetungsstatus::Aktiv | Roetungsstatus::ManuellEntroetet => false,
+        }
+    }
+}
+
+impl Abt1EintragV1 {
+    pub fn roetungsstatus(&self) -> Roetungsstatus {
+        Roetungsstatus::aus_flags(self.automatisch_geroetet, self.manuell_geroetet)
+    }
+
+    pub fn ist_geroetet(&self) -> bool {
+        self.roetungsstatus().ist_geroetet()
+    }
+}
+
+/// Problem mit der Flurstücksbezeichnung eines `BvEintragFlurstueck`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum FlurstueckFehler {
+    /// `flurstueck` ist leer
+    Leer,
+    /// Bezeichnung ist erkennbar, aber nicht in der Form "Zähler" / "Zähler/Nenner"
+    /// (z.B. "12-3" oder "12.3" aus der OCR)
+    NichtNormalisiert { vorschlag: String },
+    /// Bezeichnung entspricht keinem bekannten Muster
+    Ungueltig,
+}
+
+impl std::fmt::Display for FlurstueckFehler {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            FlurstueckFehler::Leer => write!(f, "Flurstück ist leer"),
+            FlurstueckFehler::NichtNormalisiert { vorschlag } => {
+                write!(f, "Flurstück sollte \"{vorschlag}\" lauten")
+            }
+            FlurstueckFehler::Ungueltig => write!(f, "Flurstück ist ungültig"),
+        }
+    }
+}
+
+impl std::error::Error for FlurstueckFehler {}
+
+impl BvEintragFlurstueck {
+    /// Neues Flurstück ohne Gemarkung, Bezeichnung, Größe und Rötung
+    pub fn new(lfd_nr: usize, flur: usize, flurstueck: impl Into<String>) -> Self {
+        BvEintragFlurstueck {
+            lfd_nr,
+            bisherige_lfd_nr: None,
+            flur,
+            flurstueck: flurstueck.into(),
+            gemarkung: None,
+            bezeichnung: None,
+            groesse: FlurstueckGroesse::default(),
+            automatisch_geroetet: None,
+            manuell_geroetet: None,
+            position_in_pdf: None,
+        }
+    }
+
+    /// Gemarkung des Flurstücks, falls gesetzt, sonst `grundbuch_von` aus dem Titelblatt
+    pub fn effektive_gemarkung(&self, titel: &Titelblatt) -> String {
+        match self.gemarkung.as_deref().map(str::trim) {
+            Some(g) if !g.is_empty() => g.to_string(),
+            _ => titel.grundbuch_von.trim().to_string(),
+        }
+    }
+
+    /// Fachlicher Schlüssel "Gemarkung-Flur-Flurstück" zum Abgleich und zur Deduplizierung,
+    /// mit normalisierter Gemarkung (siehe `effektive_gemarkung`, `normalisiere_suchtext`) und
+    /// normalisiertem Flurstück (siehe `flurstueck_normalisiert`)
+    pub fn fachschluessel(&self, titel: &Titelblatt) -> String {
+        let flurstueck = self
+            .flurstueck_normalisiert()
+            .unwrap_or_else(|| normalisiere_suchtext(&self.flurstueck).replace(' ', ""));
+        format!(
+            "{}-{}-{}",
+            normalisiere_suchtext(&self.effektive_gemarkung(titel)),
+            self.flur,
+            flurstueck
+        )
+    }
+
+    /// Prüft, ob `flurstueck` die Form "Zähler" oder "Zähler/Nenner" hat
+    pub fn pruefe_flurstueck(&self) -> Option<FlurstueckFehler> {
+        if self.flurstueck.trim().is_empty() {
+            return Some(FlurstueckFehler::Leer);
+        }
+        if REGEX_FLURSTUECK.is_match(&self.flurstueck) {
+            return None;
+        }
+        match self.flurstueck_normalisiert() {
+            Some(vorschlag) if vorschlag != self.flurstueck => {
+                Some(FlurstueckFehler::NichtNormalisiert { vorschlag })
+            }
+            _ => Some(FlurstueckFehler::Ungueltig),
+        }
+    }
+
+    /// Zähler und Nenner des Flurstücks, toleriert "12-3", "12.3" und "12 / 3"
+    pub fn flurstueck_zaehler_nenner(&self) -> Option<(u32, Option<u32>)> {
+        let cap = REGEX_FLURSTUECK_TOLERANT.captures(&self.flurstueck)?;
+        let zaehler = cap.get(1)?.as_str().parse().ok()?;
+        let nenner = match cap.get(2) {
+            Some(n) => Some(n.as_str().parse().ok()?),
+            None => None,
+        };
+        Some((zaehler, nenner))
+    }
+
+    /// Flurstück in der Form "Zähler" / "Zähler/Nenner", falls erkennbar
+    pub fn flurstueck_normalisiert(&self) -> Option<String> {
+        Some(match self.flurstueck_zaehler_nenner()? {
+            (z, Some(n)) => format!("{z}/{n}"),
+            (z, None) => format!("{z}"),
+        })
+    }
+
+    pub fn roetungsstatus(&self) -> Roetungsstatus {
+        Roetungsstatus::aus_flags(self.automatisch_geroetet, self.manuell_geroetet)
+    }
+
+    pub fn ist_geroetet(&self) -> bool {
+        self.roetungsstatus().ist_geroetet()
+    }
+}
+
+lazy_static::lazy_static! {
+    static ref REGEX_FLURSTUECK: regex::Regex = {
+        regex::Regex::new("^[1-9]\\d*(?:/[1-9]\\d*)?$").unwrap()
+    };
+    static ref REGEX_FLURSTUECK_TOLERANT: regex::Regex = {
+        regex::Regex::new("^\\s*0*(\\d+)\\s*(?:[/\\-.–]\\s*0*(\\d+))?\\s*$").unwrap()
+    };
+}
+
+impl BvEintragRecht {
+    pub fn roetungsstatus(&self) -> Roetungsstatus {
+        Roetungsstatus::aus_flags(self.automatisch_geroetet, self.manuell_geroetet)
+    }
+
+    pub fn ist_geroetet(&self) -> bool {
+        self.roetungsstatus().ist_geroetet()
+    }
+}
+
+impl BvEintrag {
+    pub fn roetungsstatus(&self) -> Roetungsstatus {
+        match self {
+            BvEintrag::Flurstueck(flst) => flst.roetungsstatus(),
+            BvEintrag::Recht(recht) => recht.roetungsstatus(),
+        }
+    }
+
+    pub fn ist_geroetet(&self) -> bool {
+        self.roetungsstatus().ist_geroetet()
+    }
+
+    pub fn get_lfd_nr(&self) -> usize {
+        match self {
+            BvEintrag::Flurstueck(flst) => flst.lfd_nr,
+            BvEintrag::Recht(recht) => recht.lfd_nr,
+        }
+    }
+
+    /// `false` bei `lfd_nr == 0`, also bei Platzhaltern ohne gesetzte laufende Nummer
+    pub fn hat_gueltige_lfd_nr(&self) -> bool {
+        self.get_lfd_nr() != 0
+    }
+
+    pub fn get_bisherige_lfd_nr(&self) -> Option<usize> {
+        match self {
+            BvEintrag::Flurstueck(flst) => flst.bisherige_lfd_nr,
+            BvEintrag::Recht(recht) => recht.bisherige_lfd_nr,
+        }
+    }
+
+    /// Fachliche Ordnung nach `lfd_nr`, dann `bisherige_lfd_nr`, unabhängig davon,
+    /// ob Flurstück oder Recht (das abgeleitete `PartialOrd` vergleicht strukturell
+    /// nach Variante), z.B. `eintraege.sort_by(BvEintrag::cmp_by_lfd_nr)`
+    pub fn cmp_by_lfd_nr(&self, other: &BvEintrag) -> std::cmp::Ordering {
+        self.get_lfd_nr().cmp(&other.get_lfd_nr()).then_with(|| {
+            self.get_bisherige_lfd_nr()
+                .cmp(&other.get_bisherige_lfd_nr())
+        })
+    }
+}
+
+impl Abt1GrundEintragung {
+    pub fn roetungsstatus(&self) -> Roetungsstatus {
+        Roetungsstatus::aus_flags(self.automatisch_geroetet, self.manuell_geroetet)
+    }
+
+    pub fn ist_geroetet(&self) -> bool {
+        self.roetungsstatus().ist_geroetet()
     }
 }
 
 impl BvZuschreibung {
+    pub fn roetungsstatus(&self) -> Roetungsstatus {
+        Roetungsstatus::aus_flags(self.automatisch_geroetet, self.manuell_geroetet)
+    }
+
     pub fn ist_geroetet(&self) -> bool {
-        self.manuell_geroetet
-            .or(self.automatisch_geroetet.clone())
-            .unwrap_or(false)
+        self.roetungsstatus().ist_geroetet()
     }
     pub fn ist_leer(&self) -> bool {
         self.bv_nr.is_empty() && self.text.is_empty()
@@ -609,10 +4117,12 @@ impl BvZuschreibung {
 }
 
 impl BvAbschreibung {
+    pub fn roetungsstatus(&self) -> Roetungsstatus {
+        Roetungsstatus::aus_flags(self.automatisch_geroetet, self.manuell_geroetet)
+    }
+
     pub fn ist_geroetet(&self) -> bool {
-        self.manuell_geroetet
-            .or(self.automatisch_geroetet.clone())
-            .unwrap_or(false)
+        self.roetungsstatus().ist_geroetet()
     }
 
     pub fn ist_leer(&self) -> bool {
@@ -621,10 +4131,24 @@ impl BvAbschreibung {
 }
 
 impl Abt1EintragV2 {
+    /// Neuer Eintrag (`version` 2) ohne Rötung und Position
+    pub fn new(lfd_nr: usize, eigentuemer: impl Into<StringOrLines>) -> Self {
+        Abt1EintragV2 {
+            lfd_nr,
+            eigentuemer: eigentuemer.into(),
+            version: 2,
+            automatisch_geroetet: None,
+            manuell_geroetet: None,
+            position_in_pdf: None,
+        }
+    }
+
+    pub fn roetungsstatus(&self) -> Roetungsstatus {
+        Roetungsstatus::aus_flags(self.automatisch_geroetet, self.manuell_geroetet)
+    }
+
     pub fn ist_geroetet(&self) -> bool {
-        self.manuell_geroetet
-            .or(self.automatisch_geroetet.clone())
-            .unwrap_or(false)
+        self.roetungsstatus().ist_geroetet()
     }
 }
 
@@ -636,6 +4160,11 @@ impl Abt1Eintrag {
         }
     }
 
+    /// `false` bei `lfd_nr == 0`, also bei Platzhaltern ohne gesetzte laufende Nummer
+    pub fn hat_gueltige_lfd_nr(&self) -> bool {
+        self.get_lfd_nr() != 0
+    }
+
     pub fn get_eigentuemer(&self) -> StringOrLines {
         match self {
             Abt1Eintrag::V1(v1) => v1.eigentuemer.clone(),
@@ -643,27 +4172,93 @@ impl Abt1Eintrag {
         }
     }
 
-    pub fn ist_geroetet(&self) -> bool {
+    pub fn roetungsstatus(&self) -> Roetungsstatus {
         match self {
-            Abt1Eintrag::V1(v1) => v1.ist_geroetet(),
-            Abt1Eintrag::V2(v2) => v2.ist_geroetet(),
+            Abt1Eintrag::V1(v1) => v1.roetungsstatus(),
+            Abt1Eintrag::V2(v2) => v2.roetungsstatus(),
         }
     }
+
+    pub fn ist_geroetet(&self) -> bool {
+        self.roetungsstatus().ist_geroetet()
+    }
 }
 
 impl Abt2Eintrag {
+    /// Neuer Eintrag ohne lfd. Nr.-Zusatz, Rötung und Position
+    pub fn new(
+        lfd_nr: usize,
+        bv_nr: impl Into<StringOrLines>,
+        text: impl Into<StringOrLines>,
+    ) -> Self {
+        Abt2Eintrag {
+            lfd_nr,
+            lfd_nr_zusatz: None,
+            bv_nr: bv_nr.into(),
+            text: text.into(),
+            automatisch_geroetet: None,
+            manuell_geroetet: None,
+            position_in_pdf: None,
+        }
+    }
+
+    pub fn roetungsstatus(&self) -> Roetungsstatus {
+        Roetungsstatus::aus_flags(self.automatisch_geroetet, self.manuell_geroetet)
+    }
+
     pub fn ist_geroetet(&self) -> bool {
-        self.manuell_geroetet
-            .or(self.automatisch_geroetet.clone())
-            .unwrap_or(false)
+        self.roetungsstatus().ist_geroetet()
+    }
+
+    /// `false` bei `lfd_nr == 0`, also bei Platzhaltern ohne gesetzte laufende Nummer
+    pub fn hat_gueltige_lfd_nr(&self) -> bool {
+        self.lfd_nr != 0
+    }
+
+    /// Laufende Nummer einschließlich Buchstabenzusatz, z.B. "1a"
+    pub fn lfd_nr_mit_zusatz(&self) -> String {
+        match self.lfd_nr_zusatz.as_deref().map(str::trim) {
+            Some(z) if !z.is_empty() => format!("{}{z}", self.lfd_nr),
+            _ => self.lfd_nr.to_string(),
+        }
+    }
+
+    /// Vollständige laufende Nummer (mit Zusatz) zum Abgleich mit Veränderungen / Löschungen
+    pub fn laufende_nummer(&self) -> LaufendeNummer {
+        LaufendeNummer::mit_zusatz(self.lfd_nr, self.lfd_nr_zusatz.as_deref())
     }
 }
 
 impl Abt3Eintrag {
+    /// Neuer Eintrag ohne Rötung und Position
+    pub fn new(
+        lfd_nr: usize,
+        bv_nr: impl Into<StringOrLines>,
+        betrag: impl Into<StringOrLines>,
+        text: impl Into<StringOrLines>,
+    ) -> Self {
+        Abt3Eintrag {
+            lfd_nr,
+            bv_nr: bv_nr.into(),
+            betrag: betrag.into(),
+            text: text.into(),
+            automatisch_geroetet: None,
+            manuell_geroetet: None,
+            position_in_pdf: None,
+        }
+    }
+
+    pub fn roetungsstatus(&self) -> Roetungsstatus {
+        Roetungsstatus::aus_flags(self.automatisch_geroetet, self.manuell_geroetet)
+    }
+
     pub fn ist_geroetet(&self) -> bool {
-        self.manuell_geroetet
-            .or(self.automatisch_geroetet.clone())
-            .unwrap_or(false)
+        self.roetungsstatus().ist_geroetet()
+    }
+
+    /// `false` bei `lfd_nr == 0`, also bei Platzhaltern ohne gesetzte laufende Nummer
+    pub fn hat_gueltige_lfd_nr(&self) -> bool {
+        self.lfd_nr != 0
     }
 }
 
@@ -699,7 +4294,19 @@ impl Default for StringOrLines {
 
 impl From<String> for StringOrLines {
     fn from(s: String) -> StringOrLines {
-        StringOrLines::MultiLine(s.lines().map(|s| s.to_string()).collect())
+        StringOrLines::MultiLine(zeilen_von(&s, false))
+    }
+}
+
+impl From<&str> for StringOrLines {
+    fn from(s: &str) -> StringOrLines {
+        StringOrLines::MultiLine(zeilen_von(s, false))
+    }
+}
+
+impl From<Vec<String>> for StringOrLines {
+    fn from(zeilen: Vec<String>) -> StringOrLines {
+        StringOrLines::MultiLine(zeilen)
     }
 }
 
@@ -719,63 +4326,161 @@ impl Abteilung1 {
             && self.veraenderungen.is_empty()
             && self.loeschungen.is_empty()
     }
+
+    /// Prüft, ob alle in `bv_nr` der Einträge genannten Nummern im Bestandsverzeichnis existieren
+    /// (Einträge ohne `bv_nr`, z.B. `Abt1EintragV2`, werden übersprungen)
+    pub fn pruefe_bv_referenzen(&self, bv: &Bestandsverzeichnis) -> Vec<ReferenzFehler> {
+        let eintraege = self.eintraege.iter().filter_map(|e| match e {
+            Abt1Eintrag::V1(v1) => Some((v1.lfd_nr.into(), &v1.bv_nr)),
+            Abt1Eintrag::V2(_) => None,
+        });
+        bv_referenz_fehler(bv, eintraege)
+    }
+}
+
+impl Abteilung2 {
+    /// Prüft, ob alle in `bv_nr` der Einträge genannten Nummern im Bestandsverzeichnis existieren,
+    /// siehe `Abteilung1::pruefe_bv_referenzen`
+    pub fn pruefe_bv_referenzen(&self, bv: &Bestandsverzeichnis) -> Vec<ReferenzFehler> {
+        bv_referenz_fehler(
+            bv,
+            self.eintraege
+                .iter()
+                .map(|e| (e.laufende_nummer(), &e.bv_nr)),
+        )
+    }
+}
+
+impl Abteilung3 {
+    /// Prüft, ob alle in `bv_nr` der Einträge genannten Nummern im Bestandsverzeichnis existieren,
+    /// siehe `Abteilung1::pruefe_bv_referenzen`
+    pub fn pruefe_bv_referenzen(&self, bv: &Bestandsverzeichnis) -> Vec<ReferenzFehler> {
+        bv_referenz_fehler(
+            bv,
+            self.eintraege.iter().map(|e| (e.lfd_nr.into(), &e.bv_nr)),
+        )
+    }
+}
+
+fn bv_referenz_fehler<'a>(
+    bv: &Bestandsverzeichnis,
+    eintraege: impl Iterator<Item = (LaufendeNummer, &'a StringOrLines)>,
+) -> Vec<ReferenzFehler> {
+    let vorhanden = bv
+        .eintraege
+        .iter()
+        .map(|e| e.get_lfd_nr())
+        .collect::<std::collections::BTreeSet<_>>();
+
+    let mut fehler = Vec::new();
+    for (lfd_nr, bv_nr) in eintraege {
+        for bv_nr in bv_nr.nummern() {
+            if !vorhanden.contains(&bv_nr) {
+                fehler.push(ReferenzFehler {
+                    lfd_nr: lfd_nr.clone(),
+                    bv_nr,
+                });
+            }
+        }
+    }
+    fehler
+}
+
+/// Verweis eines Eintrags auf eine nicht existierende Nummer im Bestandsverzeichnis
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ReferenzFehler {
+    /// Vollständige lfd. Nr. des verweisenden Eintrags (in Abt. 2 mit Zusatz wie "1a")
+    pub lfd_nr: LaufendeNummer,
+    /// Nicht gefundene lfd. Nr. im Bestandsverzeichnis
+    pub bv_nr: usize,
+}
+
+impl std::fmt::Display for ReferenzFehler {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "Eintrag Nr. {} verweist auf nicht vorhandene Nr. {} im Bestandsverzeichnis",
+            self.lfd_nr, self.bv_nr
+        )
+    }
 }
 
+impl std::error::Error for ReferenzFehler {}
+
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
 pub struct Abt1Veraenderung {
     #[serde(default)]
     #[serde(skip_serializing_if = "StringOrLines::is_empty")]
+    #[serde(alias = "lfdNr")]
     pub lfd_nr: StringOrLines,
     #[serde(default)]
     #[serde(skip_serializing_if = "StringOrLines::is_empty")]
     pub text: StringOrLines,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "automatischGeroetet")]
     pub automatisch_geroetet: Option<bool>,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "manuellGeroetet")]
     pub manuell_geroetet: Option<bool>,
     #[serde(default)]
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "PositionInPdf::option_ist_leer")]
+    #[serde(alias = "positionInPdf")]
     pub position_in_pdf: Option<PositionInPdf>,
 }
 
 impl Abt1Veraenderung {
+    pub fn roetungsstatus(&self) -> Roetungsstatus {
+        Roetungsstatus::aus_flags(self.automatisch_geroetet, self.manuell_geroetet)
+    }
+
     pub fn ist_geroetet(&self) -> bool {
-        self.manuell_geroetet
-            .or(self.automatisch_geroetet.clone())
-            .unwrap_or(false)
+        self.roetungsstatus().ist_geroetet()
     }
 }
 
 #[derive(Debug, Default, PartialEq, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
 pub struct Abt1Loeschung {
     #[serde(default)]
     #[serde(skip_serializing_if = "StringOrLines::is_empty")]
+    #[serde(alias = "lfdNr")]
     pub lfd_nr: StringOrLines,
     #[serde(default)]
     #[serde(skip_serializing_if = "StringOrLines::is_empty")]
     pub text: StringOrLines,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "automatischGeroetet")]
     pub automatisch_geroetet: Option<bool>,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "manuellGeroetet")]
     pub manuell_geroetet: Option<bool>,
     #[serde(default)]
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "PositionInPdf::option_ist_leer")]
+    #[serde(alias = "positionInPdf")]
     pub position_in_pdf: Option<PositionInPdf>,
 }
 
 impl Abt1Loeschung {
+    pub fn roetungsstatus(&self) -> Roetungsstatus {
+        Roetungsstatus::aus_flags(self.automatisch_geroetet, self.manuell_geroetet)
+    }
+
     pub fn ist_geroetet(&self) -> bool {
-        self.manuell_geroetet
-            .or(self.automatisch_geroetet.clone())
-            .unwrap_or(false)
+        self.roetungsstatus().ist_geroetet()
     }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
 pub struct Abteilung2 {
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
@@ -788,89 +4493,446 @@ pub struct Abteilung2 {
     pub loeschungen: Vec<Abt2Loeschung>,
 }
 
+/// Basiseintrag mit allen Veränderungen und Löschungen, die sich über ihre `lfd_nr` darauf beziehen
+#[derive(Debug, Clone, PartialEq)]
+pub struct EintragHistorie<'a, E, V, L> {
+    /// Basiseintrag, `None` wenn keine Eintragung mit dieser Nummer existiert
+    pub eintrag: Option<&'a E>,
+    pub veraenderungen: Vec<&'a V>,
+    pub loeschungen: Vec<&'a L>,
+}
+
 impl Abteilung2 {
     pub fn is_empty(&self) -> bool {
         self.eintraege.is_empty() && self.veraenderungen.is_empty() && self.loeschungen.is_empty()
     }
+
+    /// Eintrag mit der laufenden Nummer und alle zugehörigen Veränderungen und Löschungen
+    /// (in Listenreihenfolge). Nr. 1 und Nr. 1a werden unterschieden.
+    pub fn historie(
+        &self,
+        eintrag_lfd_nr: impl Into<LaufendeNummer>,
+    ) -> EintragHistorie<'_, Abt2Eintrag, Abt2Veraenderung, Abt2Loeschung> {
+        let nr = eintrag_lfd_nr.into();
+        EintragHistorie {
+            eintrag: self.eintraege.iter().find(|e| e.laufende_nummer() == nr),
+            veraenderungen: self
+                .veraenderungen
+                .iter()
+                .filter(|v| v.lfd_nr.laufende_nummern().contains(&nr))
+                .collect(),
+            loeschungen: self
+                .loeschungen
+                .iter()
+                .filter(|l| l.lfd_nr.laufende_nummern().contains(&nr))
+                .collect(),
+        }
+    }
+
+    /// Rötet die Veränderungen und Löschungen geröteter Einträge mit (über ihre `lfd_nr`,
+    /// wie in `historie`), indem `automatisch_geroetet = Some(true)` gesetzt wird. Elemente mit
+    /// manueller Entscheidung (`manuell_geroetet` gesetzt) bleiben unverändert. Gibt die Anzahl
+    /// der geänderten Veränderungen und Löschungen zurück.
+    pub fn propagiere_roetung(&mut self, opts: RoetungPropagationOptions) -> usize {
+        let geroetet = self
+            .eintraege
+            .iter()
+            .filter(|e| match e.roetungsstatus() {
+                Roetungsstatus::ManuellGeroetet => true,
+                Roetungsstatus::AutomatischGeroetet => opts.auch_automatisch_geroetete,
+                Roetungsstatus::Aktiv | Roetungsstatus::ManuellEntroetet => false,
+            })
+            .map(|e| e.laufende_nummer())
+            .collect::<std::collections::BTreeSet<_>>();
+
+        let mut geaendert = 0;
+        let mut roete = |lfd_nr: &StringOrLines,
+                         automatisch_geroetet: &mut Option<bool>,
+                         manuell_geroetet: Option<bool>| {
+            if manuell_geroetet.is_some() || *automatisch_geroetet == Some(true) {
+                return;
+            }
+            if lfd_nr
+                .laufende_nummern()
+                .iter()
+                .any(|n| geroetet.contains(n))
+            {
+                *automatisch_geroetet = Some(true);
+                geaendert += 1;
+            }
+        };
+        for v in self.veraenderungen.iter_mut() {
+            roete(&v.lfd_nr, &mut v.automatisch_geroetet, v.manuell_geroetet);
+        }
+        for l in self.loeschungen.iter_mut() {
+            roete(&l.lfd_nr, &mut l.automatisch_geroetet, l.manuell_geroetet);
+        }
+        geaendert
+    }
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// Einstellungen für `Abteilung2::propagiere_roetung`
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RoetungPropagationOptions {
+    /// Auch nur automatisch gerötete Einträge lösen die Propagation aus (Standard: nur
+    /// manuell gerötete, da die automatische Erkennung fehlerhaft sein kann)
+    pub auch_automatisch_geroetete: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
 pub struct Abt2Eintrag {
     // lfd. Nr. der Eintragung
+    #[cfg_attr(feature = "skip-zero-lfd-nr", serde(default))]
+    #[cfg_attr(
+        feature = "skip-zero-lfd-nr",
+        serde(skip_serializing_if = "ist_null_usize")
+    )]
+    #[serde(alias = "lfdNr")]
+    #[cfg_attr(feature = "schema", schemars(schema_with = "schema_lfd_nr_mit_zusatz"))]
     pub lfd_nr: usize,
+    /// Buchstabenzusatz der lfd. Nr. bei abgeleiteten Rechten ("a" bei "1a")
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "lfdNrZusatz")]
+    pub lfd_nr_zusatz: Option<String>,
     // lfd. Nr der betroffenen Grundstücke im Bestandsverzeichnis
     #[serde(default)]
     #[serde(skip_serializing_if = "StringOrLines::is_empty")]
+    #[serde(alias = "bvNr")]
     pub bv_nr: StringOrLines,
     // Rechtstext
     #[serde(default)]
     #[serde(skip_serializing_if = "StringOrLines::is_empty")]
     pub text: StringOrLines,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "automatischGeroetet")]
     pub automatisch_geroetet: Option<bool>,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "manuellGeroetet")]
     pub manuell_geroetet: Option<bool>,
     #[serde(default)]
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "PositionInPdf::option_ist_leer")]
+    #[serde(alias = "positionInPdf")]
     pub position_in_pdf: Option<PositionInPdf>,
 }
 
-#[derive(Debug, Default, PartialEq, Clone, Serialize, Deserialize)]
+/// Flache Darstellung eines `Abt2Eintrag` beim Einlesen, `lfd_nr` darf hier auch
+/// eine Nummer mit Zusatz wie "1a" sein
+#[derive(Deserialize)]
+struct Abt2EintragRoh {
+    /// Pflichtfeld, nur mit `skip-zero-lfd-nr` (wo `0` weggelassen wird) optional
+    #[serde(default)]
+    #[serde(alias = "lfdNr")]
+    lfd_nr: Option<LfdNrRoh>,
+    #[serde(default)]
+    #[serde(alias = "lfdNrZusatz")]
+    lfd_nr_zusatz: Option<String>,
+    #[serde(default)]
+    #[serde(alias = "bvNr")]
+    bv_nr: StringOrLines,
+    #[serde(default)]
+    text: StringOrLines,
+    #[serde(default)]
+    #[serde(alias = "automatischGeroetet")]
+    automatisch_geroetet: Option<bool>,
+    #[serde(default)]
+    #[serde(alias = "manuellGeroetet")]
+    manuell_geroetet: Option<bool>,
+    #[serde(default)]
+    #[serde(alias = "positionInPdf")]
+    position_in_pdf: Option<PositionInPdf>,
+}
+
+/// Laufende Nummer als Zahl / Zahlstring oder als Text mit Buchstabenzusatz
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum LfdNrRoh {
+    Zahl(UsizeOderString),
+    Text(String),
+}
+
+lazy_static::lazy_static! {
+    static ref REGEX_LFD_NR_ZUSATZ: regex::Regex = {
+        regex::Regex::new("^(\\d+)\\s*([[:alpha:]][[:alnum:]]*)$").unwrap()
+    };
+}
+
+/// Teilt eine laufende Nummer wie "1a" in Nummer und Zusatz auf ("1" ergibt keinen Zusatz)
+pub fn lfd_nr_mit_zusatz_parsen(s: &str) -> Option<(usize, Option<String>)> {
+    let s = s.trim();
+    if let Ok(n) = s.parse::<usize>() {
+        return Some((n, None));
+    }
+    let cap = REGEX_LFD_NR_ZUSATZ.captures(s)?;
+    let nummer = cap.get(1)?.as_str().parse().ok()?;
+    Some((nummer, Some(cap.get(2)?.as_str().to_string())))
+}
+
+/// Vollständige laufende Nummer einer Eintragung, z.B. "1" oder "1a". Nr. 1 und Nr. 1a sind
+/// verschiedene Eintragungen, Vergleiche über die Nummer müssen daher immer den Zusatz
+/// einbeziehen. Sortiert wird nach Nummer, dann Zusatz ("1" < "1a" < "1b" < "2").
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct LaufendeNummer {
+    pub nummer: usize,
+    /// Buchstabenzusatz in Kleinbuchstaben, `None` bei Nummern ohne Zusatz
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zusatz: Option<String>,
+}
+
+impl LaufendeNummer {
+    /// Nummer mit Zusatz, ein leerer Zusatz wird zu `None`, Groß-/Kleinschreibung wird
+    /// vereinheitlicht ("1A" == "1a")
+    pub fn mit_zusatz(nummer: usize, zusatz: Option<&str>) -> Self {
+        LaufendeNummer {
+            nummer,
+            zusatz: zusatz
+                .map(str::trim)
+                .filter(|z| !z.is_empty())
+                .map(str::to_lowercase),
+        }
+    }
+
+    /// Parst eine einzelne laufende Nummer wie "1" oder "1a"
+    pub fn parse(s: &str) -> Result<Self, GbxError> {
+        let (nummer, zusatz) = lfd_nr_mit_zusatz_parsen(s)
+            .ok_or_else(|| GbxError::LfdNrParse(s.trim().to_string()))?;
+        Ok(LaufendeNummer::mit_zusatz(nummer, zusatz.as_deref()))
+    }
+}
+
+impl From<usize> for LaufendeNummer {
+    fn from(nummer: usize) -> Self {
+        LaufendeNummer {
+            nummer,
+            zusatz: None,
+        }
+    }
+}
+
+impl std::fmt::Display for LaufendeNummer {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match &self.zusatz {
+            Some(z) => write!(f, "{}{z}", self.nummer),
+            None => write!(f, "{}", self.nummer),
+        }
+    }
+}
+
+lazy_static::lazy_static! {
+    static ref REGEX_LAUFENDE_NUMMERN: regex::Regex = {
+        regex::Regex::new("(\\d+)([[:alpha:]]\\b)?(?:\\s*(?:-|–|bis)\\s*(\\d+))?").unwrap()
+    };
+}
+
+/// Wie `parse_nummern`, erkennt aber Buchstabenzusätze direkt hinter der Zahl ("1a, 2" ergibt
+/// Nr. 1a und Nr. 2). Bereiche werden nur zwischen Nummern ohne Zusatz aufgefüllt.
+pub fn parse_laufende_nummern(text: &str) -> Vec<LaufendeNummer> {
+    let mut nummern = Vec::new();
+    for cap in REGEX_LAUFENDE_NUMMERN.captures_iter(text) {
+        let von = match cap.get(1).and_then(|m| m.as_str().parse::<usize>().ok()) {
+            Some(s) => s,
+            None => continue,
+        };
+        let zusatz = cap.get(2).map(|m| m.as_str());
+        let bis = cap.get(3).and_then(|m| m.as_str().parse::<usize>().ok());
+        match (zusatz, bis) {
+            (None, Some(bis)) if bis >= von && bis - von <= MAX_NUMMERN_BEREICH => {
+                nummern.extend((von..=bis).map(LaufendeNummer::from));
+            }
+            (_, Some(bis)) => {
+                nummern.push(LaufendeNummer::mit_zusatz(von, zusatz));
+                nummern.push(LaufendeNummer::from(bis));
+            }
+            (_, None) => nummern.push(LaufendeNummer::mit_zusatz(von, zusatz)),
+        }
+    }
+    nummern.sort_unstable();
+    nummern.dedup();
+    nummern
+}
+
+/// Liest einen `Abt2Eintrag` ein: neben dem reinen Zahlenformat wird für `lfd_nr` auch
+/// eine Nummer mit Buchstabenzusatz ("1a") akzeptiert und in `lfd_nr` / `lfd_nr_zusatz`
+/// aufgeteilt. Ein explizit angegebenes `lfd_nr_zusatz` hat Vorrang.
+impl<'de> Deserialize<'de> for Abt2Eintrag {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        let roh = Abt2EintragRoh::deserialize(deserializer)?;
+
+        let (lfd_nr, zusatz) = match roh.lfd_nr {
+            None if cfg!(feature = "skip-zero-lfd-nr") => (0, None),
+            None => return Err(D::Error::missing_field("lfd_nr")),
+            Some(LfdNrRoh::Zahl(UsizeOderString(Some(n)))) => (n, None),
+            Some(LfdNrRoh::Zahl(UsizeOderString(None))) => {
+                return Err(D::Error::custom("leere Zahl"));
+            }
+            Some(LfdNrRoh::Text(t)) => lfd_nr_mit_zusatz_parsen(&t).ok_or_else(|| {
+                D::Error::custom(format!("ungültige laufende Nummer \"{}\"", t.trim()))
+            })?,
+        };
+
+        Ok(Abt2Eintrag {
+            lfd_nr,
+            lfd_nr_zusatz: roh
+                .lfd_nr_zusatz
+                .filter(|z| !z.trim().is_empty())
+                .or(zusatz),
+            bv_nr: roh.bv_nr,
+            text: roh.text,
+            automatisch_geroetet: roh.automatisch_geroetet,
+            manuell_geroetet: roh.manuell_geroetet,
+            position_in_pdf: roh.position_in_pdf,
+        })
+    }
+}
+
+/// Art eines Rangvermerks
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum RangArt {
+    Vorrang,
+    Gleichrang,
+    Nachrang,
+}
+
+/// Strukturierter Rangvermerk ("im Range vor Abt. III Nr. 2", "Gleichrang mit Nr. 1")
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Rangvermerk {
+    /// Vorrang / Gleichrang / Nachrang des Eintrags gegenüber dem Ziel
+    pub art: RangArt,
+    /// Abteilung des Ziel-Eintrags (ohne Angabe dieselbe Abteilung)
+    pub abteilung: Abteilungsbereich,
+    /// lfd. Nr. des Ziel-Eintrags
+    pub lfd_nr: usize,
+}
+
+impl Abt2Eintrag {
+    /// Im Text enthaltene Rangvermerke, unvollständige Vermerke (z.B. ohne Nummer) werden ausgelassen
+    pub fn rangvermerke(&self) -> Vec<Rangvermerk> {
+        parse_rangvermerke(&self.text.text_clean(), Abteilungsbereich::Abt2)
+    }
+}
+
+fn parse_rangvermerke(text: &str, eigene_abteilung: Abteilungsbereich) -> Vec<Rangvermerk> {
+    REGEX_RANGVERMERK
+        .captures_iter(text)
+        .filter_map(|cap| {
+            let art = cap.get(1)?.as_str().to_lowercase();
+            let art = if art.contains("gleich") {
+                RangArt::Gleichrang
+            } else if art.contains("nach") {
+                RangArt::Nachrang
+            } else {
+                RangArt::Vorrang
+            };
+            let abteilung = match cap.get(2).map(|m| m.as_str()) {
+                Some("I") | Some("1") => Abteilungsbereich::Abt1,
+                Some("II") | Some("2") => Abteilungsbereich::Abt2,
+                Some("III") | Some("3") => Abteilungsbereich::Abt3,
+                _ => eigene_abteilung,
+            };
+            let lfd_nr = cap.get(3)?.as_str().parse().ok()?;
+            Some(Rangvermerk {
+                art,
+                abteilung,
+                lfd_nr,
+            })
+        })
+        .collect()
+}
+
+lazy_static::lazy_static! {
+    static ref REGEX_RANGVERMERK: regex::Regex = {
+        regex::Regex::new(
+            "(?i)(range\\s+vor|vorrang\\s+vor|range\\s+nach|nachrang\\s+nach|gleichrang\\s+mit|gleichem\\s+range\\s+mit|gleichrang\\s+nach)\\s+(?:(?:der|dem|den)\\s+)?(?:(?:recht|eintrag|post)\\w*\\s+)?(?:(?:abt\\.?|abteilung)\\s*(III|II|I|1|2|3)\\b\\s*)?(?:,\\s*)?(?:(?:lfd\\.?\\s*)?nr\\.?\\s*)(\\d+)"
+        ).unwrap()
+    };
+}
+
+#[derive(Debug, Default, PartialEq, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
 pub struct Abt2Veraenderung {
     #[serde(default)]
     #[serde(skip_serializing_if = "StringOrLines::is_empty")]
+    #[serde(alias = "lfdNr")]
     pub lfd_nr: StringOrLines,
     #[serde(default)]
     #[serde(skip_serializing_if = "StringOrLines::is_empty")]
     pub text: StringOrLines,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "automatischGeroetet")]
     pub automatisch_geroetet: Option<bool>,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "manuellGeroetet")]
     pub manuell_geroetet: Option<bool>,
     #[serde(default)]
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "PositionInPdf::option_ist_leer")]
+    #[serde(alias = "positionInPdf")]
     pub position_in_pdf: Option<PositionInPdf>,
 }
 
 impl Abt2Veraenderung {
+    pub fn roetungsstatus(&self) -> Roetungsstatus {
+        Roetungsstatus::aus_flags(self.automatisch_geroetet, self.manuell_geroetet)
+    }
+
     pub fn ist_geroetet(&self) -> bool {
-        self.manuell_geroetet
-            .or(self.automatisch_geroetet.clone())
-            .unwrap_or(false)
+        self.roetungsstatus().ist_geroetet()
     }
 }
 
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
 pub struct Abt2Loeschung {
     #[serde(default)]
     #[serde(skip_serializing_if = "StringOrLines::is_empty")]
+    #[serde(alias = "lfdNr")]
     pub lfd_nr: StringOrLines,
     #[serde(default)]
     #[serde(skip_serializing_if = "StringOrLines::is_empty")]
     pub text: StringOrLines,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "automatischGeroetet")]
     pub automatisch_geroetet: Option<bool>,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "manuellGeroetet")]
     pub manuell_geroetet: Option<bool>,
     #[serde(default)]
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "PositionInPdf::option_ist_leer")]
+    #[serde(alias = "positionInPdf")]
     pub position_in_pdf: Option<PositionInPdf>,
 }
 
 impl Abt2Loeschung {
+    pub fn roetungsstatus(&self) -> Roetungsstatus {
+        Roetungsstatus::aus_flags(self.automatisch_geroetet, self.manuell_geroetet)
+    }
+
     pub fn ist_geroetet(&self) -> bool {
-        self.manuell_geroetet
-            .or(self.automatisch_geroetet.clone())
-            .unwrap_or(false)
+        self.roetungsstatus().ist_geroetet()
     }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
 pub struct Abteilung3 {
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
@@ -887,15 +4949,51 @@ impl Abteilung3 {
     pub fn is_empty(&self) -> bool {
         self.eintraege.is_empty() && self.veraenderungen.is_empty() && self.loeschungen.is_empty()
     }
+
+    /// Eintrag mit der laufenden Nummer und alle zugehörigen Veränderungen und Löschungen
+    /// (in Listenreihenfolge)
+    pub fn historie(
+        &self,
+        eintrag_lfd_nr: usize,
+    ) -> EintragHistorie<'_, Abt3Eintrag, Abt3Veraenderung, Abt3Loeschung> {
+        let nr = LaufendeNummer::from(eintrag_lfd_nr);
+        EintragHistorie {
+            eintrag: self.eintraege.iter().find(|e| e.lfd_nr == eintrag_lfd_nr),
+            veraenderungen: self
+                .veraenderungen
+                .iter()
+                .filter(|v| v.lfd_nr.laufende_nummern().contains(&nr))
+                .collect(),
+            loeschungen: self
+                .loeschungen
+                .iter()
+                .filter(|l| l.lfd_nr.laufende_nummern().contains(&nr))
+                .collect(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
 pub struct Abt3Eintrag {
     // lfd. Nr. der Eintragung
+    #[cfg_attr(feature = "skip-zero-lfd-nr", serde(default))]
+    #[cfg_attr(
+        feature = "skip-zero-lfd-nr",
+        serde(skip_serializing_if = "ist_null_usize")
+    )]
+    #[serde(deserialize_with = "usize_from_str_or_num")]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(schema_with = "schema_usize_from_str_or_num")
+    )]
+    #[serde(alias = "lfdNr")]
     pub lfd_nr: usize,
     // lfd. Nr der betroffenen Grundstücke im Bestandsverzeichnis
     #[serde(default)]
     #[serde(skip_serializing_if = "StringOrLines::is_empty")]
+    #[serde(alias = "bvNr")]
     pub bv_nr: StringOrLines,
     // Betrag (EUR / DM)
     #[serde(default)]
@@ -907,19 +5005,25 @@ pub struct Abt3Eintrag {
     pub text: StringOrLines,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "automatischGeroetet")]
     pub automatisch_geroetet: Option<bool>,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "manuellGeroetet")]
     pub manuell_geroetet: Option<bool>,
     #[serde(default)]
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "PositionInPdf::option_ist_leer")]
+    #[serde(alias = "positionInPdf")]
     pub position_in_pdf: Option<PositionInPdf>,
 }
 
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
 pub struct Abt3Veraenderung {
     #[serde(default)]
     #[serde(skip_serializing_if = "StringOrLines::is_empty")]
+    #[serde(alias = "lfdNr")]
     pub lfd_nr: StringOrLines,
     #[serde(default)]
     #[serde(skip_serializing_if = "StringOrLines::is_empty")]
@@ -928,27 +5032,35 @@ pub struct Abt3Veraenderung {
     #[serde(skip_serializing_if = "StringOrLines::is_empty")]
     pub text: StringOrLines,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "automatischGeroetet")]
     pub automatisch_geroetet: Option<bool>,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "manuellGeroetet")]
     pub manuell_geroetet: Option<bool>,
     #[serde(default)]
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "PositionInPdf::option_ist_leer")]
+    #[serde(alias = "positionInPdf")]
     pub position_in_pdf: Option<PositionInPdf>,
 }
 
 impl Abt3Veraenderung {
+    pub fn roetungsstatus(&self) -> Roetungsstatus {
+        Roetungsstatus::aus_flags(self.automatisch_geroetet, self.manuell_geroetet)
+    }
+
     pub fn ist_geroetet(&self) -> bool {
-        self.manuell_geroetet
-            .or(self.automatisch_geroetet.clone())
-            .unwrap_or(false)
+        self.roetungsstatus().ist_geroetet()
     }
 }
 
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
 pub struct Abt3Loeschung {
     #[serde(default)]
     #[serde(skip_serializing_if = "StringOrLines::is_empty")]
+    #[serde(alias = "lfdNr")]
     pub lfd_nr: StringOrLines,
     #[serde(default)]
     #[serde(skip_serializing_if = "StringOrLines::is_empty")]
@@ -957,19 +5069,7235 @@ pub struct Abt3Loeschung {
     #[serde(skip_serializing_if = "StringOrLines::is_empty")]
     pub text: StringOrLines,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "automatischGeroetet")]
     pub automatisch_geroetet: Option<bool>,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "manuellGeroetet")]
     pub manuell_geroetet: Option<bool>,
     #[serde(default)]
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "PositionInPdf::option_ist_leer")]
+    #[serde(alias = "positionInPdf")]
     pub position_in_pdf: Option<PositionInPdf>,
 }
 
 impl Abt3Loeschung {
+    pub fn roetungsstatus(&self) -> Roetungsstatus {
+        Roetungsstatus::aus_flags(self.automatisch_geroetet, self.manuell_geroetet)
+    }
+
     pub fn ist_geroetet(&self) -> bool {
-        self.manuell_geroetet
-            .or(self.automatisch_geroetet.clone())
-            .unwrap_or(false)
+        self.roetungsstatus().ist_geroetet()
+    }
+}
+
+/// Währung eines Geldbetrags in Abteilung 3
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Waehrung {
+    #[serde(rename = "EUR")]
+    Euro,
+    #[serde(rename = "DM")]
+    DMark,
+    #[serde(rename = "RM")]
+    Reichsmark,
+    #[serde(rename = "GM")]
+    Goldmark,
+}
+
+/// Amtlicher Umrechnungskurs DM → EUR
+pub const DM_PRO_EURO: f64 = 1.95583;
+
+impl Waehrung {
+    /// Sucht im Text nach einer expliziten Währungsangabe ("DM", "€", "RM", ...)
+    pub fn erkennen(text: &str) -> Option<Waehrung> {
+        if REGEX_WAEHRUNG_GM.is_match(text) {
+            Some(Waehrung::Goldmark)
+        } else if REGEX_WAEHRUNG_RM.is_match(text) {
+            Some(Waehrung::Reichsmark)
+        } else if REGEX_WAEHRUNG_DM.is_match(text) {
+            Some(Waehrung::DMark)
+        } else if REGEX_WAEHRUNG_EUR.is_match(text) {
+            Some(Waehrung::Euro)
+        } else {
+            None
+        }
+    }
+
+    /// Gültige Währung im Jahr der Eintragung: vor 1948 Reichsmark, 1948 - 2001 DM,
+    /// danach Euro
+    pub fn nach_jahr(jahr: u32) -> Waehrung {
+        match jahr {
+            0..=1947 => Waehrung::Reichsmark,
+            1948..=2001 => Waehrung::DMark,
+            _ => Waehrung::Euro,
+        }
+    }
+
+    /// Kürzel der Währung ("EUR", "DM", "RM", "GM")
+    pub fn kuerzel(&self) -> &'static str {
+        match self {
+            Waehrung::Euro => "EUR",
+            Waehrung::DMark => "DM",
+            Waehrung::Reichsmark => "RM",
+            Waehrung::Goldmark => "GM",
+        }
+    }
+}
+
+/// Geldbetrag in der kleinsten Einheit der Währung (Cent / Pfennig)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Geldbetrag {
+    /// Betrag in Cent bzw. Pfennig
+    pub cent: i64,
+    /// Währung des Betrags
+    pub waehrung: Waehrung,
+}
+
+/// Fehler bei der Auswertung von Geldbeträgen
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum BetragFehler {
+    /// Für die Währung existiert kein amtlicher Umrechnungskurs nach EUR
+    NichtUmrechenbar(Waehrung),
+    /// Summe ist unvollständig, da einzelne Beträge (lfd. Nr. der Einträge) nicht erkannt
+    /// oder nicht umgerechnet werden konnten
+    Unvollstaendig {
+        summe: Geldbetrag,
+        nicht_erkannt: Vec<usize>,
+        nicht_umrechenbar: Vec<usize>,
+    },
+    /// Die Summe ist zu groß
+    Ueberlauf,
+}
+
+impl std::fmt::Display for BetragFehler {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            BetragFehler::NichtUmrechenbar(w) => {
+                write!(f, "{} kann nicht umgerechnet werden", w.kuerzel())
+            }
+            BetragFehler::Unvollstaendig {
+                summe,
+                nicht_erkannt,
+                nicht_umrechenbar,
+            } => write!(
+                f,
+                "Summe {summe} unvollständig: {} Beträge nicht erkannt, {} nicht umrechenbar",
+                nicht_erkannt.len(),
+                nicht_umrechenbar.len()
+            ),
+            BetragFehler::Ueberlauf => write!(f, "Summe der Beträge ist zu groß"),
+        }
+    }
+}
+
+impl std::error::Error for BetragFehler {}
+
+impl Geldbetrag {
+    /// Parst Beträge wie "10.000,00 DM", "€ 5.000,-" oder "1000 RM". Bevorzugt wird die Zahl
+    /// direkt an der Währungsangabe, sonst die erste Zahl im Text.
+    /// `GbxError::BetragParse`, wenn Zahl oder Währung fehlen
+    pub fn parse(text: &str) -> Result<Geldbetrag, GbxError> {
+        if let Some(betrag) = betrag_an_waehrung(text) {
+            return Ok(betrag);
+        }
+        let fehler = || GbxError::BetragParse(text.trim().to_string());
+        let waehrung = Waehrung::erkennen(text).ok_or_else(fehler)?;
+        let cent = parse_cent(text).ok_or_else(fehler)?;
+        Ok(Geldbetrag { cent, waehrung })
+    }
+
+    /// Rechnet den Betrag in EUR um (DM über den amtlichen Kurs, auf Cent gerundet),
+    /// Reichsmark und Goldmark sind nicht umrechenbar
+    pub fn in_euro(&self) -> Result<Geldbetrag, BetragFehler> {
+        match self.waehrung {
+            Waehrung::Euro => Ok(*self),
+            Waehrung::DMark => Ok(Geldbetrag {
+                cent: (self.cent as f64 / DM_PRO_EURO).round() as i64,
+                waehrung: Waehrung::Euro,
+            }),
+            w => Err(BetragFehler::NichtUmrechenbar(w)),
+        }
+    }
+}
+
+impl Geldbetrag {
+    /// Betrag in der Zielwährung, umrechenbar sind EUR und DM (über den amtlichen Kurs)
+    pub fn in_waehrung(&self, ziel: Waehrung) -> Result<Geldbetrag, BetragFehler> {
+        if self.waehrung == ziel {
+            return Ok(*self);
+        }
+        let euro = self.in_euro()?;
+        match ziel {
+            Waehrung::Euro => Ok(euro),
+            Waehrung::DMark => Ok(Geldbetrag {
+                cent: (euro.cent as f64 * DM_PRO_EURO).round() as i64,
+                waehrung: Waehrung::DMark,
+            }),
+            w => Err(BetragFehler::NichtUmrechenbar(w)),
+        }
+    }
+}
+
+impl Abteilung3 {
+    /// Summe aller nicht geröteten Beträge in der Zielwährung. Lassen sich einzelne Beträge nicht
+    /// erkennen oder umrechnen, kommt `BetragFehler::Unvollstaendig` mit der Teilsumme zurück.
+    pub fn gesamtbelastung(&self, waehrung: Waehrung) -> Result<Geldbetrag, BetragFehler> {
+        let mut summe = Geldbetrag { cent: 0, waehrung };
+        let mut nicht_erkannt = Vec::new();
+        let mut nicht_umrechenbar = Vec::new();
+
+        for e in self.eintraege.iter().filter(|e| !e.ist_geroetet()) {
+            let betrag = match e.betrag_parsed() {
+                Some(b) => b,
+                None => {
+                    nicht_erkannt.push(e.lfd_nr);
+                    continue;
+                }
+            };
+            match betrag.in_waehrung(waehrung) {
+                Ok(b) => {
+                    summe.cent = summe
+                        .cent
+                        .checked_add(b.cent)
+                        .ok_or(BetragFehler::Ueberlauf)?;
+                }
+                Err(_) => nicht_umrechenbar.push(e.lfd_nr),
+            }
+        }
+
+        if nicht_erkannt.is_empty() && nicht_umrechenbar.is_empty() {
+            Ok(summe)
+        } else {
+            Err(BetragFehler::Unvollstaendig {
+                summe,
+                nicht_erkannt,
+                nicht_umrechenbar,
+            })
+        }
+    }
+}
+
+impl std::fmt::Display for Geldbetrag {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let vorzeichen = if self.cent < 0 { "-" } else { "" };
+        let cent = self.cent.unsigned_abs();
+        write!(
+            f,
+            "{}{},{:02} {}",
+            vorzeichen,
+            tausender_trennung(cent / 100),
+            cent % 100,
+            self.waehrung.kuerzel()
+        )
+    }
+}
+
+impl Abt3Eintrag {
+    /// Geparster Betrag des Eintrags, falls Zahl und Währung erkennbar sind
+    pub fn betrag_parsed(&self) -> Option<Geldbetrag> {
+        Geldbetrag::parse(&self.betrag.text()).ok()
+    }
+
+    /// Währung des Eintrags: zuerst explizite Angaben ("DM", "€", "RM", ...) in Betrag und
+    /// Text, sonst anhand des Eintragungsjahrs (siehe `Waehrung::nach_jahr`), ohne Jahr Euro
+    pub fn waehrung_heuristik(&self, eintragsjahr: Option<u32>) -> Waehrung {
+        Waehrung::erkennen(&self.betrag.text())
+            .or_else(|| Waehrung::erkennen(&self.text.text()))
+            .or_else(|| eintragsjahr.map(Waehrung::nach_jahr))
+            .unwrap_or(Waehrung::Euro)
+    }
+}
+
+/// Art einer Veränderung in Abteilung 3, siehe `Abt3Veraenderung::parse_aenderung`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum Abt3AenderungsArt {
+    /// Erhöhung / Ermäßigung des Betrags, Teilbetrag
+    Betragsaenderung,
+    /// Änderung des Zinssatzes ("nunmehr 5 v.H.")
+    Zinsaenderung,
+    /// Abtretung an einen neuen Gläubiger
+    Abtretung,
+    /// Entlassung von Grundstücken aus der Mithaft
+    Pfandfreigabe,
+    /// Nicht erkannt, siehe `Abt3Aenderung::rohtext`
+    Unbekannt,
+}
+
+/// Heuristisch ausgewertete Veränderung in Abteilung 3
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Abt3Aenderung {
+    pub art: Abt3AenderungsArt,
+    /// Neuer Betrag bzw. Teilbetrag, aus der Betragsspalte oder dem Text
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub neuer_betrag: Option<Geldbetrag>,
+    /// Neuer Zinssatz in Prozent
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub neuer_zinssatz: Option<f32>,
+    /// Neuer Gläubiger bei Abtretungen
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub neuer_glaeubiger: Option<String>,
+    /// Vollständiger Text der Veränderung
+    pub rohtext: String,
+}
+
+impl Abt3Veraenderung {
+    /// Erkennt heuristisch, ob es sich um eine Abtretung, Pfandfreigabe, Zins- oder
+    /// Betragsänderung handelt, und extrahiert die erkennbaren neuen Werte
+    pub fn parse_aenderung(&self) -> Abt3Aenderung {
+        let rohtext = fliesstext(self.text.lines().iter().map(String::as_str));
+        let klein = rohtext.to_lowercase();
+
+        let neuer_betrag = Geldbetrag::parse(&self.betrag.text())
+            .ok()
+            .or_else(|| betrag_an_waehrung(&rohtext));
+        let neuer_zinssatz = REGEX_ZINSSATZ
+            .captures(&rohtext)
+            .and_then(|c| c.get(1)?.as_str().replace(',', ".").parse::<f32>().ok());
+        let neuer_glaeubiger = REGEX_NEUER_GLAEUBIGER
+            .captures(&rohtext)
+            .and_then(|c| Some(c.get(1)?.as_str().trim().to_string()))
+            .filter(|g| !g.is_empty());
+
+        let art = if neuer_glaeubiger.is_some()
+            || klein.contains("abgetreten")
+            || klein.contains("abtretung")
+        {
+            Abt3AenderungsArt::Abtretung
+        } else if klein.contains("pfandfrei")
+            || klein.contains("pfandentlass")
+            || klein.contains("mithaft")
+        {
+            Abt3AenderungsArt::Pfandfreigabe
+        } else if neuer_zinssatz.is_some() || klein.contains("zins") {
+            Abt3AenderungsArt::Zinsaenderung
+        } else if neuer_betrag.is_some()
+            || klein.contains("teilbetrag")
+            || klein.contains("ermäßigt")
+            || klein.contains("erhöht")
+            || klein.contains("herabgesetzt")
+        {
+            Abt3AenderungsArt::Betragsaenderung
+        } else {
+            Abt3AenderungsArt::Unbekannt
+        };
+
+        Abt3Aenderung {
+            art,
+            neuer_betrag,
+            neuer_zinssatz,
+            neuer_glaeubiger,
+            rohtext,
+        }
+    }
+}
+
+lazy_static::lazy_static! {
+    static ref REGEX_ZINSSATZ: regex::Regex = {
+        regex::Regex::new("(?i)(\\d{1,2}(?:,\\d{1,3})?)\\s*(?:%|v\\.\\s?h\\.|vom\\s+hundert|prozent)").unwrap()
+    };
+    static ref REGEX_NEUER_GLAEUBIGER: regex::Regex = {
+        regex::Regex::new(
+            "(?i)(?:abgetreten\\s+an|übergegangen\\s+auf)\\s+(?:(?:die|den|das|der)\\s+)?(.+?)(?:\\.\\s|\\.?$|;|,?\\s+(?:eingetragen|gemäß))"
+        ).unwrap()
+    };
+}
+
+/// Parst die erste Zahl im deutschen Format ("10.000,00", "5.000,-") als Cent-Betrag
+fn parse_cent(text: &str) -> Option<i64> {
+    cent_aus_treffer(&REGEX_BETRAG.captures(text)?)
+}
+
+/// Betrag, der direkt vor oder hinter einer Währungsangabe steht ("10.000 DM", "€ 5.000,-").
+/// Andere Zahlen im Text (Daten, Zinssätze, lfd. Nrn.) werden dabei übersprungen.
+fn betrag_an_waehrung(text: &str) -> Option<Geldbetrag> {
+    REGEX_BETRAG.captures_iter(text).find_map(|cap| {
+        let zahl = cap.get(0)?;
+        let waehrung = waehrung_am_rand(text[zahl.end()..].trim_start(), true)
+            .or_else(|| waehrung_am_rand(text[..zahl.start()].trim_end(), false))?;
+        Some(Geldbetrag {
+            cent: cent_aus_treffer(&cap)?,
+            waehrung,
+        })
+    })
+}
+
+/// Währungsangabe am Anfang (`am_anfang`) bzw. am Ende von `text`
+fn waehrung_am_rand(text: &str, am_anfang: bool) -> Option<Waehrung> {
+    [
+        (&*REGEX_WAEHRUNG_GM, Waehrung::Goldmark),
+        (&*REGEX_WAEHRUNG_RM, Waehrung::Reichsmark),
+        (&*REGEX_WAEHRUNG_DM, Waehrung::DMark),
+        (&*REGEX_WAEHRUNG_EUR, Waehrung::Euro),
+    ]
+    .into_iter()
+    .find(|(regex, _)| {
+        regex.find_iter(text).any(|m| {
+            if am_anfang {
+                m.start() == 0
+            } else {
+                m.end() == text.len()
+            }
+        })
+    })
+    .map(|(_, waehrung)| waehrung)
+}
+
+fn cent_aus_treffer(cap: &regex::Captures) -> Option<i64> {
+    let ganz = cap.get(1)?.as_str().replace('.', "").parse::<i64>().ok()?;
+    let nachkomma = match cap.get(2).map(|m| m.as_str()) {
+        Some(n) if n.chars().all(|c| c.is_ascii_digit()) => {
+            let n = if n.len() == 1 {
+                format!("{n}0")
+            } else {
+                n.to_string()
+            };
+            n.parse::<i64>().ok()?
+        }
+        _ => 0,
+    };
+    ganz.checked_mul(100)?.checked_add(nachkomma)
+}
+
+/// Formatiert eine Zahl mit deutscher Tausendertrennung ("1.234.567")
+fn tausender_trennung(zahl: u64) -> String {
+    let ziffern = zahl.to_string();
+    let mut out = String::new();
+    for (i, c) in ziffern.chars().enumerate() {
+        if i > 0 && (ziffern.len() - i).is_multiple_of(3) {
+            out.push('.');
+        }
+        out.push(c);
+    }
+    out
+}
+
+lazy_static::lazy_static! {
+    static ref REGEX_BETRAG: regex::Regex = {
+        regex::Regex::new("(\\d{1,3}(?:\\.\\d{3})+|\\d+)(?:,(\\d{1,2}|-+))?").unwrap()
+    };
+    static ref REGEX_WAEHRUNG_EUR: regex::Regex = {
+        regex::Regex::new("(?i)€|\\beur(o)?\\b").unwrap()
+    };
+    static ref REGEX_WAEHRUNG_DM: regex::Regex = {
+        regex::Regex::new("\\bDM\\b|(?i)\\bdeutsche[n]?\\s+mark\\b").unwrap()
+    };
+    static ref REGEX_WAEHRUNG_RM: regex::Regex = {
+        regex::Regex::new("\\bRM\\b|(?i)\\breichsmark\\b").unwrap()
+    };
+    static ref REGEX_WAEHRUNG_GM: regex::Regex = {
+        regex::Regex::new("\\bGM\\b|(?i)\\bgoldmark\\b").unwrap()
+    };
+}
+
+/// Zentraler Fehlertyp des Crates: Fehler beim Laden / Speichern von .gbx-Dateien sowie alle
+/// spezifischen Fehler der einzelnen Operationen (über `From` mit `?` konvertierbar)
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum GbxError {
+    /// Ungültiges JSON oder JSON, das nicht dem .gbx-Format entspricht
+    #[error("Fehler beim Lesen des JSON: {0}")]
+    JsonParse(#[from] serde_json::Error),
+    /// Wie `JsonParse`, mit Pfad zur fehlerhaften Stelle (siehe `PdfFile::from_json_detailed`)
+    #[error("Fehler beim Lesen des JSON: {0}")]
+    JsonPfad(#[from] DetailedError),
+    /// Ungültiges YAML oder YAML, das nicht dem .gbx-Format entspricht
+    #[cfg(feature = "yaml")]
+    #[error("Fehler beim Lesen des YAML: {0}")]
+    YamlParse(#[from] serde_yaml::Error),
+    /// Fehler beim Zusammenführen von hOCR-Layouts
+    #[error("Fehler beim Zusammenführen des hOCR: {0}")]
+    HocrMerge(#[from] MergeFehler),
+    /// Ungültige Flurstücksgröße
+    #[error("{0}")]
+    Groesse(#[from] GroesseFehler),
+    /// Ungültige Flurstücksbezeichnung
+    #[error("{0}")]
+    Flurstueck(#[from] FlurstueckFehler),
+    /// Fehler bei der Auswertung von Geldbeträgen
+    #[error("{0}")]
+    Betrag(#[from] BetragFehler),
+    /// Verweis auf eine nicht existierende Nummer im Bestandsverzeichnis
+    #[error("{0}")]
+    Referenz(#[from] ReferenzFehler),
+    /// Fehler beim Anwenden eines `GrundbuchPatch`
+    #[error("{0}")]
+    Patch(#[from] PatchError),
+    /// Fehler beim Verschieben eines Eintrags zwischen Abteilungen
+    #[error("{0}")]
+    Verschieben(#[from] VerschiebeFehler),
+    /// Widerspruch zwischen hOCR und Seitenanpassungen (siehe `PdfFile::pruefe_seiten_konsistenz`)
+    #[error("{0}")]
+    SeitenKonsistenz(#[from] SeitenKonsistenzFehler),
+    /// Text ist keine Flurstücksgröße (siehe `FlurstueckGroesse::parse`)
+    #[error("Keine Flurstücksgröße: \"{0}\"")]
+    GroesseParse(String),
+    /// Text enthält keinen Betrag mit Währung (siehe `Geldbetrag::parse`)
+    #[error("Kein Betrag mit Währung erkennbar: \"{0}\"")]
+    BetragParse(String),
+    /// Text enthält kein Datum (siehe `parse_deutsches_datum`)
+    #[error("Kein Datum erkennbar: \"{0}\"")]
+    DatumParse(String),
+    /// Text ist keine laufende Nummer (siehe `LaufendeNummer::parse`)
+    #[error("Keine laufende Nummer: \"{0}\"")]
+    LfdNrParse(String),
+}
+
+#[cfg(feature = "yaml")]
+impl Grundbuch {
+    /// Liest ein Grundbuch aus YAML (z.B. handgeschriebene Testfixtures)
+    pub fn from_yaml(s: &str) -> Result<Grundbuch, GbxError> {
+        Ok(serde_yaml::from_str(s)?)
+    }
+
+    /// Schreibt das Grundbuch als YAML
+    pub fn to_yaml(&self) -> Result<String, GbxError> {
+        Ok(serde_yaml::to_string(self)?)
+    }
+}
+
+impl PdfFile {
+    pub fn from_json(s: &str) -> Result<PdfFile, GbxError> {
+        Ok(serde_json::from_str(s)?)
+    }
+
+    pub fn to_json(&self) -> Result<String, GbxError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Wie `from_json`, der Fehler enthält aber zusätzlich den JSON-Pfad zur fehlerhaften Stelle
+    /// (z.B. "analysiert.abt2.eintraege[4].lfd_nr")
+    pub fn from_json_detailed(s: &str) -> Result<PdfFile, DetailedError> {
+        let de = &mut serde_json::Deserializer::from_str(s);
+        serde_path_to_error::deserialize(de).map_err(|e| DetailedError {
+            pfad: e.path().to_string(),
+            fehler: e.into_inner(),
+        })
+    }
+}
+
+/// Widerspruch zwischen `PdfFile::hocr` und `PdfFile::anpassungen_seite`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum SeitenKonsistenzFehler {
+    /// Anpassung für eine Seite, die im hOCR nicht existiert (z.B. nach dem Löschen von Seiten)
+    AnpassungOhneHocrSeite { seite: String },
+    /// hOCR-Seite ohne Eintrag in `anpassungen_seite`
+    HocrSeiteOhneAnpassung { seite: String },
+    /// Spalte einer Anpassung liegt (teilweise) außerhalb der Seitenmaße in mm
+    SpalteAusserhalbDerSeite {
+        seite: String,
+        spalte: String,
+        rect: Rect,
+    },
+}
+
+impl std::fmt::Display for SeitenKonsistenzFehler {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            SeitenKonsistenzFehler::AnpassungOhneHocrSeite { seite } => {
+                write!(f, "Anpassung für Seite {seite} ohne hOCR-Seite")
+            }
+            SeitenKonsistenzFehler::HocrSeiteOhneAnpassung { seite } => {
+                write!(f, "hOCR-Seite {seite} ohne Anpassung")
+            }
+            SeitenKonsistenzFehler::SpalteAusserhalbDerSeite { seite, spalte, .. } => {
+                write!(f, "Spalte {spalte} liegt außerhalb von Seite {seite}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SeitenKonsistenzFehler {}
+
+impl PdfFile {
+    /// Prüft, ob die Seitenschlüssel von `hocr.seiten` und `anpassungen_seite` zusammenpassen
+    /// und ob die `spalten` der Anpassungen innerhalb der Seitenmaße liegen
+    pub fn pruefe_seiten_konsistenz(&self) -> Vec<SeitenKonsistenzFehler> {
+        let mut fehler = Vec::new();
+
+        for (seite, anpassung) in self.anpassungen_seite.iter() {
+            let hocr = match self.hocr.seiten.get(seite) {
+                Some(h) => h,
+                None => {
+                    fehler.push(SeitenKonsistenzFehler::AnpassungOhneHocrSeite {
+                        seite: seite.clone(),
+                    });
+                    continue;
+                }
+            };
+            let seite_rect = Rect {
+                min_x: 0.0,
+                min_y: 0.0,
+                max_x: hocr.breite_mm,
+                max_y: hocr.hoehe_mm,
+            };
+            for (spalte, rect) in anpassung.spalten.iter() {
+                let r = rect.normalized();
+                if !(seite_rect.contains(r.min_x, r.min_y) && seite_rect.contains(r.max_x, r.max_y))
+                {
+                    fehler.push(SeitenKonsistenzFehler::SpalteAusserhalbDerSeite {
+                        seite: seite.clone(),
+                        spalte: spalte.clone(),
+                        rect: rect.clone(),
+                    });
+                }
+            }
+        }
+
+        for seite in self.hocr.seiten.keys() {
+            if !self.anpassungen_seite.contains_key(seite) {
+                fehler.push(SeitenKonsistenzFehler::HocrSeiteOhneAnpassung {
+                    seite: seite.clone(),
+                });
+            }
+        }
+
+        fehler
+    }
+}
+
+/// JSON-Fehler mit Pfad zur fehlerhaften Stelle, siehe `PdfFile::from_json_detailed`
+#[derive(Debug)]
+pub struct DetailedError {
+    /// Pfad im JSON, z.B. "analysiert.abt2.eintraege[4].lfd_nr"
+    pub pfad: String,
+    pub fehler: serde_json::Error,
+}
+
+impl std::fmt::Display for DetailedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}: {}", self.pfad, self.fehler)
+    }
+}
+
+impl std::error::Error for DetailedError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.fehler)
+    }
+}
+
+/// Bündel mehrerer .gbx-Dateien (z.B. mehrere Blätter eines Amtsgerichts)
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
+pub struct GbxBundle {
+    #[serde(default)]
+    pub dateien: Vec<PdfFile>,
+}
+
+impl GbxBundle {
+    pub fn from_json(s: &str) -> Result<GbxBundle, GbxError> {
+        Ok(serde_json::from_str(s)?)
+    }
+
+    pub fn to_json(&self) -> Result<String, GbxError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Liest mehrere .gbx-Dateien als (Name, JSON): gültige Dateien landen in Eingabereihenfolge
+    /// im Bündel, fehlerhafte werden mit Name und Fehler separat zurückgegeben
+    pub fn from_json_lenient(dateien: &[(String, String)]) -> (GbxBundle, Vec<(String, GbxError)>) {
+        let mut bundle = GbxBundle::default();
+        let mut fehler = Vec::new();
+        for (name, json) in dateien {
+            match PdfFile::from_json(json) {
+                Ok(datei) => bundle.dateien.push(datei),
+                Err(e) => fehler.push((name.clone(), e)),
+            }
+        }
+        (bundle, fehler)
+    }
+
+    /// Erste Datei mit exakt diesem Titelblatt
+    pub fn find_by_titelblatt(&self, t: &Titelblatt) -> Option<&PdfFile> {
+        self.dateien.iter().find(|d| d.analysiert.titelblatt == *t)
+    }
+
+    /// Führt Dateien mit identischem Titelblatt zusammen: die erste Datei bleibt erhalten,
+    /// fehlende hOCR-Seiten, Seitenanpassungen und leere Abteilungen werden aus den
+    /// späteren Dateien ergänzt
+    pub fn dedupe(&mut self) {
+        let mut index = std::collections::HashMap::<Titelblatt, usize>::new();
+        let mut dateien: Vec<PdfFile> = Vec::new();
+
+        for datei in std::mem::take(&mut self.dateien) {
+            match index.get(&datei.analysiert.titelblatt) {
+                Some(i) => dateien[*i].ergaenze_aus(datei),
+                None => {
+                    index.insert(datei.analysiert.titelblatt.clone(), dateien.len());
+                    dateien.push(datei);
+                }
+            }
+        }
+
+        self.dateien = dateien;
+    }
+}
+
+impl PdfFile {
+    fn ergaenze_aus(&mut self, other: PdfFile) {
+        self.digitalisiert = self.digitalisiert || other.digitalisiert;
+        for (k, v) in other.hocr.seiten {
+            self.hocr.seiten.entry(k).or_insert(v);
+        }
+        for (k, v) in other.anpassungen_seite {
+            self.anpassungen_seite.entry(k).or_insert(v);
+        }
+        let gb = &mut self.analysiert;
+        if gb.bestandsverzeichnis.is_empty() {
+            gb.bestandsverzeichnis = other.analysiert.bestandsverzeichnis;
+        }
+        if gb.abt1.is_empty() {
+            gb.abt1 = other.analysiert.abt1;
+        }
+        if gb.abt2.is_empty() {
+            gb.abt2 = other.analysiert.abt2;
+        }
+        if gb.abt3.is_empty() {
+            gb.abt3 = other.analysiert.abt3;
+        }
+    }
+}
+
+/// Liste innerhalb einer Abteilung, in der ein Eintrag gespeichert ist
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum EintragsListe {
+    BvEintraege,
+    BvZuschreibungen,
+    BvAbschreibungen,
+    Abt1Eintraege,
+    Abt1GrundlagenEintragungen,
+    Abt1Veraenderungen,
+    Abt1Loeschungen,
+    Abt2Eintraege,
+    Abt2Veraenderungen,
+    Abt2Loeschungen,
+    Abt3Eintraege,
+    Abt3Veraenderungen,
+    Abt3Loeschungen,
+}
+
+impl EintragsListe {
+    /// Alle Listen in der Reihenfolge, in der sie im Grundbuch erscheinen
+    pub const ALLE: [EintragsListe; 13] = [
+        EintragsListe::BvEintraege,
+        EintragsListe::BvZuschreibungen,
+        EintragsListe::BvAbschreibungen,
+        EintragsListe::Abt1Eintraege,
+        EintragsListe::Abt1GrundlagenEintragungen,
+        EintragsListe::Abt1Veraenderungen,
+        EintragsListe::Abt1Loeschungen,
+        EintragsListe::Abt2Eintraege,
+        EintragsListe::Abt2Veraenderungen,
+        EintragsListe::Abt2Loeschungen,
+        EintragsListe::Abt3Eintraege,
+        EintragsListe::Abt3Veraenderungen,
+        EintragsListe::Abt3Loeschungen,
+    ];
+
+    pub fn abteilung(&self) -> Abteilungsbereich {
+        use self::EintragsListe::*;
+        match self {
+            BvEintraege | BvZuschreibungen | BvAbschreibungen => {
+                Abteilungsbereich::Bestandsverzeichnis
+            }
+            Abt1Eintraege | Abt1GrundlagenEintragungen | Abt1Veraenderungen | Abt1Loeschungen => {
+                Abteilungsbereich::Abt1
+            }
+            Abt2Eintraege | Abt2Veraenderungen | Abt2Loeschungen => Abteilungsbereich::Abt2,
+            Abt3Eintraege | Abt3Veraenderungen | Abt3Loeschungen => Abteilungsbereich::Abt3,
+        }
+    }
+}
+
+/// Textfeld (`StringOrLines`) eines Eintrags
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum TextFeld {
+    /// `lfd_nr` der Veränderungen / Löschungen
+    LfdNr,
+    BvNr,
+    ZuNr,
+    Bezeichnung,
+    Eigentuemer,
+    GrundlageDerEintragung,
+    Betrag,
+    Text,
+}
+
+/// Pfad zu einem Eintrag im Grundbuch (Liste + Index in der Liste)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct EintragPfad {
+    pub liste: EintragsListe,
+    pub index: usize,
+}
+
+/// Pfad zu einem Textfeld eines Eintrags
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FeldPfad {
+    pub liste: EintragsListe,
+    pub index: usize,
+    pub feld: TextFeld,
+}
+
+impl FeldPfad {
+    pub fn eintrag(&self) -> EintragPfad {
+        EintragPfad {
+            liste: self.liste,
+            index: self.index,
+        }
+    }
+
+    /// Anzeige-Label des Felds, siehe `feld_label`
+    pub fn label(&self, sprache: Sprache) -> &'static str {
+        feld_label(self.liste, self.feld, sprache)
+    }
+}
+
+/// Sprache für Anzeige-Labels
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum Sprache {
+    De,
+    En,
+}
+
+/// Lesbares Label eines Textfelds für Exporte / UI, z.B. (`Abt3Eintraege`, `Betrag`)
+/// ergibt "Betrag" bzw. "Amount"
+pub fn feld_label(liste: EintragsListe, feld: TextFeld, sprache: Sprache) -> &'static str {
+    use self::EintragsListe::*;
+    let (de, en) = match (liste, feld) {
+        (_, TextFeld::LfdNr) => ("Lfd. Nr.", "Entry no."),
+        (_, TextFeld::BvNr) => ("Lfd. Nr. BV", "Property no."),
+        (_, TextFeld::ZuNr) => ("Zu Nr.", "Related no."),
+        (_, TextFeld::Bezeichnung) => ("Bezeichnung", "Description"),
+        (_, TextFeld::Eigentuemer) => ("Eigentümer", "Owner"),
+        (_, TextFeld::GrundlageDerEintragung) => ("Grundlage der Eintragung", "Basis of entry"),
+        (_, TextFeld::Betrag) => ("Betrag", "Amount"),
+        (BvZuschreibungen, TextFeld::Text) => ("Zuschreibungen", "Additions"),
+        (BvAbschreibungen, TextFeld::Text) => ("Abschreibungen", "Deductions"),
+        (Abt1GrundlagenEintragungen, TextFeld::Text) => {
+            ("Grundlage der Eintragung", "Basis of entry")
+        }
+        (Abt2Eintraege, TextFeld::Text) => {
+            ("Lasten und Beschränkungen", "Encumbrances and restrictions")
+        }
+        (Abt3Eintraege, TextFeld::Text) => (
+            "Hypotheken, Grundschulden, Rentenschulden",
+            "Mortgages, land charges, annuity charges",
+        ),
+        (Abt1Veraenderungen | Abt2Veraenderungen | Abt3Veraenderungen, TextFeld::Text) => {
+            ("Veränderungen", "Changes")
+        }
+        (Abt1Loeschungen | Abt2Loeschungen | Abt3Loeschungen, TextFeld::Text) => {
+            ("Löschungen", "Cancellations")
+        }
+        (BvEintraege | Abt1Eintraege, TextFeld::Text) => ("Text", "Text"),
+    };
+    match sprache {
+        Sprache::De => de,
+        Sprache::En => en,
+    }
+}
+
+/// Eigenständiger (besessener) Eintrag einer beliebigen Abteilung
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum GrundbuchEintrag {
+    Bv(BvEintrag),
+    BvZuschreibung(BvZuschreibung),
+    BvAbschreibung(BvAbschreibung),
+    Abt1(Abt1Eintrag),
+    Abt1GrundEintragung(Abt1GrundEintragung),
+    Abt1Veraenderung(Abt1Veraenderung),
+    Abt1Loeschung(Abt1Loeschung),
+    Abt2(Abt2Eintrag),
+    Abt2Veraenderung(Abt2Veraenderung),
+    Abt2Loeschung(Abt2Loeschung),
+    Abt3(Abt3Eintrag),
+    Abt3Veraenderung(Abt3Veraenderung),
+    Abt3Loeschung(Abt3Loeschung),
+}
+
+/// Veränderliche Referenz auf einen beliebigen Eintrag im Grundbuch
+#[derive(Debug)]
+pub enum GrundbuchEintragMut<'a> {
+    Bv(&'a mut BvEintrag),
+    BvZuschreibung(&'a mut BvZuschreibung),
+    BvAbschreibung(&'a mut BvAbschreibung),
+    Abt1(&'a mut Abt1Eintrag),
+    Abt1GrundEintragung(&'a mut Abt1GrundEintragung),
+    Abt1Veraenderung(&'a mut Abt1Veraenderung),
+    Abt1Loeschung(&'a mut Abt1Loeschung),
+    Abt2(&'a mut Abt2Eintrag),
+    Abt2Veraenderung(&'a mut Abt2Veraenderung),
+    Abt2Loeschung(&'a mut Abt2Loeschung),
+    Abt3(&'a mut Abt3Eintrag),
+    Abt3Veraenderung(&'a mut Abt3Veraenderung),
+    Abt3Loeschung(&'a mut Abt3Loeschung),
+}
+
+impl GrundbuchEintrag {
+    pub fn as_ref(&self) -> GrundbuchEintragRef<'_> {
+        match self {
+            GrundbuchEintrag::Bv(e) => GrundbuchEintragRef::Bv(e),
+            GrundbuchEintrag::BvZuschreibung(e) => GrundbuchEintragRef::BvZuschreibung(e),
+            GrundbuchEintrag::BvAbschreibung(e) => GrundbuchEintragRef::BvAbschreibung(e),
+            GrundbuchEintrag::Abt1(e) => GrundbuchEintragRef::Abt1(e),
+            GrundbuchEintrag::Abt1GrundEintragung(e) => GrundbuchEintragRef::Abt1GrundEintragung(e),
+            GrundbuchEintrag::Abt1Veraenderung(e) => GrundbuchEintragRef::Abt1Veraenderung(e),
+            GrundbuchEintrag::Abt1Loeschung(e) => GrundbuchEintragRef::Abt1Loeschung(e),
+            GrundbuchEintrag::Abt2(e) => GrundbuchEintragRef::Abt2(e),
+            GrundbuchEintrag::Abt2Veraenderung(e) => GrundbuchEintragRef::Abt2Veraenderung(e),
+            GrundbuchEintrag::Abt2Loeschung(e) => GrundbuchEintragRef::Abt2Loeschung(e),
+            GrundbuchEintrag::Abt3(e) => GrundbuchEintragRef::Abt3(e),
+            GrundbuchEintrag::Abt3Veraenderung(e) => GrundbuchEintragRef::Abt3Veraenderung(e),
+            GrundbuchEintrag::Abt3Loeschung(e) => GrundbuchEintragRef::Abt3Loeschung(e),
+        }
+    }
+
+    pub fn as_mut(&mut self) -> GrundbuchEintragMut<'_> {
+        match self {
+            GrundbuchEintrag::Bv(e) => GrundbuchEintragMut::Bv(e),
+            GrundbuchEintrag::BvZuschreibung(e) => GrundbuchEintragMut::BvZuschreibung(e),
+            GrundbuchEintrag::BvAbschreibung(e) => GrundbuchEintragMut::BvAbschreibung(e),
+            GrundbuchEintrag::Abt1(e) => GrundbuchEintragMut::Abt1(e),
+            GrundbuchEintrag::Abt1GrundEintragung(e) => GrundbuchEintragMut::Abt1GrundEintragung(e),
+            GrundbuchEintrag::Abt1Veraenderung(e) => GrundbuchEintragMut::Abt1Veraenderung(e),
+            GrundbuchEintrag::Abt1Loeschung(e) => GrundbuchEintragMut::Abt1Loeschung(e),
+            GrundbuchEintrag::Abt2(e) => GrundbuchEintragMut::Abt2(e),
+            GrundbuchEintrag::Abt2Veraenderung(e) => GrundbuchEintragMut::Abt2Veraenderung(e),
+            GrundbuchEintrag::Abt2Loeschung(e) => GrundbuchEintragMut::Abt2Loeschung(e),
+            GrundbuchEintrag::Abt3(e) => GrundbuchEintragMut::Abt3(e),
+            GrundbuchEintrag::Abt3Veraenderung(e) => GrundbuchEintragMut::Abt3Veraenderung(e),
+            GrundbuchEintrag::Abt3Loeschung(e) => GrundbuchEintragMut::Abt3Loeschung(e),
+        }
+    }
+
+    /// Liste, in die Einträge dieses Typs gehören
+    pub fn liste(&self) -> EintragsListe {
+        self.as_ref().liste()
+    }
+}
+
+impl<'a> GrundbuchEintragRef<'a> {
+    /// Kopie des Eintrags
+    pub fn to_owned(&self) -> GrundbuchEintrag {
+        match *self {
+            GrundbuchEintragRef::Bv(e) => GrundbuchEintrag::Bv(e.clone()),
+            GrundbuchEintragRef::BvZuschreibung(e) => GrundbuchEintrag::BvZuschreibung(e.clone()),
+            GrundbuchEintragRef::BvAbschreibung(e) => GrundbuchEintrag::BvAbschreibung(e.clone()),
+            GrundbuchEintragRef::Abt1(e) => GrundbuchEintrag::Abt1(e.clone()),
+            GrundbuchEintragRef::Abt1GrundEintragung(e) => {
+                GrundbuchEintrag::Abt1GrundEintragung(e.clone())
+            }
+            GrundbuchEintragRef::Abt1Veraenderung(e) => {
+                GrundbuchEintrag::Abt1Veraenderung(e.clone())
+            }
+            GrundbuchEintragRef::Abt1Loeschung(e) => GrundbuchEintrag::Abt1Loeschung(e.clone()),
+            GrundbuchEintragRef::Abt2(e) => GrundbuchEintrag::Abt2(e.clone()),
+            GrundbuchEintragRef::Abt2Veraenderung(e) => {
+                GrundbuchEintrag::Abt2Veraenderung(e.clone())
+            }
+            GrundbuchEintragRef::Abt2Loeschung(e) => GrundbuchEintrag::Abt2Loeschung(e.clone()),
+            GrundbuchEintragRef::Abt3(e) => GrundbuchEintrag::Abt3(e.clone()),
+            GrundbuchEintragRef::Abt3Veraenderung(e) => {
+                GrundbuchEintrag::Abt3Veraenderung(e.clone())
+            }
+            GrundbuchEintragRef::Abt3Loeschung(e) => GrundbuchEintrag::Abt3Loeschung(e.clone()),
+        }
+    }
+
+    /// Liste, in der der Eintrag gespeichert ist
+    pub fn liste(&self) -> EintragsListe {
+        match self {
+            GrundbuchEintragRef::Bv(_) => EintragsListe::BvEintraege,
+            GrundbuchEintragRef::BvZuschreibung(_) => EintragsListe::BvZuschreibungen,
+            GrundbuchEintragRef::BvAbschreibung(_) => EintragsListe::BvAbschreibungen,
+            GrundbuchEintragRef::Abt1(_) => EintragsListe::Abt1Eintraege,
+            GrundbuchEintragRef::Abt1GrundEintragung(_) => {
+                EintragsListe::Abt1GrundlagenEintragungen
+            }
+            GrundbuchEintragRef::Abt1Veraenderung(_) => EintragsListe::Abt1Veraenderungen,
+            GrundbuchEintragRef::Abt1Loeschung(_) => EintragsListe::Abt1Loeschungen,
+            GrundbuchEintragRef::Abt2(_) => EintragsListe::Abt2Eintraege,
+            GrundbuchEintragRef::Abt2Veraenderung(_) => EintragsListe::Abt2Veraenderungen,
+            GrundbuchEintragRef::Abt2Loeschung(_) => EintragsListe::Abt2Loeschungen,
+            GrundbuchEintragRef::Abt3(_) => EintragsListe::Abt3Eintraege,
+            GrundbuchEintragRef::Abt3Veraenderung(_) => EintragsListe::Abt3Veraenderungen,
+            GrundbuchEintragRef::Abt3Loeschung(_) => EintragsListe::Abt3Loeschungen,
+        }
+    }
+
+    /// Textfelder, die dieser Eintragstyp besitzt
+    pub fn text_felder(&self) -> &'static [TextFeld] {
+        use self::TextFeld::*;
+        match self {
+            GrundbuchEintragRef::Bv(BvEintrag::Flurstueck(_)) => &[Bezeichnung],
+            GrundbuchEintragRef::Bv(BvEintrag::Recht(_)) => &[ZuNr, Text],
+            GrundbuchEintragRef::BvZuschreibung(_) | GrundbuchEintragRef::BvAbschreibung(_) => {
+                &[BvNr, Text]
+            }
+            GrundbuchEintragRef::Abt1(Abt1Eintrag::V1(_)) => {
+                &[Eigentuemer, BvNr, GrundlageDerEintragung]
+            }
+            GrundbuchEintragRef::Abt1(Abt1Eintrag::V2(_)) => &[Eigentuemer],
+            GrundbuchEintragRef::Abt1GrundEintragung(_) => &[BvNr, Text],
+            GrundbuchEintragRef::Abt1Veraenderung(_)
+            | GrundbuchEintragRef::Abt1Loeschung(_)
+            | GrundbuchEintragRef::Abt2Veraenderung(_)
+            | GrundbuchEintragRef::Abt2Loeschung(_) => &[LfdNr, Text],
+            GrundbuchEintragRef::Abt2(_) => &[BvNr, Text],
+            GrundbuchEintragRef::Abt3(_) => &[BvNr, Betrag, Text],
+            GrundbuchEintragRef::Abt3Veraenderung(_) | GrundbuchEintragRef::Abt3Loeschung(_) => {
+                &[LfdNr, Betrag, Text]
+            }
+        }
+    }
+
+    /// Inhalt eines Textfelds, `None` wenn der Eintragstyp das Feld nicht besitzt
+    /// (oder die optionale `bezeichnung` eines Flurstücks nicht gesetzt ist)
+    pub fn text_feld(&self, feld: TextFeld) -> Option<&'a StringOrLines> {
+        use self::TextFeld::*;
+        match (*self, feld) {
+            (GrundbuchEintragRef::Bv(BvEintrag::Flurstueck(e)), Bezeichnung) => {
+                e.bezeichnung.as_ref()
+            }
+            (GrundbuchEintragRef::Bv(BvEintrag::Recht(e)), ZuNr) => Some(&e.zu_nr),
+            (GrundbuchEintragRef::Bv(BvEintrag::Recht(e)), Text) => Some(&e.text),
+            (GrundbuchEintragRef::BvZuschreibung(e), BvNr) => Some(&e.bv_nr),
+            (GrundbuchEintragRef::BvZuschreibung(e), Text) => Some(&e.text),
+            (GrundbuchEintragRef::BvAbschreibung(e), BvNr) => Some(&e.bv_nr),
+            (GrundbuchEintragRef::BvAbschreibung(e), Text) => Some(&e.text),
+            (GrundbuchEintragRef::Abt1(Abt1Eintrag::V1(e)), Eigentuemer) => Some(&e.eigentuemer),
+            (GrundbuchEintragRef::Abt1(Abt1Eintrag::V1(e)), BvNr) => Some(&e.bv_nr),
+            (GrundbuchEintragRef::Abt1(Abt1Eintrag::V1(e)), GrundlageDerEintragung) => {
+                Some(&e.grundlage_der_eintragung)
+            }
+            (GrundbuchEintragRef::Abt1(Abt1Eintrag::V2(e)), Eigentuemer) => Some(&e.eigentuemer),
+            (GrundbuchEintragRef::Abt1GrundEintragung(e), BvNr) => Some(&e.bv_nr),
+            (GrundbuchEintragRef::Abt1GrundEintragung(e), Text) => Some(&e.text),
+            (GrundbuchEintragRef::Abt1Veraenderung(e), LfdNr) => Some(&e.lfd_nr),
+            (GrundbuchEintragRef::Abt1Veraenderung(e), Text) => Some(&e.text),
+            (GrundbuchEintragRef::Abt1Loeschung(e), LfdNr) => Some(&e.lfd_nr),
+            (GrundbuchEintragRef::Abt1Loeschung(e), Text) => Some(&e.text),
+            (GrundbuchEintragRef::Abt2(e), BvNr) => Some(&e.bv_nr),
+            (GrundbuchEintragRef::Abt2(e), Text) => Some(&e.text),
+            (GrundbuchEintragRef::Abt2Veraenderung(e), LfdNr) => Some(&e.lfd_nr),
+            (GrundbuchEintragRef::Abt2Veraenderung(e), Text) => Some(&e.text),
+            (GrundbuchEintragRef::Abt2Loeschung(e), LfdNr) => Some(&e.lfd_nr),
+            (GrundbuchEintragRef::Abt2Loeschung(e), Text) => Some(&e.text),
+            (GrundbuchEintragRef::Abt3(e), BvNr) => Some(&e.bv_nr),
+            (GrundbuchEintragRef::Abt3(e), Betrag) => Some(&e.betrag),
+            (GrundbuchEintragRef::Abt3(e), Text) => Some(&e.text),
+            (GrundbuchEintragRef::Abt3Veraenderung(e), LfdNr) => Some(&e.lfd_nr),
+            (GrundbuchEintragRef::Abt3Veraenderung(e), Betrag) => Some(&e.betrag),
+            (GrundbuchEintragRef::Abt3Veraenderung(e), Text) => Some(&e.text),
+            (GrundbuchEintragRef::Abt3Loeschung(e), LfdNr) => Some(&e.lfd_nr),
+            (GrundbuchEintragRef::Abt3Loeschung(e), Betrag) => Some(&e.betrag),
+            (GrundbuchEintragRef::Abt3Loeschung(e), Text) => Some(&e.text),
+            _ => None,
+        }
+    }
+
+    /// Manuell gesetzte Rötung des Eintrags
+    pub fn manuell_geroetet(&self) -> Option<bool> {
+        match self {
+            GrundbuchEintragRef::Bv(BvEintrag::Flurstueck(e)) => e.manuell_geroetet,
+            GrundbuchEintragRef::Bv(BvEintrag::Recht(e)) => e.manuell_geroetet,
+            GrundbuchEintragRef::BvZuschreibung(e) => e.manuell_geroetet,
+            GrundbuchEintragRef::BvAbschreibung(e) => e.manuell_geroetet,
+            GrundbuchEintragRef::Abt1(Abt1Eintrag::V1(e)) => e.manuell_geroetet,
+            GrundbuchEintragRef::Abt1(Abt1Eintrag::V2(e)) => e.manuell_geroetet,
+            GrundbuchEintragRef::Abt1GrundEintragung(e) => e.manuell_geroetet,
+            GrundbuchEintragRef::Abt1Veraenderung(e) => e.manuell_geroetet,
+            GrundbuchEintragRef::Abt1Loeschung(e) => e.manuell_geroetet,
+            GrundbuchEintragRef::Abt2(e) => e.manuell_geroetet,
+            GrundbuchEintragRef::Abt2Veraenderung(e) => e.manuell_geroetet,
+            GrundbuchEintragRef::Abt2Loeschung(e) => e.manuell_geroetet,
+            GrundbuchEintragRef::Abt3(e) => e.manuell_geroetet,
+            GrundbuchEintragRef::Abt3Veraenderung(e) => e.manuell_geroetet,
+            GrundbuchEintragRef::Abt3Loeschung(e) => e.manuell_geroetet,
+        }
+    }
+
+    /// Laufende Nummer als Text: bei Einträgen die `lfd_nr`, bei Zu- / Abschreibungen die
+    /// `bv_nr`, bei Veränderungen / Löschungen der Text der Spalte ("1, 2"), sonst leer
+    pub fn lfd_nr_string(&self) -> String {
+        match self {
+            GrundbuchEintragRef::Bv(b) => b.get_lfd_nr().to_string(),
+            GrundbuchEintragRef::BvZuschreibung(e) => e.bv_nr.text(),
+            GrundbuchEintragRef::BvAbschreibung(e) => e.bv_nr.text(),
+            GrundbuchEintragRef::Abt1(a) => a.get_lfd_nr().to_string(),
+            GrundbuchEintragRef::Abt1GrundEintragung(_) => String::new(),
+            GrundbuchEintragRef::Abt1Veraenderung(e) => e.lfd_nr.text(),
+            GrundbuchEintragRef::Abt1Loeschung(e) => e.lfd_nr.text(),
+            GrundbuchEintragRef::Abt2(a) => a.lfd_nr_mit_zusatz(),
+            GrundbuchEintragRef::Abt2Veraenderung(e) => e.lfd_nr.text(),
+            GrundbuchEintragRef::Abt2Loeschung(e) => e.lfd_nr.text(),
+            GrundbuchEintragRef::Abt3(a) => a.lfd_nr.to_string(),
+            GrundbuchEintragRef::Abt3Veraenderung(e) => e.lfd_nr.text(),
+            GrundbuchEintragRef::Abt3Loeschung(e) => e.lfd_nr.text(),
+        }
+    }
+
+    /// Vollständige lfd. Nr. eines Haupteintrags (BV, Abt. 1 - 3, in Abt. 2 mit Zusatz wie
+    /// "1a"), `None` bei Veränderungen, Löschungen usw.
+    pub fn laufende_nummer(&self) -> Option<LaufendeNummer> {
+        match self {
+            GrundbuchEintragRef::Bv(b) => Some(b.get_lfd_nr().into()),
+            GrundbuchEintragRef::Abt1(a) => Some(a.get_lfd_nr().into()),
+            GrundbuchEintragRef::Abt2(a) => Some(a.laufende_nummer()),
+            GrundbuchEintragRef::Abt3(a) => Some(a.lfd_nr.into()),
+            _ => None,
+        }
+    }
+
+    /// Ob ein Haupteintrag (BV, Abt. 1 - 3) eine lfd. Nr. ungleich 0 hat. Bei Veränderungen,
+    /// Löschungen usw. ist die lfd. Nr. ein Textfeld, hier immer `false`.
+    pub fn hat_gueltige_lfd_nr(&self) -> bool {
+        match self {
+            GrundbuchEintragRef::Bv(b) => b.hat_gueltige_lfd_nr(),
+            GrundbuchEintragRef::Abt1(a) => a.hat_gueltige_lfd_nr(),
+            GrundbuchEintragRef::Abt2(a) => a.hat_gueltige_lfd_nr(),
+            GrundbuchEintragRef::Abt3(a) => a.hat_gueltige_lfd_nr(),
+            _ => false,
+        }
+    }
+
+    /// Rötungsstatus aus automatischer und manueller Rötung
+    pub fn roetungsstatus(&self) -> Roetungsstatus {
+        Roetungsstatus::aus_flags(self.automatisch_geroetet(), self.manuell_geroetet())
+    }
+
+    /// Automatisch erkannte Rötung des Eintrags
+    pub fn automatisch_geroetet(&self) -> Option<bool> {
+        match self {
+            GrundbuchEintragRef::Bv(BvEintrag::Flurstueck(e)) => e.automatisch_geroetet,
+            GrundbuchEintragRef::Bv(BvEintrag::Recht(e)) => e.automatisch_geroetet,
+            GrundbuchEintragRef::BvZuschreibung(e) => e.automatisch_geroetet,
+            GrundbuchEintragRef::BvAbschreibung(e) => e.automatisch_geroetet,
+            GrundbuchEintragRef::Abt1(Abt1Eintrag::V1(e)) => e.automatisch_geroetet,
+            GrundbuchEintragRef::Abt1(Abt1Eintrag::V2(e)) => e.automatisch_geroetet,
+            GrundbuchEintragRef::Abt1GrundEintragung(e) => e.automatisch_geroetet,
+            GrundbuchEintragRef::Abt1Veraenderung(e) => e.automatisch_geroetet,
+            GrundbuchEintragRef::Abt1Loeschung(e) => e.automatisch_geroetet,
+            GrundbuchEintragRef::Abt2(e) => e.automatisch_geroetet,
+            GrundbuchEintragRef::Abt2Veraenderung(e) => e.automatisch_geroetet,
+            GrundbuchEintragRef::Abt2Loeschung(e) => e.automatisch_geroetet,
+            GrundbuchEintragRef::Abt3(e) => e.automatisch_geroetet,
+            GrundbuchEintragRef::Abt3Veraenderung(e) => e.automatisch_geroetet,
+            GrundbuchEintragRef::Abt3Loeschung(e) => e.automatisch_geroetet,
+        }
+    }
+
+    /// Ob der Eintrag automatisch oder manuell gerötet ist (siehe `roetungsstatus`)
+    pub fn ist_geroetet(&self) -> bool {
+        self.roetungsstatus().ist_geroetet()
+    }
+
+    /// Abteilung, in der der Eintrag gespeichert ist
+    pub fn abteilung(&self) -> Abteilungsbereich {
+        self.liste().abteilung()
+    }
+
+    /// Haupttext des Eintrags als String (siehe `haupttext`), leer wenn nicht vorhanden
+    pub fn text(&self) -> String {
+        self.haupttext().map(|t| t.text()).unwrap_or_default()
+    }
+}
+
+impl<'a> GrundbuchEintragMut<'a> {
+    /// Veränderlicher Zugriff auf ein Textfeld, `None` wenn der Eintragstyp das Feld nicht besitzt
+    /// (eine fehlende `bezeichnung` eines Flurstücks wird dabei leer angelegt)
+    pub fn text_feld_mut(self, feld: TextFeld) -> Option<&'a mut StringOrLines> {
+        use self::TextFeld::*;
+        match (self, feld) {
+            (GrundbuchEintragMut::Bv(BvEintrag::Flurstueck(e)), Bezeichnung) => {
+                Some(e.bezeichnung.get_or_insert_with(StringOrLines::default))
+            }
+            (GrundbuchEintragMut::Bv(BvEintrag::Recht(e)), ZuNr) => Some(&mut e.zu_nr),
+            (GrundbuchEintragMut::Bv(BvEintrag::Recht(e)), Text) => Some(&mut e.text),
+            (GrundbuchEintragMut::BvZuschreibung(e), BvNr) => Some(&mut e.bv_nr),
+            (GrundbuchEintragMut::BvZuschreibung(e), Text) => Some(&mut e.text),
+            (GrundbuchEintragMut::BvAbschreibung(e), BvNr) => Some(&mut e.bv_nr),
+            (GrundbuchEintragMut::BvAbschreibung(e), Text) => Some(&mut e.text),
+            (GrundbuchEintragMut::Abt1(Abt1Eintrag::V1(e)), Eigentuemer) => {
+                Some(&mut e.eigentuemer)
+            }
+            (GrundbuchEintragMut::Abt1(Abt1Eintrag::V1(e)), BvNr) => Some(&mut e.bv_nr),
+            (GrundbuchEintragMut::Abt1(Abt1Eintrag::V1(e)), GrundlageDerEintragung) => {
+                Some(&mut e.grundlage_der_eintragung)
+            }
+            (GrundbuchEintragMut::Abt1(Abt1Eintrag::V2(e)), Eigentuemer) => {
+                Some(&mut e.eigentuemer)
+            }
+            (GrundbuchEintragMut::Abt1GrundEintragung(e), BvNr) => Some(&mut e.bv_nr),
+            (GrundbuchEintragMut::Abt1GrundEintragung(e), Text) => Some(&mut e.text),
+            (GrundbuchEintragMut::Abt1Veraenderung(e), LfdNr) => Some(&mut e.lfd_nr),
+            (GrundbuchEintragMut::Abt1Veraenderung(e), Text) => Some(&mut e.text),
+            (GrundbuchEintragMut::Abt1Loeschung(e), LfdNr) => Some(&mut e.lfd_nr),
+            (GrundbuchEintragMut::Abt1Loeschung(e), Text) => Some(&mut e.text),
+            (GrundbuchEintragMut::Abt2(e), BvNr) => Some(&mut e.bv_nr),
+            (GrundbuchEintragMut::Abt2(e), Text) => Some(&mut e.text),
+            (GrundbuchEintragMut::Abt2Veraenderung(e), LfdNr) => Some(&mut e.lfd_nr),
+            (GrundbuchEintragMut::Abt2Veraenderung(e), Text) => Some(&mut e.text),
+            (GrundbuchEintragMut::Abt2Loeschung(e), LfdNr) => Some(&mut e.lfd_nr),
+            (GrundbuchEintragMut::Abt2Loeschung(e), Text) => Some(&mut e.text),
+            (GrundbuchEintragMut::Abt3(e), BvNr) => Some(&mut e.bv_nr),
+            (GrundbuchEintragMut::Abt3(e), Betrag) => Some(&mut e.betrag),
+            (GrundbuchEintragMut::Abt3(e), Text) => Some(&mut e.text),
+            (GrundbuchEintragMut::Abt3Veraenderung(e), LfdNr) => Some(&mut e.lfd_nr),
+            (GrundbuchEintragMut::Abt3Veraenderung(e), Betrag) => Some(&mut e.betrag),
+            (GrundbuchEintragMut::Abt3Veraenderung(e), Text) => Some(&mut e.text),
+            (GrundbuchEintragMut::Abt3Loeschung(e), LfdNr) => Some(&mut e.lfd_nr),
+            (GrundbuchEintragMut::Abt3Loeschung(e), Betrag) => Some(&mut e.betrag),
+            (GrundbuchEintragMut::Abt3Loeschung(e), Text) => Some(&mut e.text),
+            _ => None,
+        }
+    }
+
+    /// Veränderlicher Zugriff auf die manuell gesetzte Rötung
+    pub fn manuell_geroetet_mut(self) -> &'a mut Option<bool> {
+        match self {
+            GrundbuchEintragMut::Bv(BvEintrag::Flurstueck(e)) => &mut e.manuell_geroetet,
+            GrundbuchEintragMut::Bv(BvEintrag::Recht(e)) => &mut e.manuell_geroetet,
+            GrundbuchEintragMut::BvZuschreibung(e) => &mut e.manuell_geroetet,
+            GrundbuchEintragMut::BvAbschreibung(e) => &mut e.manuell_geroetet,
+            GrundbuchEintragMut::Abt1(Abt1Eintrag::V1(e)) => &mut e.manuell_geroetet,
+            GrundbuchEintragMut::Abt1(Abt1Eintrag::V2(e)) => &mut e.manuell_geroetet,
+            GrundbuchEintragMut::Abt1GrundEintragung(e) => &mut e.manuell_geroetet,
+            GrundbuchEintragMut::Abt1Veraenderung(e) => &mut e.manuell_geroetet,
+            GrundbuchEintragMut::Abt1Loeschung(e) => &mut e.manuell_geroetet,
+            GrundbuchEintragMut::Abt2(e) => &mut e.manuell_geroetet,
+            GrundbuchEintragMut::Abt2Veraenderung(e) => &mut e.manuell_geroetet,
+            GrundbuchEintragMut::Abt2Loeschung(e) => &mut e.manuell_geroetet,
+            GrundbuchEintragMut::Abt3(e) => &mut e.manuell_geroetet,
+            GrundbuchEintragMut::Abt3Veraenderung(e) => &mut e.manuell_geroetet,
+            GrundbuchEintragMut::Abt3Loeschung(e) => &mut e.manuell_geroetet,
+        }
+    }
+
+    /// Veränderlicher Zugriff auf die Position des Eintrags im PDF
+    pub fn position_in_pdf_mut(self) -> &'a mut Option<PositionInPdf> {
+        match self {
+            GrundbuchEintragMut::Bv(BvEintrag::Flurstueck(e)) => &mut e.position_in_pdf,
+            GrundbuchEintragMut::Bv(BvEintrag::Recht(e)) => &mut e.position_in_pdf,
+            GrundbuchEintragMut::BvZuschreibung(e) => &mut e.position_in_pdf,
+            GrundbuchEintragMut::BvAbschreibung(e) => &mut e.position_in_pdf,
+            GrundbuchEintragMut::Abt1(Abt1Eintrag::V1(e)) => &mut e.position_in_pdf,
+            GrundbuchEintragMut::Abt1(Abt1Eintrag::V2(e)) => &mut e.position_in_pdf,
+            GrundbuchEintragMut::Abt1GrundEintragung(e) => &mut e.position_in_pdf,
+            GrundbuchEintragMut::Abt1Veraenderung(e) => &mut e.position_in_pdf,
+            GrundbuchEintragMut::Abt1Loeschung(e) => &mut e.position_in_pdf,
+            GrundbuchEintragMut::Abt2(e) => &mut e.position_in_pdf,
+            GrundbuchEintragMut::Abt2Veraenderung(e) => &mut e.position_in_pdf,
+            GrundbuchEintragMut::Abt2Loeschung(e) => &mut e.position_in_pdf,
+            GrundbuchEintragMut::Abt3(e) => &mut e.position_in_pdf,
+            GrundbuchEintragMut::Abt3Veraenderung(e) => &mut e.position_in_pdf,
+            GrundbuchEintragMut::Abt3Loeschung(e) => &mut e.position_in_pdf,
+        }
+    }
+}
+
+impl Grundbuch {
+    /// Anzahl der Einträge in einer Liste
+    pub fn anzahl(&self, liste: EintragsListe) -> usize {
+        use self::EintragsListe::*;
+        match liste {
+            BvEintraege => self.bestandsverzeichnis.eintraege.len(),
+            BvZuschreibungen => self.bestandsverzeichnis.zuschreibungen.len(),
+            BvAbschreibungen => self.bestandsverzeichnis.abschreibungen.len(),
+            Abt1Eintraege => self.abt1.eintraege.len(),
+            Abt1GrundlagenEintragungen => self.abt1.grundlagen_eintragungen.len(),
+            Abt1Veraenderungen => self.abt1.veraenderungen.len(),
+            Abt1Loeschungen => self.abt1.loeschungen.len(),
+            Abt2Eintraege => self.abt2.eintraege.len(),
+            Abt2Veraenderungen => self.abt2.veraenderungen.len(),
+            Abt2Loeschungen => self.abt2.loeschungen.len(),
+            Abt3Eintraege => self.abt3.eintraege.len(),
+            Abt3Veraenderungen => self.abt3.veraenderungen.len(),
+            Abt3Loeschungen => self.abt3.loeschungen.len(),
+        }
+    }
+
+    /// Eintrag an der Stelle des Pfads
+    pub fn eintrag(&self, pfad: &EintragPfad) -> Option<GrundbuchEintragRef<'_>> {
+        use self::EintragsListe::*;
+        let i = pfad.index;
+        let bv = &self.bestandsverzeichnis;
+        Some(match pfad.liste {
+            BvEintraege => GrundbuchEintragRef::Bv(bv.eintraege.get(i)?),
+            BvZuschreibungen => GrundbuchEintragRef::BvZuschreibung(bv.zuschreibungen.get(i)?),
+            BvAbschreibungen => GrundbuchEintragRef::BvAbschreibung(bv.abschreibungen.get(i)?),
+            Abt1Eintraege => GrundbuchEintragRef::Abt1(self.abt1.eintraege.get(i)?),
+            Abt1GrundlagenEintragungen => {
+                GrundbuchEintragRef::Abt1GrundEintragung(self.abt1.grundlagen_eintragungen.get(i)?)
+            }
+            Abt1Veraenderungen => {
+                GrundbuchEintragRef::Abt1Veraenderung(self.abt1.veraenderungen.get(i)?)
+            }
+            Abt1Loeschungen => GrundbuchEintragRef::Abt1Loeschung(self.abt1.loeschungen.get(i)?),
+            Abt2Eintraege => GrundbuchEintragRef::Abt2(self.abt2.eintraege.get(i)?),
+            Abt2Veraenderungen => {
+                GrundbuchEintragRef::Abt2Veraenderung(self.abt2.veraenderungen.get(i)?)
+            }
+            Abt2Loeschungen => GrundbuchEintragRef::Abt2Loeschung(self.abt2.loeschungen.get(i)?),
+            Abt3Eintraege => GrundbuchEintragRef::Abt3(self.abt3.eintraege.get(i)?),
+            Abt3Veraenderungen => {
+                GrundbuchEintragRef::Abt3Veraenderung(self.abt3.veraenderungen.get(i)?)
+            }
+            Abt3Loeschungen => GrundbuchEintragRef::Abt3Loeschung(self.abt3.loeschungen.get(i)?),
+        })
+    }
+
+    /// Veränderlicher Eintrag an der Stelle des Pfads
+    pub fn eintrag_mut(&mut self, pfad: &EintragPfad) -> Option<GrundbuchEintragMut<'_>> {
+        use self::EintragsListe::*;
+        let i = pfad.index;
+        let bv = &mut self.bestandsverzeichnis;
+        Some(match pfad.liste {
+            BvEintraege => GrundbuchEintragMut::Bv(bv.eintraege.get_mut(i)?),
+            BvZuschreibungen => GrundbuchEintragMut::BvZuschreibung(bv.zuschreibungen.get_mut(i)?),
+            BvAbschreibungen => GrundbuchEintragMut::BvAbschreibung(bv.abschreibungen.get_mut(i)?),
+            Abt1Eintraege => GrundbuchEintragMut::Abt1(self.abt1.eintraege.get_mut(i)?),
+            Abt1GrundlagenEintragungen => GrundbuchEintragMut::Abt1GrundEintragung(
+                self.abt1.grundlagen_eintragungen.get_mut(i)?,
+            ),
+            Abt1Veraenderungen => {
+                GrundbuchEintragMut::Abt1Veraenderung(self.abt1.veraenderungen.get_mut(i)?)
+            }
+            Abt1Loeschungen => {
+                GrundbuchEintragMut::Abt1Loeschung(self.abt1.loeschungen.get_mut(i)?)
+            }
+            Abt2Eintraege => GrundbuchEintragMut::Abt2(self.abt2.eintraege.get_mut(i)?),
+            Abt2Veraenderungen => {
+                GrundbuchEintragMut::Abt2Veraenderung(self.abt2.veraenderungen.get_mut(i)?)
+            }
+            Abt2Loeschungen => {
+                GrundbuchEintragMut::Abt2Loeschung(self.abt2.loeschungen.get_mut(i)?)
+            }
+            Abt3Eintraege => GrundbuchEintragMut::Abt3(self.abt3.eintraege.get_mut(i)?),
+            Abt3Veraenderungen => {
+                GrundbuchEintragMut::Abt3Veraenderung(self.abt3.veraenderungen.get_mut(i)?)
+            }
+            Abt3Loeschungen => {
+                GrundbuchEintragMut::Abt3Loeschung(self.abt3.loeschungen.get_mut(i)?)
+            }
+        })
+    }
+
+    /// Fügt den Eintrag an der Stelle `index` seiner Liste ein
+    pub fn insert_eintrag_at(
+        &mut self,
+        index: usize,
+        eintrag: GrundbuchEintrag,
+    ) -> Result<(), PatchError> {
+        let liste = eintrag.liste();
+        if index > self.anzahl(liste) {
+            return Err(PatchError::PfadNichtGefunden(EintragPfad { liste, index }));
+        }
+        let bv = &mut self.bestandsverzeichnis;
+        match eintrag {
+            GrundbuchEintrag::Bv(e) => bv.eintraege.insert(index, e),
+            GrundbuchEintrag::BvZuschreibung(e) => bv.zuschreibungen.insert(index, e),
+            GrundbuchEintrag::BvAbschreibung(e) => bv.abschreibungen.insert(index, e),
+            GrundbuchEintrag::Abt1(e) => self.abt1.eintraege.insert(index, e),
+            GrundbuchEintrag::Abt1GrundEintragung(e) => {
+                self.abt1.grundlagen_eintragungen.insert(index, e)
+            }
+            GrundbuchEintrag::Abt1Veraenderung(e) => self.abt1.veraenderungen.insert(index, e),
+            GrundbuchEintrag::Abt1Loeschung(e) => self.abt1.loeschungen.insert(index, e),
+            GrundbuchEintrag::Abt2(e) => self.abt2.eintraege.insert(index, e),
+            GrundbuchEintrag::Abt2Veraenderung(e) => self.abt2.veraenderungen.insert(index, e),
+            GrundbuchEintrag::Abt2Loeschung(e) => self.abt2.loeschungen.insert(index, e),
+            GrundbuchEintrag::Abt3(e) => self.abt3.eintraege.insert(index, e),
+            GrundbuchEintrag::Abt3Veraenderung(e) => self.abt3.veraenderungen.insert(index, e),
+            GrundbuchEintrag::Abt3Loeschung(e) => self.abt3.loeschungen.insert(index, e),
+        }
+        Ok(())
+    }
+
+    /// Entfernt den Eintrag an der Stelle des Pfads
+    pub fn remove_eintrag_at(&mut self, pfad: &EintragPfad) -> Option<GrundbuchEintrag> {
+        use self::EintragsListe::*;
+        let i = pfad.index;
+        if i >= self.anzahl(pfad.liste) {
+            return None;
+        }
+        let bv = &mut self.bestandsverzeichnis;
+        Some(match pfad.liste {
+            BvEintraege => GrundbuchEintrag::Bv(bv.eintraege.remove(i)),
+            BvZuschreibungen => GrundbuchEintrag::BvZuschreibung(bv.zuschreibungen.remove(i)),
+            BvAbschreibungen => GrundbuchEintrag::BvAbschreibung(bv.abschreibungen.remove(i)),
+            Abt1Eintraege => GrundbuchEintrag::Abt1(self.abt1.eintraege.remove(i)),
+            Abt1GrundlagenEintragungen => {
+                GrundbuchEintrag::Abt1GrundEintragung(self.abt1.grundlagen_eintragungen.remove(i))
+            }
+            Abt1Veraenderungen => {
+                GrundbuchEintrag::Abt1Veraenderung(self.abt1.veraenderungen.remove(i))
+            }
+            Abt1Loeschungen => GrundbuchEintrag::Abt1Loeschung(self.abt1.loeschungen.remove(i)),
+            Abt2Eintraege => GrundbuchEintrag::Abt2(self.abt2.eintraege.remove(i)),
+            Abt2Veraenderungen => {
+                GrundbuchEintrag::Abt2Veraenderung(self.abt2.veraenderungen.remove(i))
+            }
+            Abt2Loeschungen => GrundbuchEintrag::Abt2Loeschung(self.abt2.loeschungen.remove(i)),
+            Abt3Eintraege => GrundbuchEintrag::Abt3(self.abt3.eintraege.remove(i)),
+            Abt3Veraenderungen => {
+                GrundbuchEintrag::Abt3Veraenderung(self.abt3.veraenderungen.remove(i))
+            }
+            Abt3Loeschungen => GrundbuchEintrag::Abt3Loeschung(self.abt3.loeschungen.remove(i)),
+        })
+    }
+}
+
+/// Einzelne, serialisierbare Änderung an einem Grundbuch (für Undo / Redo und Audit-Logs)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "op")]
+pub enum GrundbuchPatch {
+    /// Ersetzt das Titelblatt
+    SetTitelblatt { neu: Titelblatt },
+    /// Ersetzt den Inhalt eines Textfelds
+    SetText { pfad: FeldPfad, neu: StringOrLines },
+    /// Setzt die manuelle Rötung eines Eintrags
+    SetGeroetet {
+        pfad: EintragPfad,
+        val: Option<bool>,
+    },
+    /// Fügt einen Eintrag an der Stelle des Pfads ein
+    InsertEintrag {
+        pfad: EintragPfad,
+        eintrag: GrundbuchEintrag,
+    },
+    /// Entfernt den Eintrag an der Stelle des Pfads
+    RemoveEintrag { pfad: EintragPfad },
+}
+
+/// Textfeld aus `Grundbuch::extrahiere_texte`: `text` kann bearbeitet werden, `lfd_nr` und
+/// `original` identifizieren den Eintrag beim Zurückschreiben mit `Grundbuch::setze_texte`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ExtrahierterText {
+    pub pfad: FeldPfad,
+    /// lfd. Nr. des Eintrags beim Extrahieren (`GrundbuchEintragRef::lfd_nr_string`)
+    pub lfd_nr: String,
+    /// Text des Felds beim Extrahieren
+    pub original: String,
+    /// Zu setzender Text
+    pub text: String,
+}
+
+/// Fehler beim Anwenden eines `GrundbuchPatch`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum PatchError {
+    /// Kein Eintrag an der Stelle des Pfads
+    PfadNichtGefunden(EintragPfad),
+    /// Der Eintragstyp an der Stelle des Pfads besitzt das Feld nicht
+    FeldNichtVorhanden(FeldPfad),
+    /// Der einzufügende Eintrag passt nicht zur Liste des Pfads
+    FalscherEintragstyp(EintragPfad),
+    /// Der Eintrag an der Stelle des Pfads ist nicht mehr derselbe wie beim Extrahieren
+    /// (andere lfd. Nr. oder anderer ursprünglicher Text), siehe `Grundbuch::setze_texte`
+    EintragGeaendert(FeldPfad),
+}
+
+impl std::fmt::Display for PatchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            PatchError::PfadNichtGefunden(p) => {
+                write!(f, "Kein Eintrag in {:?} an Index {}", p.liste, p.index)
+            }
+            PatchError::FeldNichtVorhanden(p) => write!(
+                f,
+                "Eintrag in {:?} an Index {} hat kein Feld {:?}",
+                p.liste, p.index, p.feld
+            ),
+            PatchError::FalscherEintragstyp(p) => {
+                write!(f, "Eintragstyp passt nicht zur Liste {:?}", p.liste)
+            }
+            PatchError::EintragGeaendert(p) => write!(
+                f,
+                "Eintrag in {:?} an Index {} wurde seit dem Extrahieren verändert",
+                p.liste, p.index
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PatchError {}
+
+impl Grundbuch {
+    /// Wendet eine einzelne Änderung an
+    pub fn apply(&mut self, patch: &GrundbuchPatch) -> Result<(), PatchError> {
+        match patch {
+            GrundbuchPatch::SetTitelblatt { neu } => {
+                self.titelblatt = neu.clone();
+            }
+            GrundbuchPatch::SetText { pfad, neu } => {
+                let eintrag = self
+                    .eintrag_mut(&pfad.eintrag())
+                    .ok_or(PatchError::PfadNichtGefunden(pfad.eintrag()))?;
+                let feld = eintrag
+                    .text_feld_mut(pfad.feld)
+                    .ok_or(PatchError::FeldNichtVorhanden(*pfad))?;
+                *feld = neu.clone();
+            }
+            GrundbuchPatch::SetGeroetet { pfad, val } => {
+                let eintrag = self
+                    .eintrag_mut(pfad)
+                    .ok_or(PatchError::PfadNichtGefunden(*pfad))?;
+                *eintrag.manuell_geroetet_mut() = *val;
+            }
+            GrundbuchPatch::InsertEintrag { pfad, eintrag } => {
+                if eintrag.liste() != pfad.liste {
+                    return Err(PatchError::FalscherEintragstyp(*pfad));
+                }
+                self.insert_eintrag_at(pfad.index, eintrag.clone())?;
+            }
+            GrundbuchPatch::RemoveEintrag { pfad } => {
+                self.remove_eintrag_at(pfad)
+                    .ok_or(PatchError::PfadNichtGefunden(*pfad))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Alle Textfelder der Einträge als flache Liste (Zeilen mit `\n` verbunden), z.B. für eine
+    /// externe Rechtschreibprüfung. Bearbeitet wird nur `text`, die übrigen Felder dienen
+    /// `setze_texte` zur Prüfung, ob der Pfad noch auf denselben Eintrag zeigt.
+    pub fn extrahiere_texte(&self) -> Vec<ExtrahierterText> {
+        let mut texte = Vec::new();
+        for (pfad, eintrag) in self.alle_eintraege_mit_pfad() {
+            for feld in eintrag.text_felder() {
+                if let Some(text) = eintrag.text_feld(*feld) {
+                    let text = text.text_mit("\n");
+                    texte.push(ExtrahierterText {
+                        pfad: FeldPfad {
+                            liste: pfad.liste,
+                            index: pfad.index,
+                            feld: *feld,
+                        },
+                        lfd_nr: eintrag.lfd_nr_string(),
+                        original: text.clone(),
+                        text,
+                    });
+                }
+            }
+        }
+        texte
+    }
+
+    /// Spielt mit `extrahiere_texte` extrahierte (und extern bearbeitete) Texte wieder ein.
+    /// Alle Pfade werden vorab geprüft: existiert der Eintrag nicht mehr oder haben sich seine
+    /// lfd. Nr. bzw. der ursprüngliche Text des Felds seit dem Extrahieren geändert (z.B. weil
+    /// ein Eintrag davor entfernt wurde), kommt ein Fehler zurück und das Grundbuch bleibt
+    /// unverändert. Unveränderte Texte werden nicht neu gesetzt.
+    pub fn setze_texte(&mut self, texte: &[ExtrahierterText]) -> Result<(), PatchError> {
+        for t in texte {
+            let eintrag = self
+                .eintrag(&t.pfad.eintrag())
+                .ok_or(PatchError::PfadNichtGefunden(t.pfad.eintrag()))?;
+            let aktuell = eintrag
+                .text_feld(t.pfad.feld)
+                .ok_or(PatchError::FeldNichtVorhanden(t.pfad))?;
+            if eintrag.lfd_nr_string() != t.lfd_nr || aktuell.text_mit("\n") != t.original {
+                return Err(PatchError::EintragGeaendert(t.pfad));
+            }
+        }
+
+        for t in texte {
+            if t.text == t.original {
+                continue;
+            }
+            self.apply(&GrundbuchPatch::SetText {
+                pfad: t.pfad,
+                neu: StringOrLines::from(t.text.as_str()),
+            })?;
+        }
+
+        Ok(())
+    }
+
+    /// Änderungen, die dieses Grundbuch in `other` überführen (in Reihenfolge anzuwenden)
+    pub fn diff_patches(&self, other: &Grundbuch) -> Vec<GrundbuchPatch> {
+        let mut patches = Vec::new();
+
+        if self.titelblatt != other.titelblatt {
+            patches.push(GrundbuchPatch::SetTitelblatt {
+                neu: other.titelblatt.clone(),
+            });
+        }
+
+        for liste in EintragsListe::ALLE {
+            let alt_len = self.anzahl(liste);
+            let neu_len = other.anzahl(liste);
+
+            for index in 0..alt_len.min(neu_len) {
+                let pfad = EintragPfad { liste, index };
+                let (alt, neu) = match (self.eintrag(&pfad), other.eintrag(&pfad)) {
+                    (Some(a), Some(b)) => (a, b),
+                    _ => continue,
+                };
+                if alt == neu {
+                    continue;
+                }
+                match feld_patches(&pfad, alt, neu) {
+                    Some(p) => patches.extend(p),
+                    None => {
+                        patches.push(GrundbuchPatch::RemoveEintrag { pfad });
+                        patches.push(GrundbuchPatch::InsertEintrag {
+                            pfad,
+                            eintrag: neu.to_owned(),
+                        });
+                    }
+                }
+            }
+
+            for index in alt_len..neu_len {
+                let pfad = EintragPfad { liste, index };
+                if let Some(neu) = other.eintrag(&pfad) {
+                    patches.push(GrundbuchPatch::InsertEintrag {
+                        pfad,
+                        eintrag: neu.to_owned(),
+                    });
+                }
+            }
+
+            for index in (neu_len..alt_len).rev() {
+                patches.push(GrundbuchPatch::RemoveEintrag {
+                    pfad: EintragPfad { liste, index },
+                });
+            }
+        }
+
+        patches
+    }
+}
+
+/// `SetText` / `SetGeroetet`-Änderungen von `alt` nach `neu`, `None` wenn sich die Einträge
+/// auch in anderen Feldern unterscheiden (dann muss der Eintrag ersetzt werden)
+fn feld_patches(
+    pfad: &EintragPfad,
+    alt: GrundbuchEintragRef<'_>,
+    neu: GrundbuchEintragRef<'_>,
+) -> Option<Vec<GrundbuchPatch>> {
+    if alt.liste() != neu.liste() || alt.text_felder() != neu.text_felder() {
+        return None;
+    }
+
+    let mut angeglichen = alt.to_owned();
+    for feld in neu.text_felder() {
+        if let (Some(ziel), Some(wert)) = (
+            angeglichen.as_mut().text_feld_mut(*feld),
+            neu.text_feld(*feld),
+        ) {
+            *ziel = wert.clone();
+        }
+    }
+    if let GrundbuchEintragRef::Bv(BvEintrag::Flurstueck(f)) = neu {
+        if f.bezeichnung.is_none() {
+            if let GrundbuchEintrag::Bv(BvEintrag::Flurstueck(a)) = &mut angeglichen {
+                a.bezeichnung = None;
+            }
+        }
+    }
+    *angeglichen.as_mut().manuell_geroetet_mut() = neu.manuell_geroetet();
+
+    if angeglichen.as_ref() != neu {
+        return None;
+    }
+
+    let mut patches = Vec::new();
+    for feld in neu.text_felder() {
+        let a = alt.text_feld(*feld);
+        let b = neu.text_feld(*feld);
+        if a != b {
+            // eine entfernte Flurstücks-`bezeichnung` lässt sich nicht als SetText ausdrücken
+            let neu = b.cloned()?;
+            patches.push(GrundbuchPatch::SetText {
+                pfad: FeldPfad {
+                    liste: pfad.liste,
+                    index: pfad.index,
+                    feld: *feld,
+                },
+                neu,
+            });
+        }
+    }
+    if alt.manuell_geroetet() != neu.manuell_geroetet() {
+        patches.push(GrundbuchPatch::SetGeroetet {
+            pfad: *pfad,
+            val: neu.manuell_geroetet(),
+        });
+    }
+    Some(patches)
+}
+
+impl HocrSeite {
+    /// Rechnet ein Rechteck in Pixeln (hOCR) in Millimeter auf der Seite um
+    pub fn px_to_mm(&self, rect: &Rect) -> Rect {
+        let (fx, fy) = self.mm_pro_px();
+        Rect {
+            min_x: (rect.min_x - self.parsed.bounds.min_x) * fx,
+            min_y: (rect.min_y - self.parsed.bounds.min_y) * fy,
+            max_x: (rect.max_x - self.parsed.bounds.min_x) * fx,
+            max_y: (rect.max_y - self.parsed.bounds.min_y) * fy,
+        }
+    }
+
+    /// Rechnet ein Rechteck in Millimetern auf der Seite in Pixel (hOCR) um
+    pub fn mm_to_px(&self, rect: &Rect) -> Rect {
+        let (fx, fy) = self.mm_pro_px();
+        let fx = if fx == 0.0 { 1.0 } else { fx };
+        let fy = if fy == 0.0 { 1.0 } else { fy };
+        Rect {
+            min_x: rect.min_x / fx + self.parsed.bounds.min_x,
+            min_y: rect.min_y / fy + self.parsed.bounds.min_y,
+            max_x: rect.max_x / fx + self.parsed.bounds.min_x,
+            max_y: rect.max_y / fy + self.parsed.bounds.min_y,
+        }
+    }
+
+    /// Millimeter pro Pixel in x- und y-Richtung
+    fn mm_pro_px(&self) -> (f32, f32) {
+        let w = self.parsed.bounds.width();
+        let h = self.parsed.bounds.height();
+        (
+            if w > 0.0 { self.breite_mm / w } else { 0.0 },
+            if h > 0.0 { self.hoehe_mm / h } else { 0.0 },
+        )
+    }
+
+    /// Alle Worte auf der Seite, deren Mittelpunkt innerhalb des Bereichs (in mm) liegt
+    pub fn worte_in_bereich(&self, bereich_mm: &Rect) -> Vec<&HocrWord> {
+        let bereich_px = self.mm_to_px(bereich_mm);
+        self.parsed
+            .worte()
+            .filter(|w| {
+                let (x, y) = w.bounds.center();
+                bereich_px.contains(x, y)
+            })
+            .collect()
+    }
+
+    /// Ob die Seite weniger als `min_worte` nicht-leere Worte mit einer Konfidenz von mindestens
+    /// `min_confidence` enthält (unbedruckte oder fehlgescannte Seite)
+    pub fn ist_leer(&self, min_worte: usize, min_confidence: f32) -> bool {
+        self.parsed
+            .worte()
+            .filter(|w| w.confidence >= min_confidence && !w.text.trim().is_empty())
+            .take(min_worte)
+            .count()
+            < min_worte
+    }
+
+    /// Text aller Worte, deren Mittelpunkt in der Spalte (in mm) liegt: Worte werden anhand
+    /// ihrer vertikalen Ausdehnung zu Zeilen gruppiert, innerhalb einer Zeile nach x sortiert
+    /// und mit Leerzeichen verbunden, Zeilen mit "\n"
+    pub fn text_in_spalte(&self, spalte: &Rect) -> String {
+        let mut worte = self
+            .worte_in_bereich(spalte)
+            .into_iter()
+            .filter(|w| !w.text.trim().is_empty())
+            .map(|w| (w.bounds.normalized(), w.text.trim()))
+            .collect::<Vec<_>>();
+        worte.sort_by(|a, b| a.0.center().1.total_cmp(&b.0.center().1));
+
+        let mut zeilen: Vec<(f32, Vec<(Rect, &str)>)> = Vec::new();
+        for (rect, text) in worte {
+            match zeilen.last_mut() {
+                Some((max_y, zeile)) if rect.center().1 <= *max_y => {
+                    *max_y = max_y.max(rect.max_y);
+                    zeile.push((rect, text));
+                }
+                _ => zeilen.push((rect.max_y, vec![(rect, text)])),
+            }
+        }
+
+        zeilen
+            .into_iter()
+            .map(|(_, mut zeile)| {
+                zeile.sort_by(|a, b| a.0.min_x.total_cmp(&b.0.min_x));
+                zeile.iter().map(|(_, t)| *t).collect::<Vec<_>>().join(" ")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Textzeilen je Spalten-ID (siehe `text_in_spalte`) für eine Formularseite vom Typ `typ`:
+    /// Spalten aus `SeitenTyp::default_spalten_mm`, überschrieben bzw. ergänzt durch die
+    /// `spalten` der Anpassung (in mm). Spalten ohne Text erhalten eine leere Liste.
+    pub fn extrahiere_formular(
+        &self,
+        typ: SeitenTyp,
+        anpassung: Option<&AnpassungSeite>,
+    ) -> BTreeMap<String, Vec<String>> {
+        let mut spalten = typ.default_spalten_mm(self.breite_mm, self.hoehe_mm);
+        if let Some(a) = anpassung {
+            spalten.extend(a.spalten.iter().map(|(id, r)| (id.clone(), r.clone())));
+        }
+
+        spalten
+            .into_iter()
+            .map(|(id, rect)| {
+                let zeilen = self
+                    .text_in_spalte(&rect)
+                    .lines()
+                    .map(|z| z.to_string())
+                    .filter(|z| !z.is_empty())
+                    .collect();
+                (id, zeilen)
+            })
+            .collect()
+    }
+
+    /// Bounds aller Worte der Seite (in mm) mit ihrer `confidence`, z.B. für eine Heatmap
+    /// der OCR-Qualität (siehe `farbe_fuer_konfidenz`)
+    pub fn konfidenz_rechtecke(&self) -> Vec<(Rect, f32)> {
+        self.parsed
+            .worte()
+            .map(|w| (self.px_to_mm(&w.bounds.normalized()), w.confidence))
+            .collect()
+    }
+}
+
+/// RGB-Farbe für eine Konfidenz zwischen 0.0 (rot) und 1.0 (grün), über gelb interpoliert,
+/// Werte außerhalb werden begrenzt, NaN gilt als 0.0
+pub fn farbe_fuer_konfidenz(conf: f32) -> (u8, u8, u8) {
+    let conf = if conf.is_nan() {
+        0.0
+    } else {
+        conf.clamp(0.0, 1.0)
+    };
+    let rot = ((1.0 - conf) * 2.0).min(1.0);
+    let gruen = (conf * 2.0).min(1.0);
+    (
+        (rot * 255.0).round() as u8,
+        (gruen * 255.0).round() as u8,
+        0,
+    )
+}
+
+impl Grundbuch {
+    /// Alle Einträge mit ihrem Pfad (Liste + Index)
+    pub fn alle_eintraege_mit_pfad(
+        &self,
+    ) -> impl Iterator<Item = (EintragPfad, GrundbuchEintragRef<'_>)> {
+        EintragsListe::ALLE.into_iter().flat_map(move |liste| {
+            (0..self.anzahl(liste)).filter_map(move |index| {
+                let pfad = EintragPfad { liste, index };
+                Some((pfad, self.eintrag(&pfad)?))
+            })
+        })
+    }
+}
+
+impl<'a> GrundbuchEintragRef<'a> {
+    /// Textfeld, das bei diesem Eintragstyp immer ausgefüllt sein sollte
+    pub fn pflichtfeld(&self) -> Option<TextFeld> {
+        match self {
+            GrundbuchEintragRef::Bv(BvEintrag::Flurstueck(_)) => None,
+            GrundbuchEintragRef::Abt1(_) => Some(TextFeld::Eigentuemer),
+            _ => Some(TextFeld::Text),
+        }
+    }
+}
+
+/// Einstellungen für `Grundbuch::ocr_warnungen`
+#[derive(Debug, Clone)]
+pub struct OcrWarnOptions<'a> {
+    /// hOCR-Layout, gegen das die Konfidenz der Worte geprüft wird
+    pub hocr: Option<&'a HocrLayout>,
+    /// Maximaler Anteil an Sonderzeichen in einem Textfeld
+    pub max_sonderzeichen_anteil: f32,
+    /// Minimale Länge eines Pflichttexts (in Zeichen)
+    pub min_pflichttext_laenge: usize,
+    /// Worte mit einer Konfidenz unterhalb dieses Werts werden gemeldet
+    pub min_confidence: f32,
+}
+
+impl Default for OcrWarnOptions<'_> {
+    fn default() -> Self {
+        OcrWarnOptions {
+            hocr: None,
+            max_sonderzeichen_anteil: 0.15,
+            min_pflichttext_laenge: 3,
+            min_confidence: 0.6,
+        }
+    }
+}
+
+/// Auffälliges Textfeld eines Eintrags
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct OcrWarnung {
+    pub pfad: EintragPfad,
+    /// Betroffenes Feld (`None` bei Warnungen, die den ganzen Eintrag betreffen)
+    pub feld: Option<TextFeld>,
+    pub art: OcrWarnungArt,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum OcrWarnungArt {
+    /// Ungewöhnlich hoher Anteil an Sonderzeichen
+    Sonderzeichen { anteil: f32 },
+    /// Pflichttext ist leer oder sehr kurz
+    KurzerPflichttext { laenge: usize },
+    /// Dem Eintrag zugeordnetes hOCR-Wort mit niedriger Konfidenz
+    NiedrigeKonfidenz { wort: String, confidence: f32 },
+}
+
+impl Grundbuch {
+    /// Meldet verdächtige Textfelder (viele Sonderzeichen, sehr kurze Pflichttexte und,
+    /// falls ein hOCR-Layout übergeben wurde, Worte mit niedriger Konfidenz an der
+    /// `position_in_pdf` des Eintrags)
+    pub fn ocr_warnungen(&self, opts: OcrWarnOptions) -> Vec<OcrWarnung> {
+        let mut warnungen = Vec::new();
+
+        for (pfad, eintrag) in self.alle_eintraege_mit_pfad() {
+            for feld in eintrag.text_felder() {
+                let text = match eintrag.text_feld(*feld) {
+                    Some(s) => s.text(),
+                    None => continue,
+                };
+                let anteil = sonderzeichen_anteil(&text);
+                if anteil > opts.max_sonderzeichen_anteil {
+                    warnungen.push(OcrWarnung {
+                        pfad,
+                        feld: Some(*feld),
+                        art: OcrWarnungArt::Sonderzeichen { anteil },
+                    });
+                }
+            }
+
+            if let Some(feld) = eintrag.pflichtfeld() {
+                let laenge = eintrag
+                    .text_feld(feld)
+                    .map(|s| s.text().trim().chars().count())
+                    .unwrap_or(0);
+                if laenge < opts.min_pflichttext_laenge {
+                    warnungen.push(OcrWarnung {
+                        pfad,
+                        feld: Some(feld),
+                        art: OcrWarnungArt::KurzerPflichttext { laenge },
+                    });
+                }
+            }
+
+            let (hocr, pos) = match (opts.hocr, eintrag.position_in_pdf()) {
+                (Some(h), Some(p)) => (h, p),
+                _ => continue,
+            };
+            let seite = match hocr.seiten.get(&pos.seite) {
+                Some(s) => s,
+                None => continue,
+            };
+            for wort in seite.worte_in_bereich(&pos.rect) {
+                if wort.confidence < opts.min_confidence {
+                    warnungen.push(OcrWarnung {
+                        pfad,
+                        feld: None,
+                        art: OcrWarnungArt::NiedrigeKonfidenz {
+                            wort: wort.text.clone(),
+                            confidence: wort.confidence,
+                        },
+                    });
+                }
+            }
+        }
+
+        warnungen
+    }
+}
+
+/// Anteil der Zeichen (ohne Leerzeichen), die weder alphanumerisch noch übliche Satzzeichen sind
+fn sonderzeichen_anteil(text: &str) -> f32 {
+    let zeichen = text.chars().filter(|c| !c.is_whitespace());
+    let (mut gesamt, mut sonder) = (0, 0);
+    for c in zeichen {
+        gesamt += 1;
+        if !c.is_alphanumeric() && !".,;:-/()§%&'\"".contains(c) {
+            sonder += 1;
+        }
+    }
+    if gesamt == 0 {
+        0.0
+    } else {
+        sonder as f32 / gesamt as f32
+    }
+}
+
+/// Mindest-Ähnlichkeit zwischen Eintragstext und hOCR-Text, unterhalb der
+/// `Grundbuch::verifiziere_gegen_hocr` eine Abweichung meldet
+pub const MIN_HOCR_AEHNLICHKEIT: f32 = 0.8;
+
+/// Einstellungen für `Grundbuch::verifiziere_gegen_hocr_mit`
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct VerifikationsOptions {
+    /// Einträge mit einer Ähnlichkeit darunter werden gemeldet (0.0 - 1.0),
+    /// Standard ist `MIN_HOCR_AEHNLICHKEIT`
+    pub min_aehnlichkeit: f32,
+}
+
+impl Default for VerifikationsOptions {
+    fn default() -> Self {
+        VerifikationsOptions {
+            min_aehnlichkeit: MIN_HOCR_AEHNLICHKEIT,
+        }
+    }
+}
+
+/// Abweichung zwischen dem Text eines Eintrags und den hOCR-Worten an seiner Position
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AbweichungsBericht {
+    pub pfad: EintragPfad,
+    pub seite: String,
+    /// Bereinigter Text des Eintrags
+    pub eintrag_text: String,
+    /// Bereinigter, aus den hOCR-Worten zusammengesetzter Text
+    pub ocr_text: String,
+    /// Normalisierte Ähnlichkeit (0.0 - 1.0)
+    pub aehnlichkeit: f32,
+}
+
+impl<'a> GrundbuchEintragRef<'a> {
+    /// Haupttext des Eintrags (Pflichtfeld bzw. Bezeichnung bei Flurstücken)
+    pub fn haupttext(&self) -> Option<&'a StringOrLines> {
+        match self.pflichtfeld() {
+            Some(feld) => self.text_feld(feld),
+            None => self.text_feld(TextFeld::Bezeichnung),
+        }
+    }
+}
+
+impl Grundbuch {
+    /// Vergleicht für jeden Eintrag mit `position_in_pdf` den Eintragstext mit den an dieser
+    /// Position liegenden hOCR-Worten und meldet Einträge mit einer Ähnlichkeit unter
+    /// `MIN_HOCR_AEHNLICHKEIT`
+    pub fn verifiziere_gegen_hocr(&self, hocr: &HocrLayout) -> Vec<AbweichungsBericht> {
+        self.verifiziere_gegen_hocr_mit(hocr, VerifikationsOptions::default())
+    }
+
+    /// Wie `verifiziere_gegen_hocr`, mit eigener Mindest-Ähnlichkeit
+    pub fn verifiziere_gegen_hocr_mit(
+        &self,
+        hocr: &HocrLayout,
+        optionen: VerifikationsOptions,
+    ) -> Vec<AbweichungsBericht> {
+        let mut berichte = Vec::new();
+
+        for (pfad, eintrag) in self.alle_eintraege_mit_pfad() {
+            let pos = match eintrag.position_in_pdf() {
+                Some(p) => p,
+                None => continue,
+            };
+            let seite = match hocr.seiten.get(&pos.seite) {
+                Some(s) => s,
+                None => continue,
+            };
+            let eintrag_text = eintrag
+                .haupttext()
+                .map(|t| normalisiere_leerzeichen(&t.text_clean()))
+                .unwrap_or_default();
+            let ocr_text = seite
+                .worte_in_bereich(&pos.rect)
+                .iter()
+                .map(|w| w.text.as_str())
+                .collect::<Vec<_>>()
+                .join(" ");
+            let ocr_text =
+                normalisiere_leerzeichen(&StringOrLines::SingleLine(ocr_text).text_clean());
+
+            let aehnlichkeit = aehnlichkeit(&eintrag_text, &ocr_text);
+            if aehnlichkeit < optionen.min_aehnlichkeit {
+                berichte.push(AbweichungsBericht {
+                    pfad,
+                    seite: pos.seite.clone(),
+                    eintrag_text,
+                    ocr_text,
+                    aehnlichkeit,
+                });
+            }
+        }
+
+        berichte
+    }
+}
+
+/// Normalisierte Ähnlichkeit zweier Texte über die Levenshtein-Distanz
+/// (1.0 = identisch, 0.0 = völlig verschieden)
+pub fn aehnlichkeit(a: &str, b: &str) -> f32 {
+    if a == b {
+        return 1.0;
+    }
+    let a = a.chars().collect::<Vec<_>>();
+    let b = b.chars().collect::<Vec<_>>();
+    let max = a.len().max(b.len());
+    if max == 0 {
+        return 1.0;
+    }
+    1.0 - levenshtein(&a, &b) as f32 / max as f32
+}
+
+fn levenshtein(a: &[char], b: &[char]) -> usize {
+    let mut vorher = (0..=b.len()).collect::<Vec<_>>();
+    let mut aktuell = vec![0; b.len() + 1];
+    for (i, ca) in a.iter().enumerate() {
+        aktuell[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let kosten = if ca == cb { 0 } else { 1 };
+            aktuell[j + 1] = (vorher[j] + kosten)
+                .min(vorher[j + 1] + 1)
+                .min(aktuell[j] + 1);
+        }
+        std::mem::swap(&mut vorher, &mut aktuell);
+    }
+    vorher[b.len()]
+}
+
+/// Fasst alle Leerzeichen / Zeilenumbrüche zu einzelnen Leerzeichen zusammen
+fn normalisiere_leerzeichen(s: &str) -> String {
+    s.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Anzahl geröteter Einträge in einer Liste / Abteilung
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RoetungZaehler {
+    /// Anzahl aller Einträge
+    pub gesamt: usize,
+    /// Anzahl geröteter Einträge (manuell oder automatisch)
+    pub geroetet: usize,
+    /// Davon manuell als gerötet markiert
+    pub manuell_geroetet: usize,
+    /// Davon nur automatisch als gerötet erkannt (ohne manuelle Angabe)
+    pub nur_automatisch_geroetet: usize,
+}
+
+impl RoetungZaehler {
+    fn zaehle(&mut self, e: &GrundbuchEintragRef<'_>) {
+        self.gesamt += 1;
+        match e.roetungsstatus() {
+            Roetungsstatus::ManuellGeroetet => {
+                self.geroetet += 1;
+                self.manuell_geroetet += 1;
+            }
+            Roetungsstatus::AutomatischGeroetet => {
+                self.geroetet += 1;
+                self.nur_automatisch_geroetet += 1;
+            }
+            Roetungsstatus::Aktiv | Roetungsstatus::ManuellEntroetet => {}
+        }
+    }
+}
+
+/// Bearbeitungsfortschritt der Rötung eines Grundbuchblatts
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RoetungStatistik {
+    /// Summe über alle Einträge
+    pub gesamt: RoetungZaehler,
+    /// Summe pro Abteilung
+    pub abteilungen: BTreeMap<Abteilungsbereich, RoetungZaehler>,
+    /// Aufschlüsselung nach Einträgen / Veränderungen / Löschungen
+    pub listen: BTreeMap<EintragsListe, RoetungZaehler>,
+}
+
+impl Grundbuch {
+    /// Zählt gerötete und gesamte Einträge pro Abteilung und Liste
+    pub fn roetung_statistik(&self) -> RoetungStatistik {
+        let mut statistik = RoetungStatistik::default();
+        for e in self.alle_eintraege() {
+            let liste = e.liste();
+            statistik.gesamt.zaehle(&e);
+            statistik
+                .abteilungen
+                .entry(liste.abteilung())
+                .or_default()
+                .zaehle(&e);
+            statistik.listen.entry(liste).or_default().zaehle(&e);
+        }
+        statistik
+    }
+}
+
+/// Bruch, z.B. Miteigentumsanteil "1/2"
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Bruch {
+    pub zaehler: u64,
+    pub nenner: u64,
+}
+
+impl Bruch {
+    /// Gekürzter Bruch, `None` bei Nenner 0
+    pub fn gekuerzt(&self) -> Option<Bruch> {
+        if self.nenner == 0 {
+            return None;
+        }
+        let teiler = ggt(self.zaehler, self.nenner);
+        Some(Bruch {
+            zaehler: self.zaehler / teiler,
+            nenner: self.nenner / teiler,
+        })
+    }
+
+    /// Summe zweier Brüche (gekürzt), `None` bei Nenner 0 oder Überlauf
+    pub fn checked_add(&self, other: &Bruch) -> Option<Bruch> {
+        let a = self.gekuerzt()?;
+        let b = other.gekuerzt()?;
+        let nenner = a.nenner.checked_mul(b.nenner / ggt(a.nenner, b.nenner))?;
+        let zaehler = a
+            .zaehler
+            .checked_mul(nenner / a.nenner)?
+            .checked_add(b.zaehler.checked_mul(nenner / b.nenner)?)?;
+        Bruch { zaehler, nenner }.gekuerzt()
+    }
+}
+
+impl std::fmt::Display for Bruch {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}/{}", self.zaehler, self.nenner)
+    }
+}
+
+fn ggt(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a.max(1)
+    } else {
+        ggt(b, a % b)
+    }
+}
+
+/// Einzelner Eigentümer aus dem Eigentümer-Feld eines `Abt1Eintrag`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Eigentuemer {
+    pub name: String,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub anteil: Option<Bruch>,
+}
+
+impl Abt1Eintrag {
+    /// Zerlegt das Eigentümer-Feld heuristisch in einzelne Personen mit Anteilen
+    /// ("zu 1/2 Max Muster, zu 1/2 Erika Muster" oder "Max Muster zu 1/2" bzw. eine Person
+    /// pro Zeile, wenn keine Anteile angegeben sind)
+    pub fn eigentuemer_liste(&self) -> Vec<Eigentuemer> {
+        parse_eigentuemer(&self.get_eigentuemer().lines().join("\n"))
+    }
+
+    /// Prüft, ob sich die angegebenen Anteile zu 1 summieren. Sind gar keine Anteile angegeben,
+    /// gilt die Aufteilung als vollständig, fehlt nur bei einzelnen Eigentümern der Anteil, nicht.
+    pub fn anteile_vollstaendig(&self) -> bool {
+        let liste = self.eigentuemer_liste();
+        if liste.iter().all(|e| e.anteil.is_none()) {
+            return true;
+        }
+        let mut summe = Bruch {
+            zaehler: 0,
+            nenner: 1,
+        };
+        for e in liste.iter() {
+            let anteil = match e.anteil {
+                Some(s) => s,
+                None => return false,
+            };
+            summe = match summe.checked_add(&anteil) {
+                Some(s) => s,
+                None => return false,
+            };
+        }
+        summe.zaehler == summe.nenner
+    }
+}
+
+/// Aktueller Eigentümerstand eines nicht geröteten Eintrags in Abteilung 1,
+/// siehe `Abteilung1::aktuelle_eigentuemer`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct EigentuemerStand {
+    pub lfd_nr: usize,
+    /// Personen mit Anteilen, siehe `Abt1Eintrag::eigentuemer_liste`
+    pub eigentuemer: Vec<Eigentuemer>,
+    /// Betroffene Grundstücke im Bestandsverzeichnis (leer bei `Abt1EintragV2`)
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub bv_nr: Vec<usize>,
+}
+
+impl Abteilung1 {
+    /// Eigentümer aller nicht geröteten Einträge, nach lfd. Nr. sortiert
+    pub fn aktuelle_eigentuemer(&self) -> Vec<EigentuemerStand> {
+        let mut stand = self
+            .eintraege
+            .iter()
+            .filter(|e| !e.ist_geroetet())
+            .map(|e| EigentuemerStand {
+                lfd_nr: e.get_lfd_nr(),
+                eigentuemer: e.eigentuemer_liste(),
+                bv_nr: match e {
+                    Abt1Eintrag::V1(v1) => v1.bv_nr.nummern(),
+                    Abt1Eintrag::V2(_) => Vec::new(),
+                },
+            })
+            .collect::<Vec<_>>();
+        stand.sort_by_key(|s| s.lfd_nr);
+        stand
+    }
+}
+
+fn parse_eigentuemer(text: &str) -> Vec<Eigentuemer> {
+    let bereinige = |s: &str| -> String {
+        let s = REGEX_EIGENTUEMER_AUFZAEHLUNG.replace_all(s, " ");
+        let s = s.split_whitespace().collect::<Vec<_>>().join(" ");
+        let trenner = |c: char| matches!(c, ',' | ';' | '-' | '–') || c.is_whitespace();
+        let s = s.trim_matches(trenner);
+        let s = s.strip_prefix("und ").unwrap_or(s);
+        let s = s.strip_suffix(" und").unwrap_or(s);
+        s.trim_matches(trenner).to_string()
+    };
+
+    let anteile = REGEX_EIGENTUEMER_ANTEIL
+        .captures_iter(text)
+        .filter_map(|cap| {
+            let m = cap.get(0)?;
+            let bruch = Bruch {
+                zaehler: cap.get(1)?.as_str().parse().ok()?,
+                nenner: cap.get(2)?.as_str().parse().ok()?,
+            };
+            Some((m.start(), m.end(), bruch))
+        })
+        .collect::<Vec<_>>();
+
+    let mut liste = Vec::new();
+    if anteile.is_empty() {
+        for zeile in text.lines() {
+            let name = bereinige(zeile);
+            if !name.is_empty() {
+                liste.push(Eigentuemer { name, anteil: None });
+            }
+        }
+        return liste;
+    }
+
+    // Steht der erste Anteil vor dem ersten Namen ("zu 1/2 Max Muster"),
+    // gehört der Name hinter den Anteil, sonst davor ("Max Muster zu 1/2")
+    let anteil_vorne = bereinige(&text[..anteile[0].0]).is_empty();
+    if anteil_vorne {
+        for (i, (_, ende, bruch)) in anteile.iter().enumerate() {
+            let bis = anteile.get(i + 1).map(|a| a.0).unwrap_or(text.len());
+            let name = bereinige(&text[*ende..bis]);
+            if !name.is_empty() {
+                liste.push(Eigentuemer {
+                    name,
+                    anteil: Some(*bruch),
+                });
+            }
+        }
+    } else {
+        let mut von = 0;
+        for (start, ende, bruch) in anteile.iter() {
+            let name = bereinige(&text[von..*start]);
+            if !name.is_empty() {
+                liste.push(Eigentuemer {
+                    name,
+                    anteil: Some(*bruch),
+                });
+            }
+            von = *ende;
+        }
+        let rest = bereinige(&text[von..]);
+        if !rest.is_empty() {
+            liste.push(Eigentuemer {
+                name: rest,
+                anteil: None,
+            });
+        }
+    }
+
+    liste
+}
+
+lazy_static::lazy_static! {
+    static ref REGEX_EIGENTUEMER_ANTEIL: regex::Regex = {
+        regex::Regex::new("(?i)\\b(?:zu\\s+)?(?:je\\s+)?(\\d+)\\s*/\\s*(\\d+)(?:\\s*anteil)?").unwrap()
+    };
+    static ref REGEX_EIGENTUEMER_AUFZAEHLUNG: regex::Regex = {
+        regex::Regex::new("(?m)^\\s*(?:[a-z]|\\d+)[\\).]\\s+").unwrap()
+    };
+}
+
+/// Flache Darstellung eines Eintrags mit einheitlichen Spalten über alle Abteilungen,
+/// z.B. für den Export in einen DataFrame
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GrundbuchRecord {
+    pub abteilung: Abteilungsbereich,
+    pub eintragstyp: EintragsListe,
+    /// Laufende Nummer, bei Veränderungen / Löschungen der Text der Spalte ("1, 2")
+    pub lfd_nr: String,
+    pub text: String,
+    pub betrag: Option<String>,
+    pub flur: Option<usize>,
+    pub flurstueck: Option<String>,
+    pub groesse_m2: Option<u64>,
+    pub geroetet: bool,
+    pub seite: Option<String>,
+}
+
+impl Grundbuch {
+    /// Alle Einträge als flache Records in Grundbuch-Reihenfolge
+    pub fn to_records(&self) -> Vec<GrundbuchRecord> {
+        self.alle_eintraege()
+            .map(|e| {
+                let liste = e.liste();
+                let (flur, flurstueck, groesse_m2) = match e {
+                    GrundbuchEintragRef::Bv(BvEintrag::Flurstueck(f)) => (
+                        Some(f.flur),
+                        Some(f.flurstueck.clone()),
+                        if f.groesse.ist_leer() {
+                            None
+                        } else {
+                            Some(f.groesse.get_m2())
+                        },
+                    ),
+                    _ => (None, None, None),
+                };
+                GrundbuchRecord {
+                    abteilung: liste.abteilung(),
+                    eintragstyp: liste,
+                    lfd_nr: e.lfd_nr_string(),
+                    text: e.haupttext().map(|t| t.text_clean()).unwrap_or_default(),
+                    betrag: e.text_feld(TextFeld::Betrag).map(|t| t.text()),
+                    flur,
+                    flurstueck,
+                    groesse_m2,
+                    geroetet: e.roetungsstatus().ist_geroetet(),
+                    seite: e.position_in_pdf().map(|p| p.seite.clone()),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Inhalt eines Grundbuchs als Schlüssel für `HashMap`s / Caches: `Eq` und `Hash` berücksichtigen
+/// Titelblatt, Nummern und Textfelder aller Einträge, aber weder Rötung noch Koordinaten
+/// (`position_in_pdf`). Einzeilige und mehrzeilige Texte mit gleichen Zeilen gelten als gleich.
+#[derive(Debug, Copy, Clone)]
+pub struct GrundbuchInhalt<'a>(pub &'a Grundbuch);
+
+#[derive(PartialEq, Eq, Hash)]
+struct EintragInhalt<'a> {
+    liste: EintragsListe,
+    lfd_nr: Option<usize>,
+    bisherige_lfd_nr: Option<usize>,
+    version: Option<usize>,
+    flurstueck: Option<(usize, &'a str, Option<&'a str>, &'a FlurstueckGroesse)>,
+    texte: Vec<Option<Vec<String>>>,
+    automatisch_geroetet: Option<bool>,
+    manuell_geroetet: Option<bool>,
+}
+
+impl EintragInhalt<'_> {
+    fn ohne_roetung(self) -> Self {
+        EintragInhalt {
+            automatisch_geroetet: None,
+            manuell_geroetet: None,
+            ..self
+        }
+    }
+}
+
+impl<'a> GrundbuchInhalt<'a> {
+    fn eintraege(&self) -> impl Iterator<Item = EintragInhalt<'a>> {
+        self.0
+            .alle_eintraege()
+            .map(|e| EintragInhalt::aus(e).ohne_roetung())
+    }
+}
+
+impl<'a> EintragInhalt<'a> {
+    fn aus(e: GrundbuchEintragRef<'a>) -> Self {
+        let (lfd_nr, bisherige_lfd_nr, version, flurstueck) = match e {
+            GrundbuchEintragRef::Bv(BvEintrag::Flurstueck(f)) => (
+                Some(f.lfd_nr),
+                f.bisherige_lfd_nr,
+                None,
+                Some((
+                    f.flur,
+                    f.flurstueck.as_str(),
+                    f.gemarkung.as_deref(),
+                    &f.groesse,
+                )),
+            ),
+            GrundbuchEintragRef::Bv(BvEintrag::Recht(r)) => {
+                (Some(r.lfd_nr), r.bisherige_lfd_nr, None, None)
+            }
+            GrundbuchEintragRef::Abt1(Abt1Eintrag::V1(a)) => (Some(a.lfd_nr), None, None, None),
+            GrundbuchEintragRef::Abt1(Abt1Eintrag::V2(a)) => {
+                (Some(a.lfd_nr), None, Some(a.version), None)
+            }
+            GrundbuchEintragRef::Abt2(a) => (Some(a.lfd_nr), None, None, None),
+            GrundbuchEintragRef::Abt3(a) => (Some(a.lfd_nr), None, None, None),
+            _ => (None, None, None, None),
+        };
+        EintragInhalt {
+            liste: e.liste(),
+            lfd_nr,
+            bisherige_lfd_nr,
+            version,
+            flurstueck,
+            texte: e
+                .text_felder()
+                .iter()
+                .map(|f| e.text_feld(*f).map(|t| t.lines()))
+                .collect(),
+            automatisch_geroetet: e.automatisch_geroetet(),
+            manuell_geroetet: e.manuell_geroetet(),
+        }
+    }
+}
+
+impl PartialEq for GrundbuchInhalt<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.titelblatt == other.0.titelblatt && self.eintraege().eq(other.eintraege())
+    }
+}
+
+impl Eq for GrundbuchInhalt<'_> {}
+
+impl std::hash::Hash for GrundbuchInhalt<'_> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.0.titelblatt.hash(state);
+        for e in self.eintraege() {
+            e.hash(state);
+        }
+    }
+}
+
+impl Grundbuch {
+    /// Vergleicht nur den fachlichen Inhalt zweier Grundbücher: Titelblatt, Nummern und
+    /// Textfelder. Ignoriert werden `position_in_pdf`, `automatisch_geroetet`,
+    /// `manuell_geroetet` sowie die Darstellung der Texte (`SingleLine` / `MultiLine`),
+    /// gleichbedeutend mit dem Vergleich über `GrundbuchInhalt`.
+    pub fn inhaltlich_gleich(&self, other: &Grundbuch) -> bool {
+        GrundbuchInhalt(self) == GrundbuchInhalt(other)
+    }
+}
+
+impl GrundbuchEintragRef<'_> {
+    /// Prüfsumme über den fachlichen Inhalt des Eintrags (Nummern und Textfelder wie bei
+    /// `GrundbuchInhalt`), z.B. als ETag für einzelne Einträge. Mit `mit_metadaten` fließen
+    /// zusätzlich Rötung und `position_in_pdf` ein. Der Wert ist nur innerhalb derselben
+    /// Programmversion stabil und sollte nicht dauerhaft gespeichert werden.
+    pub fn eintrag_hash(&self, mit_metadaten: bool) -> u64 {
+        use std::hash::{Hash, Hasher};
+
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        let inhalt = EintragInhalt::aus(*self);
+        if mit_metadaten {
+            inhalt.hash(&mut hasher);
+            self.position_in_pdf()
+                .map(|p| {
+                    let r = &p.rect;
+                    (
+                        p.seite.as_str(),
+                        [r.min_x, r.min_y, r.max_x, r.max_y].map(f32::to_bits),
+                    )
+                })
+                .hash(&mut hasher);
+        } else {
+            inhalt.ohne_roetung().hash(&mut hasher);
+        }
+        hasher.finish()
+    }
+}
+
+impl Grundbuch {
+    /// Prüfsummen aller Einträge (siehe `GrundbuchEintragRef::eintrag_hash`), Schlüssel ist
+    /// "<liste>/<lfd_nr>" (z.B. "abt3_eintraege/2", siehe `EintragsListe::name`). Ist die
+    /// laufende Nummer leer oder innerhalb der Liste mehrfach vergeben, wird der Index in der
+    /// Liste angehängt ("abt2_veraenderungen/1@3").
+    pub fn eintrags_hashes(&self, mit_metadaten: bool) -> BTreeMap<String, u64> {
+        let mut hashes = BTreeMap::new();
+        for (pfad, eintrag) in self.alle_eintraege_mit_pfad() {
+            let lfd_nr = eintrag.lfd_nr_string();
+            let mut schluessel = format!("{}/{}", pfad.liste.name(), lfd_nr.trim());
+            if lfd_nr.trim().is_empty() || hashes.contains_key(&schluessel) {
+                schluessel = format!("{}@{}", schluessel, pfad.index);
+            }
+            hashes.insert(schluessel, eintrag.eintrag_hash(mit_metadaten));
+        }
+        hashes
+    }
+}
+
+impl EintragsListe {
+    /// Name der Liste wie in der Serialisierung ("bv_eintraege", "abt3_loeschungen", ...)
+    pub fn name(&self) -> &'static str {
+        use self::EintragsListe::*;
+        match self {
+            BvEintraege => "bv_eintraege",
+            BvZuschreibungen => "bv_zuschreibungen",
+            BvAbschreibungen => "bv_abschreibungen",
+            Abt1Eintraege => "abt1_eintraege",
+            Abt1GrundlagenEintragungen => "abt1_grundlagen_eintragungen",
+            Abt1Veraenderungen => "abt1_veraenderungen",
+            Abt1Loeschungen => "abt1_loeschungen",
+            Abt2Eintraege => "abt2_eintraege",
+            Abt2Veraenderungen => "abt2_veraenderungen",
+            Abt2Loeschungen => "abt2_loeschungen",
+            Abt3Eintraege => "abt3_eintraege",
+            Abt3Veraenderungen => "abt3_veraenderungen",
+            Abt3Loeschungen => "abt3_loeschungen",
+        }
+    }
+}
+
+impl Abteilungsbereich {
+    /// Überschrift der Abteilung ("Bestandsverzeichnis", "Abteilung I", ...)
+    pub fn titel(&self) -> &'static str {
+        match self {
+            Abteilungsbereich::Bestandsverzeichnis => "Bestandsverzeichnis",
+            Abteilungsbereich::Abt1 => "Abteilung I",
+            Abteilungsbereich::Abt2 => "Abteilung II",
+            Abteilungsbereich::Abt3 => "Abteilung III",
+        }
+    }
+}
+
+impl EintragsListe {
+    /// Überschrift der Liste innerhalb der Abteilung, `None` für die Haupteinträge
+    pub fn titel(&self) -> Option<&'static str> {
+        use self::EintragsListe::*;
+        match self {
+            BvEintraege | Abt1Eintraege | Abt2Eintraege | Abt3Eintraege => None,
+            BvZuschreibungen => Some("Zuschreibungen"),
+            BvAbschreibungen => Some("Abschreibungen"),
+            Abt1GrundlagenEintragungen => Some("Grundlagen der Eintragungen"),
+            Abt1Veraenderungen | Abt2Veraenderungen | Abt3Veraenderungen => Some("Veränderungen"),
+            Abt1Loeschungen | Abt2Loeschungen | Abt3Loeschungen => Some("Löschungen"),
+        }
+    }
+}
+
+impl Grundbuch {
+    /// Linearisierte Textfassung des Grundbuchs (z.B. für Screenreader oder Textsuche):
+    /// Überschriften pro Abteilung / Liste, dann je Eintrag "Nr. X: ...", gerötete Einträge mit
+    /// dem Präfix "[gerötet] ", Folgezeilen mehrzeiliger Felder eingerückt
+    pub fn to_plaintext(&self) -> String {
+        let tb = &self.titelblatt;
+        let mut out = format!(
+            "Grundbuch von {}, Blatt {} (Amtsgericht {})\n",
+            tb.grundbuch_von, tb.blatt, tb.amtsgericht
+        );
+
+        let mut letzte_liste = None;
+        for e in self.alle_eintraege() {
+            let liste = e.liste();
+            if letzte_liste.map(|l: EintragsListe| l.abteilung()) != Some(liste.abteilung()) {
+                out.push_str(&format!("\n{}\n", liste.abteilung().titel()));
+            }
+            if letzte_liste != Some(liste) {
+                if let Some(titel) = liste.titel() {
+                    out.push_str(&format!("\n{titel}\n"));
+                }
+            }
+            letzte_liste = Some(liste);
+
+            let mut zeilen = Vec::new();
+            let mut kopf = Vec::new();
+            if let GrundbuchEintragRef::Bv(BvEintrag::Flurstueck(f)) = e {
+                kopf.push(format!("Flur {}, Flurstück {}", f.flur, f.flurstueck));
+                if let Some(g) = f.gemarkung.as_ref() {
+                    kopf.push(format!("Gemarkung {g}"));
+                }
+                if !f.groesse.ist_leer() {
+                    kopf.push(f.groesse.anzeige(GroesseFormat::Kompakt));
+                }
+            }
+            for feld in e.text_felder() {
+                let feld_zeilen = match e.text_feld(*feld) {
+                    Some(t) => t
+                        .lines()
+                        .iter()
+                        .map(|z| normalisiere_leerzeichen(z))
+                        .filter(|z| !z.is_empty())
+                        .collect::<Vec<_>>(),
+                    None => continue,
+                };
+                if feld_zeilen.is_empty() {
+                    continue;
+                }
+                let text = feld_zeilen.join(" ");
+                match feld {
+                    TextFeld::LfdNr => {}
+                    TextFeld::BvNr => kopf.push(format!("BV-Nr. {text}")),
+                    TextFeld::ZuNr => kopf.push(format!("zu Nr. {text}")),
+                    TextFeld::Betrag => kopf.push(text),
+                    TextFeld::Bezeichnung
+                    | TextFeld::Eigentuemer
+                    | TextFeld::GrundlageDerEintragung
+                    | TextFeld::Text => zeilen.extend(feld_zeilen),
+                }
+            }
+            if !kopf.is_empty() {
+                zeilen.insert(0, kopf.join(", "));
+            }
+
+            let praefix = format!(
+                "{}Nr. {}: ",
+                if e.roetungsstatus().ist_geroetet() {
+                    "[gerötet] "
+                } else {
+                    ""
+                },
+                e.lfd_nr_string()
+            );
+            let einrueckung = " ".repeat(praefix.chars().count());
+            out.push_str(&praefix);
+            if zeilen.is_empty() {
+                out.push('\n');
+            }
+            for (i, z) in zeilen.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(&einrueckung);
+                }
+                out.push_str(z);
+                out.push('\n');
+            }
+        }
+
+        out
+    }
+}
+
+/// Fehler beim Verschieben eines Eintrags zwischen Abteilungen
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum VerschiebeFehler {
+    /// Quell- und Zielabteilung sind identisch
+    GleicheAbteilung(Abteilungsbereich),
+    /// In der Quellabteilung existiert kein Eintrag mit dieser lfd. Nr.
+    EintragNichtGefunden {
+        abteilung: Abteilungsbereich,
+        lfd_nr: LaufendeNummer,
+    },
+    /// In der Zielabteilung existiert bereits ein Eintrag mit dieser lfd. Nr.
+    LfdNrBelegt {
+        abteilung: Abteilungsbereich,
+        lfd_nr: LaufendeNummer,
+    },
+    /// Flurstücke können nicht in eine Abteilung verschoben werden
+    FlurstueckNichtVerschiebbar { lfd_nr: LaufendeNummer },
+    /// Die Zielabteilung kennt keine lfd. Nr. mit Buchstabenzusatz (nur Abt. 2)
+    ZusatzNichtUebertragbar {
+        abteilung: Abteilungsbereich,
+        lfd_nr: LaufendeNummer,
+    },
+}
+
+impl std::fmt::Display for VerschiebeFehler {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            VerschiebeFehler::GleicheAbteilung(a) => {
+                write!(f, "Eintrag ist bereits in {}", a.titel())
+            }
+            VerschiebeFehler::EintragNichtGefunden { abteilung, lfd_nr } => {
+                write!(f, "Kein Eintrag Nr. {lfd_nr} in {}", abteilung.titel())
+            }
+            VerschiebeFehler::LfdNrBelegt { abteilung, lfd_nr } => {
+                write!(
+                    f,
+                    "Nr. {lfd_nr} ist in {} bereits belegt",
+                    abteilung.titel()
+                )
+            }
+            VerschiebeFehler::FlurstueckNichtVerschiebbar { lfd_nr } => {
+                write!(f, "Flurstück Nr. {lfd_nr} kann nicht verschoben werden")
+            }
+            VerschiebeFehler::ZusatzNichtUebertragbar { abteilung, lfd_nr } => {
+                write!(
+                    f,
+                    "Nr. {lfd_nr} kann wegen des Zusatzes nicht nach {} verschoben werden",
+                    abteilung.titel()
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for VerschiebeFehler {}
+
+/// Feld, das beim Verschieben verworfen wurde, weil der Zieltyp es nicht besitzt
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct VerschiebeHinweis {
+    pub feld: TextFeld,
+    pub text: String,
+}
+
+impl Grundbuch {
+    /// Verschiebt den Eintrag mit `lfd_nr` aus der Abteilung `von` nach `nach` (z.B. bei
+    /// Fehlklassifikation als Abt. 2 statt Abt. 3), wobei die Felder auf den Zieltyp gemappt
+    /// werden (`text` ↔ `eigentuemer`, fehlender `betrag` bleibt leer). Gibt die Felder zurück,
+    /// die im Zieltyp nicht existieren und daher verworfen wurden. Die lfd. Nr. wird
+    /// einschließlich Zusatz verglichen ("1a" ist nicht Nr. 1), ein Zusatz bleibt nur in
+    /// Abt. 2 erhalten.
+    pub fn verschiebe_eintrag(
+        &mut self,
+        von: Abteilungsbereich,
+        lfd_nr: impl Into<LaufendeNummer>,
+        nach: Abteilungsbereich,
+    ) -> Result<Vec<VerschiebeHinweis>, VerschiebeFehler> {
+        let lfd_nr = lfd_nr.into();
+        if von == nach {
+            return Err(VerschiebeFehler::GleicheAbteilung(von));
+        }
+        if lfd_nr.zusatz.is_some() && nach != Abteilungsbereich::Abt2 {
+            return Err(VerschiebeFehler::ZusatzNichtUebertragbar {
+                abteilung: nach,
+                lfd_nr,
+            });
+        }
+
+        let liste_von = haupt_liste(von);
+        let liste_nach = haupt_liste(nach);
+        let laufende_nummer =
+            |gb: &Grundbuch, pfad: &EintragPfad| gb.eintrag(pfad).and_then(|e| e.laufende_nummer());
+        let lfd_nr_in = |gb: &Grundbuch, liste: EintragsListe, nr: &LaufendeNummer| {
+            (0..gb.anzahl(liste)).find(|i| {
+                laufende_nummer(gb, &EintragPfad { liste, index: *i }).as_ref() == Some(nr)
+            })
+        };
+
+        let index = lfd_nr_in(self, liste_von, &lfd_nr).ok_or_else(|| {
+            VerschiebeFehler::EintragNichtGefunden {
+                abteilung: von,
+                lfd_nr: lfd_nr.clone(),
+            }
+        })?;
+        if lfd_nr_in(self, liste_nach, &lfd_nr).is_some() {
+            return Err(VerschiebeFehler::LfdNrBelegt {
+                abteilung: nach,
+                lfd_nr,
+            });
+        }
+
+        let pfad = EintragPfad {
+            liste: liste_von,
+            index,
+        };
+        let quelle = self
+            .eintrag(&pfad)
+            .ok_or_else(|| VerschiebeFehler::EintragNichtGefunden {
+                abteilung: von,
+                lfd_nr: lfd_nr.clone(),
+            })?;
+        if let GrundbuchEintragRef::Bv(BvEintrag::Flurstueck(_)) = quelle {
+            return Err(VerschiebeFehler::FlurstueckNichtVerschiebbar { lfd_nr });
+        }
+
+        let mut felder = quelle
+            .text_felder()
+            .iter()
+            .filter_map(|f| Some((*f, quelle.text_feld(*f)?.clone())))
+            .filter(|(_, t)| !t.is_empty())
+            .collect::<BTreeMap<_, _>>();
+        let automatisch_geroetet = quelle.automatisch_geroetet();
+        let manuell_geroetet = quelle.manuell_geroetet();
+        let position_in_pdf = quelle.position_in_pdf().cloned();
+
+        let neu = match nach {
+            Abteilungsbereich::Bestandsverzeichnis => {
+                GrundbuchEintrag::Bv(BvEintrag::Recht(BvEintragRecht {
+                    lfd_nr: lfd_nr.nummer,
+                    zu_nr: nimm_feld(&mut felder, TextFeld::ZuNr, TextFeld::ZuNr),
+                    bisherige_lfd_nr: None,
+                    text: nimm_feld(&mut felder, TextFeld::Text, TextFeld::Eigentuemer),
+                    automatisch_geroetet,
+                    manuell_geroetet,
+                    position_in_pdf,
+                }))
+            }
+            Abteilungsbereich::Abt1 => GrundbuchEintrag::Abt1(Abt1Eintrag::V1(Abt1EintragV1 {
+                lfd_nr: lfd_nr.nummer,
+                eigentuemer: nimm_feld(&mut felder, TextFeld::Eigentuemer, TextFeld::Text),
+                bv_nr: nimm_feld(&mut felder, TextFeld::BvNr, TextFeld::BvNr),
+                grundlage_der_eintragung: nimm_feld(
+                    &mut felder,
+                    TextFeld::GrundlageDerEintragung,
+                    TextFeld::GrundlageDerEintragung,
+                ),
+                automatisch_geroetet,
+                manuell_geroetet,
+                position_in_pdf,
+            })),
+            Abteilungsbereich::Abt2 => GrundbuchEintrag::Abt2(Abt2Eintrag {
+                lfd_nr: lfd_nr.nummer,
+                lfd_nr_zusatz: lfd_nr.zusatz.clone(),
+                bv_nr: nimm_feld(&mut felder, TextFeld::BvNr, TextFeld::BvNr),
+                text: nimm_feld(&mut felder, TextFeld::Text, TextFeld::Eigentuemer),
+                automatisch_geroetet,
+                manuell_geroetet,
+                position_in_pdf,
+            }),
+            Abteilungsbereich::Abt3 => GrundbuchEintrag::Abt3(Abt3Eintrag {
+                lfd_nr: lfd_nr.nummer,
+                bv_nr: nimm_feld(&mut felder, TextFeld::BvNr, TextFeld::BvNr),
+                betrag: nimm_feld(&mut felder, TextFeld::Betrag, TextFeld::Betrag),
+                text: nimm_feld(&mut felder, TextFeld::Text, TextFeld::Eigentuemer),
+                automatisch_geroetet,
+                manuell_geroetet,
+                position_in_pdf,
+            }),
+        };
+
+        let hinweise = felder
+            .into_iter()
+            .map(|(feld, t)| VerschiebeHinweis {
+                feld,
+                text: t.text(),
+            })
+            .collect();
+
+        self.remove_eintrag_at(&pfad);
+        let ziel_index = (0..self.anzahl(liste_nach))
+            .find(|i| {
+                let pfad = EintragPfad {
+                    liste: liste_nach,
+                    index: *i,
+                };
+                laufende_nummer(self, &pfad).is_some_and(|nr| nr > lfd_nr)
+            })
+            .unwrap_or(self.anzahl(liste_nach));
+        // Index ist immer gültig, da er höchstens der Listenlänge entspricht
+        let _ = self.insert_eintrag_at(ziel_index, neu);
+
+        Ok(hinweise)
+    }
+}
+
+/// Entfernt `feld` aus `felder`, ist es leer oder nicht vorhanden stattdessen `ersatz`
+fn nimm_feld(
+    felder: &mut BTreeMap<TextFeld, StringOrLines>,
+    feld: TextFeld,
+    ersatz: TextFeld,
+) -> StringOrLines {
+    match felder.remove(&feld) {
+        Some(t) if !t.is_empty() => t,
+        _ => felder.remove(&ersatz).unwrap_or_default(),
+    }
+}
+
+/// Liste der Haupteinträge einer Abteilung
+fn haupt_liste(abteilung: Abteilungsbereich) -> EintragsListe {
+    match abteilung {
+        Abteilungsbereich::Bestandsverzeichnis => EintragsListe::BvEintraege,
+        Abteilungsbereich::Abt1 => EintragsListe::Abt1Eintraege,
+        Abteilungsbereich::Abt2 => EintragsListe::Abt2Eintraege,
+        Abteilungsbereich::Abt3 => EintragsListe::Abt3Eintraege,
+    }
+}
+
+/// Unterschiede eines Grundbuchs zu einer Basisversion, siehe `Grundbuch::to_delta`
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GrundbuchDelta {
+    /// Änderungen, die in Reihenfolge auf die Basis angewendet werden
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub patches: Vec<GrundbuchPatch>,
+}
+
+impl GrundbuchDelta {
+    pub fn is_empty(&self) -> bool {
+        self.patches.is_empty()
+    }
+
+    /// Wendet das Delta auf eine Kopie der Basis an, schlägt fehl, wenn das Delta nicht
+    /// gegenüber dieser Basis erzeugt wurde
+    pub fn apply_to(&self, basis: &Grundbuch) -> Result<Grundbuch, PatchError> {
+        let mut grundbuch = basis.clone();
+        for patch in self.patches.iter() {
+            grundbuch.apply(patch)?;
+        }
+        Ok(grundbuch)
+    }
+}
+
+impl Grundbuch {
+    /// Nur die Unterschiede gegenüber `basis`, sodass `to_delta(basis).apply_to(basis) == self`
+    pub fn to_delta(&self, basis: &Grundbuch) -> GrundbuchDelta {
+        GrundbuchDelta {
+            patches: basis.diff_patches(self),
+        }
+    }
+}
+
+impl Abteilung1 {
+    /// Paare von Indizes in `eintraege`, deren Eigentümer-Text mindestens die Ähnlichkeit
+    /// `aehnlichkeit` hat (1.0 = identisch), siehe `finde_duplikate_in`
+    pub fn finde_duplikate(&self, aehnlichkeit: f32) -> Vec<(usize, usize)> {
+        let texte = self
+            .eintraege
+            .iter()
+            .map(|e| e.get_eigentuemer().text_clean())
+            .collect::<Vec<_>>();
+        finde_duplikate_in(&texte, aehnlichkeit)
+    }
+}
+
+impl Abteilung2 {
+    /// Paare von Indizes in `eintraege`, deren Text mindestens die Ähnlichkeit `aehnlichkeit`
+    /// hat (1.0 = identisch), z.B. um versehentliche Doppelerfassungen zu finden
+    pub fn finde_duplikate(&self, aehnlichkeit: f32) -> Vec<(usize, usize)> {
+        let texte = self
+            .eintraege
+            .iter()
+            .map(|e| e.text.text_clean())
+            .collect::<Vec<_>>();
+        finde_duplikate_in(&texte, aehnlichkeit)
+    }
+}
+
+impl Abteilung3 {
+    /// Paare von Indizes in `eintraege`, deren Text mindestens die Ähnlichkeit `aehnlichkeit`
+    /// hat (1.0 = identisch), siehe `finde_duplikate_in`
+    pub fn finde_duplikate(&self, aehnlichkeit: f32) -> Vec<(usize, usize)> {
+        let texte = self
+            .eintraege
+            .iter()
+            .map(|e| e.text.text_clean())
+            .collect::<Vec<_>>();
+        finde_duplikate_in(&texte, aehnlichkeit)
+    }
+}
+
+/// Paare (i, j) mit i < j, deren Texte (mit normalisierten Leerzeichen) mindestens die
+/// Ähnlichkeit `schwelle` haben, leere Texte werden ignoriert. Bei `schwelle >= 1.0` wird nur
+/// auf Gleichheit geprüft.
+fn finde_duplikate_in(texte: &[String], schwelle: f32) -> Vec<(usize, usize)> {
+    let texte = texte
+        .iter()
+        .map(|t| normalisiere_leerzeichen(t))
+        .collect::<Vec<_>>();
+    let mut paare = Vec::new();
+
+    if schwelle >= 1.0 {
+        let mut gruppen: BTreeMap<&str, Vec<usize>> = BTreeMap::new();
+        for (i, t) in texte.iter().enumerate() {
+            if !t.is_empty() {
+                gruppen.entry(t.as_str()).or_default().push(i);
+            }
+        }
+        for indizes in gruppen.values() {
+            for (n, i) in indizes.iter().enumerate() {
+                for j in indizes.iter().skip(n + 1) {
+                    paare.push((*i, *j));
+                }
+            }
+        }
+        paare.sort();
+        return paare;
+    }
+
+    let laengen = texte.iter().map(|t| t.chars().count()).collect::<Vec<_>>();
+    for i in 0..texte.len() {
+        if texte[i].is_empty() {
+            continue;
+        }
+        for j in (i + 1)..texte.len() {
+            if texte[j].is_empty() {
+                continue;
+            }
+            // Die Levenshtein-Distanz ist mindestens die Längendifferenz
+            let (kurz, lang) = (laengen[i].min(laengen[j]), laengen[i].max(laengen[j]));
+            if (kurz as f32 / lang as f32) < schwelle {
+                continue;
+            }
+            if aehnlichkeit(&texte[i], &texte[j]) >= schwelle {
+                paare.push((i, j));
+            }
+        }
+    }
+    paare
+}
+
+/// Einheitliche CSV-Ausgabe für Einträge einer Abteilung
+pub trait CsvExport {
+    /// Spaltennamen
+    fn csv_header() -> Vec<&'static str>;
+    /// Werte in der Reihenfolge von `csv_header`
+    fn csv_row(&self) -> Vec<String>;
+}
+
+impl CsvExport for Abt1EintragV1 {
+    fn csv_header() -> Vec<&'static str> {
+        vec![
+            "lfd_nr",
+            "eigentuemer",
+            "bv_nr",
+            "grundlage_der_eintragung",
+            "geroetet",
+        ]
+    }
+
+    fn csv_row(&self) -> Vec<String> {
+        vec![
+            self.lfd_nr.to_string(),
+            self.eigentuemer.text(),
+            self.bv_nr.text(),
+            self.grundlage_der_eintragung.text(),
+            self.ist_geroetet().to_string(),
+        ]
+    }
+}
+
+impl CsvExport for Abt1EintragV2 {
+    fn csv_header() -> Vec<&'static str> {
+        vec!["lfd_nr", "eigentuemer", "geroetet"]
+    }
+
+    fn csv_row(&self) -> Vec<String> {
+        vec![
+            self.lfd_nr.to_string(),
+            self.eigentuemer.text(),
+            self.ist_geroetet().to_string(),
+        ]
+    }
+}
+
+impl CsvExport for Abt1Eintrag {
+    /// Spalten von `Abt1EintragV1`, bei V2-Einträgen bleiben `bv_nr` und
+    /// `grundlage_der_eintragung` leer
+    fn csv_header() -> Vec<&'static str> {
+        Abt1EintragV1::csv_header()
+    }
+
+    fn csv_row(&self) -> Vec<String> {
+        match self {
+            Abt1Eintrag::V1(v1) => v1.csv_row(),
+            Abt1Eintrag::V2(v2) => vec![
+                v2.lfd_nr.to_string(),
+                v2.eigentuemer.text(),
+                String::new(),
+                String::new(),
+                v2.ist_geroetet().to_string(),
+            ],
+        }
+    }
+}
+
+impl CsvExport for Abt2Eintrag {
+    fn csv_header() -> Vec<&'static str> {
+        vec!["lfd_nr", "bv_nr", "text", "geroetet"]
+    }
+
+    fn csv_row(&self) -> Vec<String> {
+        vec![
+            self.lfd_nr_mit_zusatz(),
+            self.bv_nr.text(),
+            self.text.text(),
+            self.ist_geroetet().to_string(),
+        ]
+    }
+}
+
+impl CsvExport for Abt3Eintrag {
+    fn csv_header() -> Vec<&'static str> {
+        vec!["lfd_nr", "bv_nr", "betrag", "text", "geroetet"]
+    }
+
+    fn csv_row(&self) -> Vec<String> {
+        vec![
+            self.lfd_nr.to_string(),
+            self.bv_nr.text(),
+            self.betrag.text(),
+            self.text.text(),
+            self.ist_geroetet().to_string(),
+        ]
+    }
+}
+
+impl Abteilung1 {
+    /// Einträge als CSV (RFC 4180), siehe `CsvExport`
+    pub fn to_csv(&self) -> String {
+        csv_tabelle(&self.eintraege)
+    }
+}
+
+impl Abteilung2 {
+    /// Einträge als CSV (RFC 4180), siehe `CsvExport`
+    pub fn to_csv(&self) -> String {
+        csv_tabelle(&self.eintraege)
+    }
+}
+
+impl Abteilung3 {
+    /// Einträge als CSV (RFC 4180), siehe `CsvExport`
+    pub fn to_csv(&self) -> String {
+        csv_tabelle(&self.eintraege)
+    }
+}
+
+/// CSV-Tabelle mit Kopfzeile, Zeilen mit CRLF getrennt
+pub fn csv_tabelle<T: CsvExport>(eintraege: &[T]) -> String {
+    let mut out = csv_zeile(T::csv_header().iter().map(|s| s.to_string()));
+    for e in eintraege {
+        out.push_str(&csv_zeile(e.csv_row().into_iter()));
+    }
+    out
+}
+
+fn csv_zeile(werte: impl Iterator<Item = String>) -> String {
+    let mut zeile = werte
+        .map(|w| {
+            if w.contains([',', '"', '\r', '\n']) {
+                format!("\"{}\"", w.replace('"', "\"\""))
+            } else {
+                w
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    zeile.push_str("\r\n");
+    zeile
+}
+
+/// Art einer Eintragungsgrundlage in Abteilung 1
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum GrundlageArt {
+    Auflassung,
+    Erbschein,
+    Testament,
+    Erbvertrag,
+    Zuschlag,
+    Umlegung,
+    Flurbereinigung,
+    Sonstige,
+}
+
+/// Strukturierte Eintragungsgrundlage, z.B. "Auflassung vom 12.03.1990"
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Eintragungsgrundlage {
+    pub art: GrundlageArt,
+    /// Erstes im Text genanntes gültiges Datum (siehe `parse_deutsches_datum`), wie im Text
+    /// geschrieben
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub datum: Option<String>,
+    /// Originaltext der Grundlage
+    pub text: String,
+}
+
+impl Abt1EintragV1 {
+    /// Zerlegt `grundlage_der_eintragung` zeilenweise in einzelne Grundlagen
+    /// und erkennt deren Art und Datum heuristisch
+    pub fn grundlagen(&self) -> Vec<Eintragungsgrundlage> {
+        self.grundlage_der_eintragung
+            .lines()
+            .iter()
+            .map(|z| normalisiere_leerzeichen(z))
+            .filter(|t| !t.is_empty())
+            .map(|text| {
+                let art = REGEX_GRUNDLAGE_ARTEN
+                    .iter()
+                    .find(|(_, r)| r.is_match(&text))
+                    .map(|(a, _)| *a)
+                    .unwrap_or(GrundlageArt::Sonstige);
+                let datum = numerische_daten(&text)
+                    .find(|(_, d)| d.ist_plausibel())
+                    .map(|(m, _)| m.as_str().split_whitespace().collect::<String>());
+                Eintragungsgrundlage { art, datum, text }
+            })
+            .collect()
+    }
+}
+
+lazy_static::lazy_static! {
+    static ref REGEX_GRUNDLAGE_ARTEN: Vec<(GrundlageArt, regex::Regex)> = {
+        [
+            (GrundlageArt::Auflassung, "(?i)auflassung"),
+            (GrundlageArt::Erbschein, "(?i)erbschein"),
+            (GrundlageArt::Erbvertrag, "(?i)erbvertrag"),
+            (GrundlageArt::Testament, "(?i)testament|verfügung von todes wegen"),
+            (GrundlageArt::Zuschlag, "(?i)zuschlag"),
+            (GrundlageArt::Umlegung, "(?i)umlegung"),
+            (GrundlageArt::Flurbereinigung, "(?i)flurbereinigung"),
+        ]
+        .into_iter()
+        .map(|(a, r)| (a, regex::Regex::new(r).unwrap()))
+        .collect()
+    };
+    static ref REGEX_DATUM: regex::Regex = {
+        regex::Regex::new("\\b(\\d{1,2})\\.\\s?(\\d{1,2})\\.\\s?(\\d{4}|\\d{2})\\b").unwrap()
+    };
+}
+
+/// Strukturierter Inhalt eines Abt2-Eintrags, siehe `Abt2Eintrag::parse_inhalt`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Abt2Inhalt {
+    /// Art des Rechts, wie im Text geschrieben ("Wegerecht", "Nießbrauch", ...)
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rechtsart: Option<String>,
+    /// Berechtigter ("für ..." im Text)
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub berechtigter: Option<String>,
+    /// Nicht zugeordneter Resttext
+    pub rest: String,
+}
+
+impl Abt2Eintrag {
+    /// Trennt Rechtsart (Wegerecht, Nießbrauch, Wohnungsrecht, Vorkaufsrecht,
+    /// Grunddienstbarkeit, ...) und Berechtigten ("für den jeweiligen Eigentümer von ...")
+    /// vom übrigen Text
+    pub fn parse_inhalt(&self) -> Abt2Inhalt {
+        let text = normalisiere_leerzeichen(&self.text.lines().join(" "));
+
+        let rechtsart = REGEX_RECHTSART.find(&text).map(|m| (m.range(), m.as_str()));
+        let berechtigter = REGEX_BERECHTIGTER.captures(&text).and_then(|c| {
+            let gruppe = c.get(1)?;
+            Some((c.get(0)?.start()..gruppe.end(), gruppe.as_str()))
+        });
+
+        let mut entfernen = rechtsart
+            .iter()
+            .map(|(r, _)| r.clone())
+            .chain(berechtigter.iter().map(|(r, _)| r.clone()))
+            .collect::<Vec<_>>();
+        entfernen.sort_by_key(|r| std::cmp::Reverse(r.start));
+
+        let mut rest = text.clone();
+        let mut bis = rest.len();
+        for r in entfernen {
+            // überlappende Bereiche nur einmal entfernen
+            let ende = r.end.min(bis);
+            if r.start < ende {
+                rest.replace_range(r.start..ende, " ");
+            }
+            bis = r.start;
+        }
+        let rest = normalisiere_leerzeichen(&rest)
+            .trim_matches(|c: char| c == ',' || c == ';' || c.is_whitespace())
+            .to_string();
+
+        Abt2Inhalt {
+            rechtsart: rechtsart.map(|(_, s)| s.to_string()),
+            berechtigter: berechtigter
+                .map(|(_, s)| s.trim_end_matches([',', ';', '.']).trim().to_string())
+                .filter(|s| !s.is_empty()),
+            rest,
+        }
+    }
+}
+
+lazy_static::lazy_static! {
+    static ref REGEX_RECHTSART: regex::Regex = {
+        regex::Regex::new(
+            "(?i)\\b(?:\\w*wegerecht|nießbrauch(?:srecht)?|niessbrauch(?:srecht)?|wohnungsrecht|\
+             vorkaufsrecht|grunddienstbarkeit|beschränkte persönliche dienstbarkeit|\
+             \\w*leitungsrecht|reallast|auflassungsvormerkung|vormerkung)\\b",
+        )
+        .unwrap()
+    };
+    static ref REGEX_BERECHTIGTER: regex::Regex = {
+        regex::Regex::new(
+            "(?i)\\bfür\\s+(.+?)\\s*(?:;|,?\\s+(?:gemäß|unter bezugnahme|eingetragen|mit rang)\\b|$)",
+        )
+        .unwrap()
+    };
+}
+
+/// JSON Schema des .gbx-Formats (`PdfFile`) für die Eingabe: beschreibt alle Formate, die
+/// auch die Deserialisierung akzeptiert (Zahlstrings, camelCase-Aliase, Rect als Array, ...).
+/// Die `untagged`-Enums `StringOrLines`, `BvEintrag` und `Abt1Eintrag` werden als `oneOf`
+/// abgebildet, siehe deren `JsonSchema`-Implementierungen.
+#[cfg(feature = "schema")]
+pub fn pdf_file_json_schema() -> serde_json::Value {
+    let schema = schemars::gen::SchemaSettings::draft07()
+        .with_visitor(SchemaAliase)
+        .into_generator()
+        .into_root_schema_for::<PdfFile>();
+    serde_json::to_value(schema).unwrap_or_default()
+}
+
+/// Ergänzt das Schema um die Aliase der Felder (`#[serde(alias)]` kennt `schemars` nicht):
+/// jedes Feld `a_b` ist auch als `aB` zulässig, dazu die alten Feldnamen aus
+/// `schema_feld_aliase`. Pflichtfelder sind unter einem ihrer Namen anzugeben.
+#[cfg(feature = "schema")]
+#[derive(Debug, Clone)]
+struct SchemaAliase;
+
+#[cfg(feature = "schema")]
+fn schema_feld_aliase(feld: &str) -> Vec<String> {
+    let mut aliase = Vec::new();
+    if feld.contains('_') {
+        let mut teile = feld.split('_');
+        let mut camel = teile.next().unwrap_or_default().to_string();
+        for teil in teile {
+            let mut zeichen = teil.chars();
+            if let Some(erstes) = zeichen.next() {
+                camel.extend(erstes.to_uppercase());
+                camel.push_str(zeichen.as_str());
+            }
+        }
+        aliase.push(camel);
+    }
+    match feld {
+        "eigentuemer" => aliase.push("eigentümer".to_string()),
+        "grundbuch_von" => aliase.push("grundbuch".to_string()),
+        _ => {}
+    }
+    aliase
+}
+
+#[cfg(feature = "schema")]
+impl schemars::visit::Visitor for SchemaAliase {
+    fn visit_schema_object(&mut self, schema: &mut schemars::schema::SchemaObject) {
+        let mut pflichtfelder = Vec::new();
+
+        if let Some(objekt) = schema.object.as_mut() {
+            let felder = objekt
+                .properties
+                .iter()
+                .map(|(k, v)| (k.clone(), v.clone()))
+                .collect::<Vec<_>>();
+            for (feld, feld_schema) in felder {
+                for alias in schema_feld_aliase(&feld) {
+                    objekt
+                        .properties
+                        .entry(alias)
+                        .or_insert_with(|| feld_schema.clone());
+                }
+            }
+
+            let mit_alias = objekt
+                .required
+                .iter()
+                .filter(|feld| {
+                    objekt.properties.contains_key(*feld) && !schema_feld_aliase(feld).is_empty()
+                })
+                .cloned()
+                .collect::<Vec<_>>();
+            for feld in mit_alias {
+                objekt.required.remove(&feld);
+                pflichtfelder.push(schema_pflichtfeld(&feld));
+            }
+        }
+
+        if !pflichtfelder.is_empty() {
+            schema
+                .subschemas()
+                .all_of
+                .get_or_insert_with(Vec::new)
+                .extend(pflichtfelder);
+        }
+
+        schemars::visit::visit_schema_object(self, schema)
+    }
+}
+
+/// Objekt, das das Feld `feld` unter seinem Namen oder einem seiner Aliase enthält
+#[cfg(feature = "schema")]
+fn schema_pflichtfeld(feld: &str) -> schemars::schema::Schema {
+    let mut varianten = std::iter::once(feld.to_string())
+        .chain(schema_feld_aliase(feld))
+        .map(|name| {
+            let mut s = schemars::schema::SchemaObject::default();
+            s.object().required.insert(name);
+            s.into()
+        })
+        .collect::<Vec<_>>();
+    if varianten.len() == 1 {
+        varianten.remove(0)
+    } else {
+        schema_any_of(varianten)
+    }
+}
+
+#[cfg(feature = "schema")]
+fn schema_any_of(varianten: Vec<schemars::schema::Schema>) -> schemars::schema::Schema {
+    let mut s = schemars::schema::SchemaObject::default();
+    s.subschemas().any_of = Some(varianten);
+    s.into()
+}
+
+#[cfg(feature = "schema")]
+fn schema_one_of(varianten: Vec<schemars::schema::Schema>) -> schemars::schema::Schema {
+    let mut s = schemars::schema::SchemaObject::default();
+    s.subschemas().one_of = Some(varianten);
+    s.into()
+}
+
+#[cfg(feature = "schema")]
+fn schema_typ(typ: schemars::schema::InstanceType) -> schemars::schema::SchemaObject {
+    schemars::schema::SchemaObject {
+        instance_type: Some(typ.into()),
+        ..Default::default()
+    }
+}
+
+/// Nicht-negative Ganzzahl ab `minimum`
+#[cfg(feature = "schema")]
+fn schema_ganzzahl(minimum: f64) -> schemars::schema::Schema {
+    let mut s = schema_typ(schemars::schema::InstanceType::Integer);
+    s.number().minimum = Some(minimum);
+    s.into()
+}
+
+/// String, der auf den regulären Ausdruck `muster` passt
+#[cfg(feature = "schema")]
+fn schema_string_muster(muster: &str) -> schemars::schema::Schema {
+    let mut s = schema_typ(schemars::schema::InstanceType::String);
+    s.string().pattern = Some(muster.to_string());
+    s.into()
+}
+
+#[cfg(feature = "schema")]
+fn schema_null() -> schemars::schema::Schema {
+    schema_typ(schemars::schema::InstanceType::Null).into()
+}
+
+/// Objekt, das keines der `felder` enthält
+#[cfg(feature = "schema")]
+fn schema_ohne_felder(felder: &[&str]) -> schemars::schema::Schema {
+    let varianten = felder.iter().map(|feld| schema_pflichtfeld(feld)).collect();
+    let mut s = schemars::schema::SchemaObject::default();
+    s.subschemas().not = Some(Box::new(schema_any_of(varianten)));
+    s.into()
+}
+
+/// Variante eines `oneOf`: Schema von `T`, eingeschränkt durch `einschraenkungen`
+#[cfg(feature = "schema")]
+fn schema_variante<T: schemars::JsonSchema>(
+    gen: &mut schemars::gen::SchemaGenerator,
+    einschraenkungen: Vec<schemars::schema::Schema>,
+) -> schemars::schema::Schema {
+    let mut s = schemars::schema::SchemaObject::default();
+    s.subschemas().all_of = Some(
+        std::iter::once(gen.subschema_for::<T>())
+            .chain(einschraenkungen)
+            .collect(),
+    );
+    s.into()
+}
+
+/// Objekt, dessen Feld `feld` (falls vorhanden) auf `feld_schema` passt
+#[cfg(feature = "schema")]
+fn schema_feld(feld: &str, feld_schema: schemars::schema::Schema) -> schemars::schema::Schema {
+    let mut s = schemars::schema::SchemaObject::default();
+    s.object().properties.insert(feld.to_string(), feld_schema);
+    s.into()
+}
+
+/// Schema für `usize_from_str_or_num`: Zahl oder Zahlstring
+#[cfg(feature = "schema")]
+fn schema_usize_from_str_or_num(
+    _: &mut schemars::gen::SchemaGenerator,
+) -> schemars::schema::Schema {
+    schema_any_of(vec![
+        schema_ganzzahl(0.0),
+        schema_string_muster("^\\s*\\d+\\s*$"),
+    ])
+}
+
+/// Schema für `option_usize_from_str_or_num`: Zahl, Zahlstring, `""` oder `null`
+#[cfg(feature = "schema")]
+fn schema_option_usize_from_str_or_num(
+    _: &mut schemars::gen::SchemaGenerator,
+) -> schemars::schema::Schema {
+    schema_any_of(vec![
+        schema_ganzzahl(0.0),
+        schema_string_muster("^\\s*\\d*\\s*$"),
+        schema_null(),
+    ])
+}
+
+/// Schema für `groesse_from_tagged_or_num`: getaggte `FlurstueckGroesse` oder Zahl in m²
+#[cfg(feature = "schema")]
+fn schema_groesse_from_tagged_or_num(
+    gen: &mut schemars::gen::SchemaGenerator,
+) -> schemars::schema::Schema {
+    schema_any_of(vec![
+        gen.subschema_for::<FlurstueckGroesse>(),
+        schema_ganzzahl(0.0),
+    ])
+}
+
+/// Schema der lfd. Nr. eines `Abt2Eintrag`: Zahl, Zahlstring oder Nummer mit Zusatz ("1a")
+#[cfg(feature = "schema")]
+fn schema_lfd_nr_mit_zusatz(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+    schema_any_of(vec![
+        schema_ganzzahl(0.0),
+        schema_string_muster("^\\s*\\d+(\\s*[A-Za-z][A-Za-z0-9]*)?\\s*$"),
+        schema_null(),
+    ])
+}
+
+/// Zeichenkette oder Liste von Zeilen
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for StringOrLines {
+    fn schema_name() -> String {
+        "StringOrLines".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        let mut s = schema_one_of(vec![
+            gen.subschema_for::<String>(),
+            gen.subschema_for::<Vec<String>>(),
+        ]);
+        if let schemars::schema::Schema::Object(o) = &mut s {
+            o.metadata().description = Some(
+                "Text als Zeichenkette oder als Liste von Zeilen (ohne Zeilenende-Zeichen)"
+                    .to_string(),
+            );
+        }
+        s
+    }
+}
+
+/// Wie beim Deserialisieren: `flur` / `flurstueck` kennzeichnen ein Flurstück, `zu_nr` /
+/// `text` ein Recht, Einträge mit Feldern beider Varianten sind ungültig
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for BvEintrag {
+    fn schema_name() -> String {
+        "BvEintrag".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        schema_one_of(vec![
+            schema_variante::<BvEintragFlurstueck>(
+                gen,
+                vec![schema_ohne_felder(&["zu_nr", "text"])],
+            ),
+            schema_variante::<BvEintragRecht>(
+                gen,
+                vec![schema_ohne_felder(&[
+                    "flur",
+                    "flurstueck",
+                    "gemarkung",
+                    "bezeichnung",
+                    "groesse",
+                ])],
+            ),
+        ])
+    }
+}
+
+/// Wie beim Deserialisieren: ohne `version` (oder mit `version: 1`) `Abt1EintragV1`, ab
+/// `version: 2` `Abt1EintragV2` (dann ohne `bv_nr` / `grundlage_der_eintragung`)
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for Abt1Eintrag {
+    fn schema_name() -> String {
+        "Abt1Eintrag".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        let version_1 = schemars::schema::SchemaObject {
+            enum_values: Some(vec![serde_json::json!(1)]),
+            ..Default::default()
+        };
+        let v1 = schema_variante::<Abt1EintragV1>(
+            gen,
+            vec![schema_feld(
+                "version",
+                schema_any_of(vec![
+                    version_1.into(),
+                    schema_string_muster("^\\s*(0*1)?\\s*$"),
+                    schema_null(),
+                ]),
+            )],
+        );
+        let v2 = schema_variante::<Abt1EintragV2>(
+            gen,
+            vec![
+                schema_feld(
+                    "version",
+                    schema_any_of(vec![
+                        schema_ganzzahl(2.0),
+                        schema_string_muster("^\\s*0*([2-9]|[1-9]\\d+)\\s*$"),
+                    ]),
+                ),
+                schema_ohne_felder(&["bv_nr", "grundlage_der_eintragung"]),
+            ],
+        );
+        let mut s = schema_one_of(vec![v1, v2]);
+        if !cfg!(feature = "skip-zero-lfd-nr") {
+            if let schemars::schema::Schema::Object(o) = &mut s {
+                o.subschemas().all_of = Some(vec![schema_pflichtfeld("lfd_nr")]);
+            }
+        }
+        s
+    }
+}
+
+/// Einfügeposition für `lfd_nr` in einer nach lfd. Nr. sortierten Liste (Binärsuche). Gibt es
+/// die lfd. Nr. bereits, liegt die Position direkt hinter dem letzten Eintrag mit dieser Nummer.
+fn einfuege_position<T, K: Ord>(eintraege: &[T], lfd_nr: &K, nr: impl Fn(&T) -> K) -> usize {
+    eintraege.partition_point(|e| nr(e) <= *lfd_nr)
+}
+
+impl Bestandsverzeichnis {
+    /// Fügt den Eintrag nach `lfd_nr` sortiert ein (siehe `einfuege_position`), setzt voraus,
+    /// dass `eintraege` bereits nach lfd. Nr. sortiert ist
+    pub fn insert_eintrag(&mut self, eintrag: BvEintrag) {
+        let pos = einfuege_position(&self.eintraege, &eintrag.get_lfd_nr(), |e| e.get_lfd_nr());
+        self.eintraege.insert(pos, eintrag);
+    }
+}
+
+impl Abteilung1 {
+    /// Fügt den Eintrag nach `lfd_nr` sortiert ein, bei bereits vorhandener lfd. Nr. direkt
+    /// hinter den vorhandenen Einträgen
+    pub fn insert_eintrag(&mut self, eintrag: Abt1Eintrag) {
+        let pos = einfuege_position(&self.eintraege, &eintrag.get_lfd_nr(), |e| e.get_lfd_nr());
+        self.eintraege.insert(pos, eintrag);
+    }
+}
+
+impl Abteilung2 {
+    /// Fügt den Eintrag nach der vollständigen lfd. Nr. sortiert ein ("1" < "1a" < "2"), bei
+    /// bereits vorhandener lfd. Nr. direkt hinter den vorhandenen Einträgen
+    pub fn insert_eintrag(&mut self, eintrag: Abt2Eintrag) {
+        let pos = einfuege_position(&self.eintraege, &eintrag.laufende_nummer(), |e| {
+            e.laufende_nummer()
+        });
+        self.eintraege.insert(pos, eintrag);
+    }
+}
+
+impl Abteilung3 {
+    /// Fügt den Eintrag nach `lfd_nr` sortiert ein, bei bereits vorhandener lfd. Nr. direkt
+    /// hinter den vorhandenen Einträgen
+    pub fn insert_eintrag(&mut self, eintrag: Abt3Eintrag) {
+        let pos = einfuege_position(&self.eintraege, &eintrag.lfd_nr, |e| e.lfd_nr);
+        self.eintraege.insert(pos, eintrag);
+    }
+}
+
+impl GrundbuchEintragRef<'_> {
+    /// Ob alle Textfelder des Eintrags leer sind (bei Flurstücken zusätzlich Flurstück und Größe)
+    pub fn ist_leer(&self) -> bool {
+        let texte_leer = self.text_felder().iter().all(|f| {
+            self.text_feld(*f)
+                .is_none_or(|t| t.text().trim().is_empty())
+        });
+        match self {
+            GrundbuchEintragRef::Bv(BvEintrag::Flurstueck(f)) => {
+                texte_leer && f.flurstueck.trim().is_empty() && f.groesse.ist_leer()
+            }
+            _ => texte_leer,
+        }
+    }
+}
+
+impl Grundbuch {
+    /// Bringt das Grundbuch in einen reproduzierbaren Zustand (stabile Diffs und Hashes):
+    /// `entferne_leere_eintraege`, `normalisiere_texte`, `sortiere_nach_lfd_nr` und
+    /// `Bestandsverzeichnis::fuelle_gemarkungen`. Mehrfaches Aufrufen ändert nichts mehr.
+    pub fn kanonisieren(&mut self) {
+        self.normalisiere_texte();
+        self.entferne_leere_eintraege();
+        self.sortiere_nach_lfd_nr();
+        self.bestandsverzeichnis
+            .fuelle_gemarkungen(&self.titelblatt);
+    }
+
+    /// Sortiert die Einträge von Bestandsverzeichnis und Abteilungen stabil nach `lfd_nr`
+    pub fn sortiere_nach_lfd_nr(&mut self) {
+        self.bestandsverzeichnis
+            .eintraege
+            .sort_by_key(|e| e.get_lfd_nr());
+        self.abt1.eintraege.sort_by_key(|e| e.get_lfd_nr());
+        self.abt2.eintraege.sort_by_key(|e| e.lfd_nr);
+        self.abt3.eintraege.sort_by_key(|e| e.lfd_nr);
+    }
+
+    /// Trimmt alle Textfelder (siehe `StringOrLines::trimmed`, innere Leerzeilen bleiben
+    /// erhalten) und wandelt sie in `MultiLine` um
+    pub fn normalisiere_texte(&mut self) {
+        for liste in EintragsListe::ALLE {
+            for index in 0..self.anzahl(liste) {
+                let pfad = EintragPfad { liste, index };
+                let felder = match self.eintrag(&pfad) {
+                    Some(e) => e
+                        .text_felder()
+                        .iter()
+                        .filter(|f| e.text_feld(**f).is_some())
+                        .collect::<Vec<_>>(),
+                    None => continue,
+                };
+                for feld in felder {
+                    let text = self.eintrag_mut(&pfad).and_then(|e| e.text_feld_mut(*feld));
+                    if let Some(text) = text {
+                        *text = text.trimmed(true).to_multiline();
+                    }
+                }
+            }
+        }
+    }
+
+    /// Entfernt alle Einträge, deren Textfelder leer sind (siehe `GrundbuchEintragRef::ist_leer`).
+    /// Haupteinträge mit gültiger lfd. Nr. bleiben auch ohne Text erhalten (z.B. Platzhalter
+    /// für noch nicht erfasste oder vollständig gerötete Eintragungen).
+    pub fn entferne_leere_eintraege(&mut self) {
+        for liste in EintragsListe::ALLE {
+            for index in (0..self.anzahl(liste)).rev() {
+                let pfad = EintragPfad { liste, index };
+                if self
+                    .eintrag(&pfad)
+                    .is_some_and(|e| e.ist_leer() && !e.hat_gueltige_lfd_nr())
+                {
+                    self.remove_eintrag_at(&pfad);
+                }
+            }
+        }
+    }
+
+    /// Einträge, deren `position_in_pdf` invertierte Rechtecke enthält (`min_x > max_x` oder
+    /// `min_y > max_y`), ohne sie zu ändern, siehe `repariere_positionen`
+    pub fn invertierte_positionen(&self) -> Vec<EintragPfad> {
+        self.alle_eintraege_mit_pfad()
+            .filter(|(_, e)| e.position_in_pdf().is_some_and(|p| p.ist_invertiert()))
+            .map(|(pfad, _)| pfad)
+            .collect()
+    }
+
+    /// Normalisiert alle invertierten Rechtecke in `position_in_pdf` und gibt die Anzahl der
+    /// reparierten Einträge zurück
+    pub fn repariere_positionen(&mut self) -> usize {
+        let mut repariert = 0;
+        for pfad in self.invertierte_positionen() {
+            if let Some(pos) = self
+                .eintrag_mut(&pfad)
+                .and_then(|e| e.position_in_pdf_mut().as_mut())
+            {
+                if pos.normalisiere_rects() {
+                    repariert += 1;
+                }
+            }
+        }
+        repariert
+    }
+
+    /// Führt Einträge zusammen, die durch einen Seitenumbruch in zwei Teile getrennt wurden:
+    /// direkt aufeinanderfolgende Einträge derselben Liste mit gleicher lfd. Nr., bei denen
+    /// der erste Teil der unterste Eintrag seiner Seite und der zweite Teil der oberste
+    /// Eintrag der folgenden Seite ist. Die Texte des zweiten Teils werden an den ersten Teil
+    /// angehängt, seine Rechtecke landen in `PositionInPdf::weitere_rects`, der zweite Teil
+    /// wird entfernt. Gibt die Anzahl der zusammengeführten Paare
+    /// zurück.
+    pub fn vereinige_seitenumbruch_eintraege(&mut self) -> usize {
+        // (oberster min_y, unterster max_y) aller Einträge je Seite
+        let mut seiten_grenzen = BTreeMap::<String, (f32, f32)>::new();
+        for eintrag in self.alle_eintraege() {
+            if let Some(pos) = eintrag.position_in_pdf().filter(|p| !p.rect.is_zero()) {
+                let rect = pos.rect.normalized();
+                let grenzen = seiten_grenzen
+                    .entry(pos.seite.trim().to_string())
+                    .or_insert((f32::MAX, f32::MIN));
+                grenzen.0 = grenzen.0.min(rect.min_y);
+                grenzen.1 = grenzen.1.max(rect.max_y);
+            }
+        }
+
+        let seitenzahl = |pos: &PositionInPdf| -> Option<usize> {
+            Some(seiten_sortierschluessel(&pos.seite).0).filter(|n| *n != usize::MAX)
+        };
+
+        let mut zusammengefuehrt = 0;
+
+        for liste in EintragsListe::ALLE {
+            for index in (1..self.anzahl(liste)).rev() {
+                let pfad_oben = EintragPfad {
+                    liste,
+                    index: index - 1,
+                };
+                let pfad_unten = EintragPfad { liste, index };
+
+                let (oben, unten) = match (self.eintrag(&pfad_oben), self.eintrag(&pfad_unten)) {
+                    (Some(a), Some(b)) => (a, b),
+                    _ => continue,
+                };
+
+                let lfd_nr = oben.lfd_nr_string();
+                if lfd_nr.trim().is_empty() || lfd_nr.trim() == "0" {
+                    continue;
+                }
+                if lfd_nr.trim() != unten.lfd_nr_string().trim() {
+                    continue;
+                }
+
+                let (pos_oben, pos_unten) = match (oben.position_in_pdf(), unten.position_in_pdf())
+                {
+                    (Some(a), Some(b)) if !a.rect.is_zero() && !b.rect.is_zero() => (a, b),
+                    _ => continue,
+                };
+
+                let benachbart = match (seitenzahl(pos_oben), seitenzahl(pos_unten)) {
+                    (Some(a), Some(b)) => a + 1 == b,
+                    _ => false,
+                };
+                if !benachbart {
+                    continue;
+                }
+
+                let endet_unten = seiten_grenzen
+                    .get(pos_oben.seite.trim())
+                    .is_some_and(|g| pos_oben.rect.normalized().max_y >= g.1);
+                let beginnt_oben = seiten_grenzen
+                    .get(pos_unten.seite.trim())
+                    .is_some_and(|g| pos_unten.rect.normalized().min_y <= g.0);
+                if !endet_unten || !beginnt_oben {
+                    continue;
+                }
+
+                let weitere_rects = pos_unten
+                    .alle_rects()
+                    .into_iter()
+                    .map(|(seite, rect)| (seite.to_string(), rect.clone()))
+                    .collect::<Vec<_>>();
+
+                let fortsetzungen = unten
+                    .text_felder()
+                    .iter()
+                    .filter_map(|feld| Some((*feld, unten.text_feld(*feld)?.clone())))
+                    .filter(|(_, text)| !text.is_empty())
+                    .collect::<Vec<_>>();
+
+                for (feld, fortsetzung) in fortsetzungen {
+                    let ziel = match self
+                        .eintrag_mut(&pfad_oben)
+                        .and_then(|e| e.text_feld_mut(feld))
+                    {
+                        Some(z) => z,
+                        None => continue,
+                    };
+                    let mut zeilen = ziel.lines();
+                    zeilen.extend(fortsetzung.lines());
+                    *ziel = StringOrLines::MultiLine(zeilen);
+                }
+
+                if let Some(pos) = self
+                    .eintrag_mut(&pfad_oben)
+                    .and_then(|e| e.position_in_pdf_mut().as_mut())
+                {
+                    pos.weitere_rects.extend(weitere_rects);
+                }
+
+                self.remove_eintrag_at(&pfad_unten);
+                zusammengefuehrt += 1;
+            }
+        }
+
+        zusammengefuehrt
+    }
+}
+
+impl Abteilung1 {
+    /// Grundlagen-Eintragungen, deren `bv_nr` mindestens eine der BV-Nummern des Eintrags mit
+    /// der lfd. Nr. `lfd_nr` nennt (leer, wenn der Eintrag nicht existiert oder als
+    /// `Abt1EintragV2` keine BV-Nummern hat)
+    pub fn grundlagen_fuer_eintrag(&self, lfd_nr: usize) -> Vec<&Abt1GrundEintragung> {
+        let bv_nummern = self
+            .eintraege
+            .iter()
+            .filter(|e| e.get_lfd_nr() == lfd_nr)
+            .flat_map(|e| match e {
+                Abt1Eintrag::V1(v1) => v1.bv_nr.nummern(),
+                Abt1Eintrag::V2(_) => Vec::new(),
+            })
+            .collect::<std::collections::BTreeSet<_>>();
+
+        if bv_nummern.is_empty() {
+            return Vec::new();
+        }
+
+        self.grundlagen_eintragungen
+            .iter()
+            .filter(|g| g.bv_nr.nummern().iter().any(|n| bv_nummern.contains(n)))
+            .collect()
+    }
+}
+
+/// Verbindet Zeilen mit Leerzeichen zu einem Fließtext, Silbentrennungen am Zeilenende
+/// ("Gun-" / "sten") werden dabei entfernt
+fn fliesstext<'a>(zeilen: impl Iterator<Item = &'a str>) -> String {
+    let mut text = String::new();
+    for zeile in zeilen.map(str::trim).filter(|z| !z.is_empty()) {
+        let getrennt = text.ends_with('-')
+            && !text.ends_with(" -")
+            && zeile.starts_with(|c: char| c.is_lowercase());
+        if getrennt {
+            text.pop();
+        } else if !text.is_empty() {
+            text.push(' ');
+        }
+        text.push_str(zeile);
+    }
+    text
+}
+
+/// Einstellungen für `Grundbuch::verorte_eintraege`
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct VerortungsOptions {
+    /// Einträge, deren bereinigter Text kürzer ist (in Zeichen), werden nicht gesucht,
+    /// da kurze Texte zu viele zufällige Treffer erzeugen
+    pub min_zeichen: usize,
+    /// Maximale Anzahl aufeinanderfolgender hOCR-Zeilen, über die sich ein Eintrag erstrecken darf
+    pub max_zeilen: usize,
+    /// Groß- / Kleinschreibung beim Vergleich ignorieren
+    pub gross_klein_ignorieren: bool,
+}
+
+impl Default for VerortungsOptions {
+    fn default() -> Self {
+        VerortungsOptions {
+            min_zeichen: 10,
+            max_zeilen: 30,
+            gross_klein_ignorieren: true,
+        }
+    }
+}
+
+impl Grundbuch {
+    /// Setzt für Einträge ohne `position_in_pdf` die Position aus dem hOCR-Layout: der
+    /// bereinigte Haupttext (`text_clean`) wird in aufeinanderfolgenden Zeilen jeder Seite
+    /// gesucht, bei genau einem Treffer wird die Seite und das umschließende Rechteck der
+    /// Zeilen (in mm) übernommen. Mehrdeutige oder fehlende Treffer bleiben unverortet.
+    /// Gibt die Anzahl der verorteten Einträge zurück.
+    pub fn verorte_eintraege(&mut self, hocr: &HocrLayout, opts: VerortungsOptions) -> usize {
+        let normalisiere = |text: &str| {
+            let text = normalisiere_leerzeichen(text);
+            if opts.gross_klein_ignorieren {
+                text.to_lowercase()
+            } else {
+                text
+            }
+        };
+
+        // Fließtext jeder Seite (wie `fliesstext`, normalisiert) einmalig aufbauen, mit dem
+        // Byte-Offset, an dem jede nicht-leere Zeile im Text beginnt
+        let seiten = hocr
+            .seiten
+            .iter()
+            .map(|(nr, seite)| {
+                let zeilen = seite.parsed.zeilen().collect::<Vec<_>>();
+                let mut text = String::new();
+                let mut zeilen_start = Vec::new();
+                let mut vorherige_roh = String::new();
+                for (i, zeile) in zeilen.iter().enumerate() {
+                    let roh = normalisiere_leerzeichen(
+                        &zeile
+                            .words
+                            .iter()
+                            .map(|w| w.text.trim())
+                            .collect::<Vec<_>>()
+                            .join(" "),
+                    );
+                    if roh.is_empty() {
+                        continue;
+                    }
+                    let getrennt = vorherige_roh.ends_with('-')
+                        && !vorherige_roh.ends_with(" -")
+                        && roh.starts_with(|c: char| c.is_lowercase());
+                    if getrennt {
+                        text.pop();
+                    } else if !text.is_empty() {
+                        text.push(' ');
+                    }
+                    zeilen_start.push((text.len(), i));
+                    text.push_str(&normalisiere(&roh));
+                    vorherige_roh = roh;
+                }
+                (nr, seite, zeilen, text, zeilen_start)
+            })
+            .collect::<Vec<_>>();
+
+        let mut gesucht = Vec::new();
+        for (pfad, eintrag) in self.alle_eintraege_mit_pfad() {
+            if eintrag.position_in_pdf().is_some() {
+                continue;
+            }
+            let text = normalisiere(
+                &eintrag
+                    .haupttext()
+                    .map(|t| fliesstext(t.lines().iter().map(|l| l.as_str())))
+                    .unwrap_or_default(),
+            );
+            if text.chars().count() >= opts.min_zeichen.max(1) {
+                gesucht.push((pfad, text));
+            }
+        }
+
+        let mut verortet = 0;
+        for (pfad, text) in gesucht {
+            let mut treffer = Vec::new();
+            for (nr, seite, zeilen, seitentext, zeilen_start) in seiten.iter() {
+                // Index der hOCR-Zeile, in der das Byte `offset` des Seitentexts liegt
+                let zeile_bei = |offset: usize| {
+                    let pos = zeilen_start.partition_point(|(start, _)| *start <= offset);
+                    zeilen_start[pos.saturating_sub(1)].1
+                };
+                // jedes (Start-, End-)Zeilenfenster nur einmal zählen
+                let mut fenster = std::collections::BTreeSet::new();
+                let mut ab = 0;
+                while let Some(pos) = seitentext[ab..].find(&text) {
+                    let anfang = ab + pos;
+                    let start = zeile_bei(anfang);
+                    let ende = zeile_bei(anfang + text.len() - 1);
+                    if ende - start < opts.max_zeilen.max(1) {
+                        fenster.insert((start, ende));
+                    }
+                    ab = anfang
+                        + seitentext[anfang..]
+                            .chars()
+                            .next()
+                            .map_or(1, char::len_utf8);
+                }
+                for (start, ende) in fenster {
+                    let rect = zeilen[start..=ende]
+                        .iter()
+                        .map(|l| l.bounds.normalized())
+                        .reduce(|a, b| a.union(&b))
+                        .unwrap_or_default();
+                    treffer.push(PositionInPdf {
+                        seite: nr.to_string(),
+                        rect: seite.px_to_mm(&rect),
+                        weitere_rects: Vec::new(),
+                    });
+                }
+            }
+            if treffer.len() != 1 {
+                continue;
+            }
+            if let Some(eintrag) = self.eintrag_mut(&pfad) {
+                *eintrag.position_in_pdf_mut() = treffer.pop();
+                verortet += 1;
+            }
+        }
+        verortet
+    }
+}
+
+lazy_static::lazy_static! {
+    static ref REGEX_UEBERSCHRIFT_BV: regex::Regex = {
+        regex::Regex::new("(?i)\\bbestandsverzeichnis\\b").unwrap()
+    };
+    static ref REGEX_UEBERSCHRIFT_ABT: regex::Regex = {
+        regex::Regex::new(
+            "(?i)\\b(?:(erste|zweite|dritte)\\s+abteilung|abt(?:eilung|\\.)\\s*(III|II|I|1|2|3)\\b)"
+        ).unwrap()
+    };
+    static ref REGEX_SPALTE_ZU_ABSCHREIBUNGEN: regex::Regex = {
+        regex::Regex::new("(?i)\\b(?:zu|ab)schreibungen\\b").unwrap()
+    };
+    static ref REGEX_SPALTE_VERAENDERUNGEN: regex::Regex = {
+        regex::Regex::new("(?i)\\bver(?:ä|ae)nderungen\\b").unwrap()
+    };
+    static ref REGEX_SPALTE_LOESCHUNGEN: regex::Regex = {
+        regex::Regex::new("(?i)\\bl(?:ö|oe)schungen\\b").unwrap()
+    };
+}
+
+/// Abteilung aus einer Überschrift ("Bestandsverzeichnis", "Erste Abteilung", "Abteilung II", ...)
+fn abteilung_aus_ueberschrift(zeile: &str) -> Option<Abteilungsbereich> {
+    if REGEX_UEBERSCHRIFT_BV.is_match(zeile) {
+        return Some(Abteilungsbereich::Bestandsverzeichnis);
+    }
+    let cap = REGEX_UEBERSCHRIFT_ABT.captures(zeile)?;
+    let nr = cap.get(1).or_else(|| cap.get(2))?.as_str().to_lowercase();
+    match nr.as_str() {
+        "erste" | "i" | "1" => Some(Abteilungsbereich::Abt1),
+        "zweite" | "ii" | "2" => Some(Abteilungsbereich::Abt2),
+        "dritte" | "iii" | "3" => Some(Abteilungsbereich::Abt3),
+        _ => None,
+    }
+}
+
+impl HocrLayout {
+    /// Schlägt für jede Seite (in Seitenreihenfolge) einen wahrscheinlichen `SeitenTyp` vor:
+    /// die erste Zeile mit einer Abteilungsüberschrift ("Bestandsverzeichnis", "Erste Abteilung",
+    /// "Abteilung II", ...) bestimmt die Abteilung, Spaltenüberschriften ("Veränderungen",
+    /// "Löschungen", "Zuschreibungen") und das Seitenformat (quer / hoch) den genauen Typ.
+    /// Seiten ohne Überschrift übernehmen den Typ der vorherigen Seite, Seiten vor der ersten
+    /// erkannten Überschrift fehlen im Ergebnis. Gedacht als Startklassifikation für
+    /// `AnpassungSeite::klassifikation_neu`.
+    pub fn erkenne_abteilungsgrenzen(&self) -> Vec<(String, SeitenTyp)> {
+        let mut seiten = self.seiten.iter().collect::<Vec<_>>();
+        seiten.sort_by_key(|(k, _)| seiten_sortierschluessel(k));
+
+        let mut ergebnis = Vec::new();
+        let mut letzter_typ = None;
+        for (nr, seite) in seiten {
+            let zeilen = seite
+                .parsed
+                .zeilen()
+                .map(|l| {
+                    l.words
+                        .iter()
+                        .map(|w| w.text.trim())
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                })
+                .collect::<Vec<_>>();
+
+            let typ = match zeilen.iter().find_map(|z| abteilung_aus_ueberschrift(z)) {
+                Some(abteilung) => {
+                    let text = zeilen.join("\n");
+                    Some(seite.seitentyp_vorschlag(abteilung, &text))
+                }
+                None => letzter_typ,
+            };
+            if let Some(typ) = typ {
+                ergebnis.push((nr.clone(), typ));
+            }
+            letzter_typ = typ;
+        }
+        ergebnis
+    }
+}
+
+impl HocrSeite {
+    /// Ob die Seite im Querformat vorliegt (aus `breite_mm` / `hoehe_mm`, ersatzweise aus den
+    /// hOCR-Bounds)
+    fn ist_querformat(&self) -> bool {
+        if self.breite_mm > 0.0 && self.hoehe_mm > 0.0 {
+            self.breite_mm > self.hoehe_mm
+        } else {
+            self.parsed.bounds.width() > self.parsed.bounds.height()
+        }
+    }
+
+    /// Genauer `SeitenTyp` für eine Seite der Abteilung anhand der Spaltenüberschriften im Text
+    fn seitentyp_vorschlag(&self, abteilung: Abteilungsbereich, text: &str) -> SeitenTyp {
+        use self::SeitenTyp::*;
+        let horz = self.ist_querformat();
+        let veraenderungen = REGEX_SPALTE_VERAENDERUNGEN.is_match(text);
+        let loeschungen = REGEX_SPALTE_LOESCHUNGEN.is_match(text);
+        match abteilung {
+            Abteilungsbereich::Bestandsverzeichnis => {
+                match (horz, REGEX_SPALTE_ZU_ABSCHREIBUNGEN.is_match(text)) {
+                    (true, true) => BestandsverzeichnisHorzZuUndAbschreibungen,
+                    (true, false) => BestandsverzeichnisHorz,
+                    (false, true) => BestandsverzeichnisVertZuUndAbschreibungen,
+                    (false, false) => BestandsverzeichnisVert,
+                }
+            }
+            Abteilungsbereich::Abt1 => {
+                if horz {
+                    Abt1Horz
+                } else {
+                    Abt1Vert
+                }
+            }
+            Abteilungsbereich::Abt2 => match (horz, veraenderungen || loeschungen) {
+                (true, true) => Abt2HorzVeraenderungen,
+                (true, false) => Abt2Horz,
+                (false, true) => Abt2VertVeraenderungen,
+                (false, false) => Abt2Vert,
+            },
+            Abteilungsbereich::Abt3 => match (horz, veraenderungen, loeschungen) {
+                (true, false, false) => Abt3Horz,
+                (true, _, _) => Abt3HorzVeraenderungenLoeschungen,
+                (false, true, true) => Abt3VertVeraenderungenLoeschungen,
+                (false, true, false) => Abt3VertVeraenderungen,
+                (false, false, true) => Abt3VertLoeschungen,
+                (false, false, false) => Abt3Vert,
+            },
+        }
+    }
+}
+
+impl Bestandsverzeichnis {
+    /// GeoJSON-`FeatureCollection` mit einem Feature je nicht gerötetem Flurstück, ohne Geometrie
+    /// (`"geometry": null`, wird extern z.B. aus ALKIS ergänzt). Properties: `gemarkung`
+    /// (siehe `BvEintragFlurstueck::effektive_gemarkung`), `flur`, `flurstueck`, `groesse_m2`
+    /// (`null` bei fehlender Größe) und `lfd_nr`.
+    pub fn to_geojson_features(&self, titel: &Titelblatt) -> serde_json::Value {
+        use serde_json::json;
+
+        let features = self
+            .eintraege
+            .iter()
+            .filter_map(|e| match e {
+                BvEintrag::Flurstueck(f) if !f.ist_geroetet() => Some(f),
+                _ => None,
+            })
+            .map(|f| {
+                let groesse_m2 = if f.groesse.ist_leer() {
+                    None
+                } else {
+                    f.groesse.checked_get_m2()
+                };
+                json!({
+                    "type": "Feature",
+                    "geometry": null,
+                    "properties": {
+                        "gemarkung": f.effektive_gemarkung(titel),
+                        "flur": f.flur,
+                        "flurstueck": f.flurstueck.trim(),
+                        "groesse_m2": groesse_m2,
+                        "lfd_nr": f.lfd_nr,
+                    },
+                })
+            })
+            .collect::<Vec<_>>();
+
+        json!({
+            "type": "FeatureCollection",
+            "features": features,
+        })
+    }
+}
+
+/// Art eines Teilflächen-Vermerks
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum TeilflaechenArt {
+    /// "Rest von Flurstück 12", "Restfläche aus 12"
+    Rest,
+    /// "Teilfläche aus 34/5", "Teil von Flurstück 34/5"
+    Teilflaeche,
+}
+
+/// Bezug eines Flurstücks auf das Flurstück, aus dem es durch Teilung hervorgegangen ist
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Teilflaeche {
+    pub art: TeilflaechenArt,
+    /// Flur des Quell-Flurstücks, falls im Vermerk angegeben
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flur: Option<usize>,
+    /// Quell-Flurstück ("12" oder "34/5")
+    pub flurstueck: String,
+}
+
+lazy_static::lazy_static! {
+    static ref REGEX_TEILFLAECHE: regex::Regex = {
+        regex::Regex::new(
+            "(?i)\\b(rest(?:fläche|flaeche)?|teilfläche|teilflaeche|teil)\\s+(?:von|aus|des)\\s+(?:(?:dem|der)\\s+)?(?:(?:flur\\s+(\\d+)\\s*,?\\s*)?(?:flurstücks?|flurstuecks?|flurst\\.|flst\\.?)\\s*)?(?:nr\\.?\\s*)?(\\d+(?:\\s*/\\s*\\d+)?)"
+        ).unwrap()
+    };
+}
+
+impl BvEintragFlurstueck {
+    /// Erkennt in `bezeichnung` Vermerke wie "Rest von Flurstück 12" oder "Teilfläche aus 34/5"
+    /// und liefert das Quell-Flurstück (erster Vermerk, `None` ohne Vermerk)
+    pub fn teilflaechen_vermerk(&self) -> Option<Teilflaeche> {
+        let text = self.bezeichnung.as_ref()?.lines().join(" ");
+        let cap = REGEX_TEILFLAECHE.captures(&text)?;
+        let art = if cap.get(1)?.as_str().to_lowercase().starts_with("rest") {
+            TeilflaechenArt::Rest
+        } else {
+            TeilflaechenArt::Teilflaeche
+        };
+        let flur = cap.get(2).and_then(|m| m.as_str().parse().ok());
+        let flurstueck = cap
+            .get(3)?
+            .as_str()
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect();
+        Some(Teilflaeche {
+            art,
+            flur,
+            flurstueck,
+        })
+    }
+}
+
+impl Abteilung2 {
+    /// Rötet automatisch alle Einträge, deren lfd. Nr. in einer nicht geröteten Löschung genannt
+    /// wird (`automatisch_geroetet = Some(true)`), Einträge mit manueller Rötungsangabe bleiben
+    /// unverändert. Eine Löschung von Nr. 1a betrifft nur Nr. 1a, nicht Nr. 1. Gibt die Anzahl
+    /// neu geröteter Einträge zurück.
+    pub fn wende_loeschungen_an(&mut self) -> usize {
+        roete_geloeschte(
+            self.loeschungen
+                .iter()
+                .map(|l| (l.lfd_nr.laufende_nummern(), l.ist_geroetet())),
+            self.eintraege.iter_mut().map(|e| {
+                (
+                    e.laufende_nummer(),
+                    &mut e.automatisch_geroetet,
+                    e.manuell_geroetet,
+                )
+            }),
+        )
+    }
+}
+
+impl Abteilung3 {
+    /// Rötet automatisch alle Einträge, deren lfd. Nr. in einer nicht geröteten Löschung genannt
+    /// wird, siehe `Abteilung2::wende_loeschungen_an`
+    pub fn wende_loeschungen_an(&mut self) -> usize {
+        roete_geloeschte(
+            self.loeschungen
+                .iter()
+                .map(|l| (l.lfd_nr.laufende_nummern(), l.ist_geroetet())),
+            self.eintraege.iter_mut().map(|e| {
+                (
+                    LaufendeNummer::from(e.lfd_nr),
+                    &mut e.automatisch_geroetet,
+                    e.manuell_geroetet,
+                )
+            }),
+        )
+    }
+}
+
+/// Setzt `automatisch_geroetet` aller Einträge ohne manuelle Rötungsangabe, deren lfd. Nr. in
+/// einer nicht geröteten Löschung genannt wird. `loeschungen` liefert die genannten Nummern und
+/// die Rötung je Löschung, `eintraege` die lfd. Nr. sowie automatische und manuelle Rötung je
+/// Eintrag. Gibt die Anzahl neu geröteter Einträge zurück.
+fn roete_geloeschte<'a>(
+    loeschungen: impl Iterator<Item = (Vec<LaufendeNummer>, bool)>,
+    eintraege: impl Iterator<Item = (LaufendeNummer, &'a mut Option<bool>, Option<bool>)>,
+) -> usize {
+    let geloescht = loeschungen
+        .filter(|(_, geroetet)| !geroetet)
+        .flat_map(|(nummern, _)| nummern)
+        .collect::<std::collections::BTreeSet<_>>();
+    let mut neu_geroetet = 0;
+    for (lfd_nr, automatisch_geroetet, manuell_geroetet) in eintraege {
+        if geloescht.contains(&lfd_nr)
+            && manuell_geroetet.is_none()
+            && *automatisch_geroetet != Some(true)
+        {
+            *automatisch_geroetet = Some(true);
+            neu_geroetet += 1;
+        }
+    }
+    neu_geroetet
+}
+
+impl HocrSeite {
+    /// Erkannter Text der Seite: Worte einer Zeile durch Leerzeichen, Zeilen durch "\n" getrennt
+    /// (in der Reihenfolge careas / paragraphs / lines des hOCR)
+    pub fn volltext(&self) -> String {
+        self.parsed
+            .zeilen()
+            .map(|l| {
+                l.words
+                    .iter()
+                    .map(|w| w.text.trim())
+                    .filter(|t| !t.is_empty())
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .filter(|z| !z.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Seite, deren Volltext sich zwischen zwei hOCR-Layouts unterscheidet
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct HocrSeitenAenderung {
+    pub seite: String,
+    /// Ähnlichkeit der Volltexte (siehe `aehnlichkeit`, 1.0 = identisch)
+    pub aehnlichkeit: f32,
+}
+
+/// Ergebnis von `HocrLayout::diff`, alle Listen in Seitenreihenfolge
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct HocrDiff {
+    /// Seiten, die nur im neuen Layout vorhanden sind
+    pub neu: Vec<String>,
+    /// Seiten, die nur im alten Layout vorhanden sind
+    pub entfernt: Vec<String>,
+    /// Seiten in beiden Layouts mit unterschiedlichem Volltext
+    pub geaendert: Vec<HocrSeitenAenderung>,
+    /// Seiten in beiden Layouts mit gleichem Volltext
+    pub unveraendert: Vec<String>,
+}
+
+impl HocrDiff {
+    /// Ob sich keine Seite geändert hat und keine Seite hinzugekommen oder entfernt worden ist
+    pub fn ist_leer(&self) -> bool {
+        self.neu.is_empty() && self.entfernt.is_empty() && self.geaendert.is_empty()
+    }
+}
+
+impl HocrLayout {
+    /// Vergleicht den Volltext (`HocrSeite::volltext`, Leerzeichen normalisiert) jeder Seite mit
+    /// dem neuen Layout `other`, z.B. nach einem erneuten Scan
+    pub fn diff(&self, other: &HocrLayout) -> HocrDiff {
+        let mut seiten = self
+            .seiten
+            .keys()
+            .chain(other.seiten.keys())
+            .collect::<std::collections::BTreeSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+        seiten.sort_by_key(|s| seiten_sortierschluessel(s));
+
+        let mut diff = HocrDiff::default();
+        for seite in seiten {
+            match (self.seiten.get(seite), other.seiten.get(seite)) {
+                (Some(alt), Some(neu)) => {
+                    let alt = normalisiere_leerzeichen(&alt.volltext());
+                    let neu = normalisiere_leerzeichen(&neu.volltext());
+                    if alt == neu {
+                        diff.unveraendert.push(seite.clone());
+                    } else {
+                        diff.geaendert.push(HocrSeitenAenderung {
+                            seite: seite.clone(),
+                            aehnlichkeit: aehnlichkeit(&alt, &neu),
+                        });
+                    }
+                }
+                (Some(_), None) => diff.entfernt.push(seite.clone()),
+                (None, Some(_)) => diff.neu.push(seite.clone()),
+                (None, None) => {}
+            }
+        }
+        diff
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl HocrLayout {
+    /// Wendet `f` parallel auf alle Seiten an und sammelt die Ergebnisse nach Seitenschlüssel,
+    /// z.B. für teure Operationen wie `HocrSeite::volltext`
+    pub fn par_map_seiten<F, R>(&self, f: F) -> BTreeMap<String, R>
+    where
+        F: Fn(&str, &HocrSeite) -> R + Sync + Send,
+        R: Send,
+    {
+        use rayon::prelude::*;
+
+        self.seiten
+            .par_iter()
+            .map(|(nr, seite)| (nr.clone(), f(nr, seite)))
+            .collect()
+    }
+}
+
+impl Grundbuch {
+    /// Grundbuch mit leeren Einträgen passend zu einer Seitentyp-Sequenz (z.B. für Tests und
+    /// Layout-Vorschauen): je Seite ein leerer Eintrag in der Abteilung der Seite
+    /// (siehe `SeitenTyp::abteilung`), die lfd. Nr. je Abteilung fortlaufend ab 1
+    pub fn leeres_formular(titel: Titelblatt, seitentypen: &[SeitenTyp]) -> Grundbuch {
+        let mut gb = Grundbuch {
+            titelblatt: titel,
+            bestandsverzeichnis: Bestandsverzeichnis::default(),
+            abt1: Abteilung1::default(),
+            abt2: Abteilung2::default(),
+            abt3: Abteilung3::default(),
+        };
+        for typ in seitentypen {
+            match typ.abteilung() {
+                Abteilungsbereich::Bestandsverzeichnis => {
+                    let lfd_nr = gb.bestandsverzeichnis.eintraege.len() + 1;
+                    gb.bestandsverzeichnis.eintraege.push(BvEintrag::Flurstueck(
+                        BvEintragFlurstueck {
+                            lfd_nr,
+                            bisherige_lfd_nr: None,
+                            flur: 0,
+                            flurstueck: String::new(),
+                            gemarkung: None,
+                            bezeichnung: None,
+                            groesse: FlurstueckGroesse::default(),
+                            automatisch_geroetet: None,
+                            manuell_geroetet: None,
+                            position_in_pdf: None,
+                        },
+                    ));
+                }
+                Abteilungsbereich::Abt1 => {
+                    let lfd_nr = gb.abt1.eintraege.len() + 1;
+                    gb.abt1.eintraege.push(Abt1Eintrag::V1(Abt1EintragV1 {
+                        lfd_nr,
+                        eigentuemer: StringOrLines::default(),
+                        bv_nr: StringOrLines::default(),
+                        grundlage_der_eintragung: StringOrLines::default(),
+                        automatisch_geroetet: None,
+                        manuell_geroetet: None,
+                        position_in_pdf: None,
+                    }));
+                }
+                Abteilungsbereich::Abt2 => {
+                    let lfd_nr = gb.abt2.eintraege.len() + 1;
+                    gb.abt2.eintraege.push(Abt2Eintrag {
+                        lfd_nr,
+                        lfd_nr_zusatz: None,
+                        bv_nr: StringOrLines::default(),
+                        text: StringOrLines::default(),
+                        automatisch_geroetet: None,
+                        manuell_geroetet: None,
+                        position_in_pdf: None,
+                    });
+                }
+                Abteilungsbereich::Abt3 => {
+                    let lfd_nr = gb.abt3.eintraege.len() + 1;
+                    gb.abt3.eintraege.push(Abt3Eintrag {
+                        lfd_nr,
+                        bv_nr: StringOrLines::default(),
+                        betrag: StringOrLines::default(),
+                        text: StringOrLines::default(),
+                        automatisch_geroetet: None,
+                        manuell_geroetet: None,
+                        position_in_pdf: None,
+                    });
+                }
+            }
+        }
+        gb
+    }
+}
+
+impl Bestandsverzeichnis {
+    /// Gruppiert die Flurstücke nach identischer Bezeichnung (`text_clean`, getrimmt) und liefert
+    /// je Gruppe die lfd. Nrn., Gruppen in der Reihenfolge ihres ersten Auftretens. Flurstücke
+    /// ohne (oder mit leerer) Bezeichnung bilden eine eigene Gruppe mit leerem Text.
+    pub fn gruppiere_nach_bezeichnung(&self) -> Vec<(String, Vec<usize>)> {
+        let mut gruppen: Vec<(String, Vec<usize>)> = Vec::new();
+        for e in self.eintraege.iter() {
+            let f = match e {
+                BvEintrag::Flurstueck(f) => f,
+                BvEintrag::Recht(_) => continue,
+            };
+            let bezeichnung = f
+                .bezeichnung
+                .as_ref()
+                .map(|b| b.text_clean().trim().to_string())
+                .unwrap_or_default();
+            match gruppen.iter_mut().find(|(b, _)| *b == bezeichnung) {
+                Some((_, nummern)) => nummern.push(f.lfd_nr),
+                None => gruppen.push((bezeichnung, vec![f.lfd_nr])),
+            }
+        }
+        gruppen
+    }
+}
+
+/// (Teilweise) Datumsangabe aus einem Text, Tag und Monat fehlen bei Angaben wie "1990"
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Datum {
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<u8>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub monat: Option<u8>,
+    pub jahr: i32,
+}
+
+/// Zweistellige Jahre unter diesem Wert gelten als 20xx, alle anderen als 19xx
+pub const ZWEISTELLIGES_JAHR_GRENZE: i32 = 30;
+
+lazy_static::lazy_static! {
+    static ref REGEX_DATUM_MONATSNAME: regex::Regex = {
+        regex::Regex::new(
+            "(?i)(?:\\b(\\d{1,2})\\.?\\s*)?\\b(januar|jan|februar|feb|märz|maerz|mrz|april|apr|mai|juni|jun|juli|jul|august|aug|september|sept|sep|oktober|okt|november|nov|dezember|dez)\\b\\.?\\s*(\\d{4}|\\d{2})\\b"
+        ).unwrap()
+    };
+    static ref REGEX_DATUM_JAHR: regex::Regex = {
+        regex::Regex::new("\\b(1[6-9]\\d{2}|20\\d{2})\\b").unwrap()
+    };
+}
+
+/// Monat (1 - 12) zu einem ausgeschriebenen oder abgekürzten deutschen Monatsnamen
+fn monat_aus_name(name: &str) -> Option<u8> {
+    let name = name.to_lowercase();
+    let monat = match name.as_str() {
+        "januar" | "jan" => 1,
+        "februar" | "feb" => 2,
+        "märz" | "maerz" | "mrz" => 3,
+        "april" | "apr" => 4,
+        "mai" => 5,
+        "juni" | "jun" => 6,
+        "juli" | "jul" => 7,
+        "august" | "aug" => 8,
+        "september" | "sept" | "sep" => 9,
+        "oktober" | "okt" => 10,
+        "november" | "nov" => 11,
+        "dezember" | "dez" => 12,
+        _ => return None,
+    };
+    Some(monat)
+}
+
+/// Jahr aus zwei- oder vierstelliger Angabe, siehe `ZWEISTELLIGES_JAHR_GRENZE`
+fn jahr_aus_text(jahr: &str) -> Option<i32> {
+    let wert = jahr.parse::<i32>().ok()?;
+    if jahr.len() > 2 {
+        Some(wert)
+    } else if wert < ZWEISTELLIGES_JAHR_GRENZE {
+        Some(2000 + wert)
+    } else {
+        Some(1900 + wert)
+    }
+}
+
+impl Datum {
+    /// Tag (1 - 31) und Monat (1 - 12) liegen im gültigen Bereich, soweit angegeben
+    fn ist_plausibel(&self) -> bool {
+        self.tag.is_none_or(|t| (1..=31).contains(&t))
+            && self.monat.is_none_or(|m| (1..=12).contains(&m))
+    }
+}
+
+/// Alle numerischen Datumsangaben ("12.3.1990", "12. 03. 90") im Text mit Fundstelle, ohne
+/// Prüfung von Tag und Monat (siehe `Datum::ist_plausibel`)
+fn numerische_daten(s: &str) -> impl Iterator<Item = (regex::Match<'_>, Datum)> + '_ {
+    REGEX_DATUM.captures_iter(s).filter_map(|cap| {
+        Some((
+            cap.get(0)?,
+            Datum {
+                tag: Some(cap.get(1)?.as_str().parse().ok()?),
+                monat: Some(cap.get(2)?.as_str().parse().ok()?),
+                jahr: jahr_aus_text(cap.get(3)?.as_str())?,
+            },
+        ))
+    })
+}
+
+/// Erstes Datum im Text: "12.3.1990", "12. 03. 90", "12. März 1990", "März 1990" oder nur
+/// "1990". Ungültige Tage / Monate (z.B. "32.13.1990") werden übersprungen,
+/// `GbxError::DatumParse`, wenn kein gültiges Datum im Text steht.
+pub fn parse_deutsches_datum(s: &str) -> Result<Datum, GbxError> {
+    let mut kandidaten = Vec::new();
+
+    kandidaten.extend(numerische_daten(s).map(|(m, d)| (m.start(), d)));
+
+    for cap in REGEX_DATUM_MONATSNAME.captures_iter(s) {
+        let datum = (|| {
+            let tag = match cap.get(1) {
+                Some(t) => Some(t.as_str().parse().ok()?),
+                None => None,
+            };
+            Some(Datum {
+                tag,
+                monat: Some(monat_aus_name(cap.get(2)?.as_str())?),
+                jahr: jahr_aus_text(cap.get(3)?.as_str())?,
+            })
+        })();
+        kandidaten.extend(datum.map(|d| (cap.get(0).map_or(0, |m| m.start()), d)));
+    }
+
+    for cap in REGEX_DATUM_JAHR.captures_iter(s) {
+        let datum = cap.get(1).and_then(|j| {
+            Some(Datum {
+                tag: None,
+                monat: None,
+                jahr: j.as_str().parse().ok()?,
+            })
+        });
+        kandidaten.extend(datum.map(|d| (cap.get(0).map_or(0, |m| m.start()), d)));
+    }
+
+    // bei gleicher Position gewinnt die genauere Angabe (stabile Sortierung)
+    kandidaten.sort_by_key(|(start, _)| *start);
+    kandidaten
+        .into_iter()
+        .map(|(_, d)| d)
+        .find(Datum::ist_plausibel)
+        .ok_or_else(|| GbxError::DatumParse(s.trim().to_string()))
+}
+
+/// Einstellungen für `Grundbuch::zufaellig`
+#[cfg(feature = "test-utils")]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct GenOptions {
+    /// Maximale Anzahl Einträge im Bestandsverzeichnis (mindestens 1 wird erzeugt)
+    pub max_bv: usize,
+    /// Maximale Anzahl Einträge in Abteilung 1 (mindestens 1 wird erzeugt)
+    pub max_abt1: usize,
+    /// Maximale Anzahl Einträge in Abteilung 2
+    pub max_abt2: usize,
+    /// Maximale Anzahl Einträge in Abteilung 3
+    pub max_abt3: usize,
+    /// Wahrscheinlichkeit (0.0 - 1.0), mit der ein Eintrag manuell gerötet ist
+    pub roetung_wahrscheinlichkeit: f32,
+}
+
+#[cfg(feature = "test-utils")]
+impl Default for GenOptions {
+    fn default() -> Self {
+        GenOptions {
+            max_bv: 10,
+            max_abt1: 3,
+            max_abt2: 8,
+            max_abt3: 8,
+            roetung_wahrscheinlichkeit: 0.2,
+        }
+    }
+}
+
+/// Deterministischer Zufallsgenerator (SplitMix64) für `Grundbuch::zufaellig`
+#[cfg(feature = "test-utils")]
+struct Zufall(u64);
+
+#[cfg(feature = "test-utils")]
+impl Zufall {
+    fn naechste(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Zahl in `min..=max`
+    fn bereich(&mut self, min: usize, max: usize) -> usize {
+        if max <= min {
+            return min;
+        }
+        min + (self.naechste() % (max - min + 1) as u64) as usize
+    }
+
+    fn chance(&mut self, wahrscheinlichkeit: f32) -> bool {
+        let zahl = (self.naechste() >> 11) as f64 / (1u64 << 53) as f64;
+        zahl < wahrscheinlichkeit as f64
+    }
+
+    fn waehle<'a>(&mut self, auswahl: &[&'a str]) -> &'a str {
+        auswahl[self.bereich(0, auswahl.len() - 1)]
+    }
+
+    fn roetung(&mut self, wahrscheinlichkeit: f32) -> Option<bool> {
+        if self.chance(wahrscheinlichkeit) {
+            Some(true)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(feature = "test-utils")]
+impl Grundbuch {
+    /// Plausibles, zufällig erzeugtes Grundbuch für Property- und Roundtrip-Tests: derselbe
+    /// `seed` ergibt immer exakt dasselbe Grundbuch. Die lfd. Nr. sind je Abteilung fortlaufend,
+    /// BV-Nummern in Abteilung 1 - 3 verweisen auf existierende Flurstücke.
+    pub fn zufaellig(seed: u64, opts: GenOptions) -> Grundbuch {
+        const ORTE: &[&str] = &["Musterstadt", "Beispielheim", "Neudorf", "Altenberg"];
+        const NUTZUNGEN: &[&str] = &[
+            "Gebäude- und Freifläche",
+            "Landwirtschaftsfläche",
+            "Waldfläche",
+            "Verkehrsfläche",
+        ];
+        const NAMEN: &[&str] = &[
+            "Müller, Hans, geb. 01.02.1950",
+            "Schmidt, Anna, geb. 12.03.1962",
+            "Meier, Karl, geb. 05.11.1948",
+            "Schulz, Petra, geb. 23.07.1970",
+        ];
+        const ABT2_TEXTE: &[&str] = &[
+            "Beschränkte persönliche Dienstbarkeit (Leitungsrecht) für die Stadtwerke",
+            "Grunddienstbarkeit (Wegerecht) für den jeweiligen Eigentümer von Flurstück 12",
+            "Vorkaufsrecht für alle Verkaufsfälle",
+            "Auflassungsvormerkung",
+        ];
+        const GLAEUBIGER: &[&str] = &["Sparkasse Musterstadt", "Volksbank eG", "Bausparkasse AG"];
+
+        let mut z = Zufall(seed);
+        let roetung = opts.roetung_wahrscheinlichkeit;
+        let ort = z.waehle(ORTE);
+
+        let mut gb = Grundbuch {
+            titelblatt: Titelblatt {
+                amtsgericht: z.waehle(ORTE).to_string(),
+                grundbuch_von: ort.to_string(),
+                blatt: z.bereich(1, 9999).to_string(),
+            },
+            bestandsverzeichnis: Bestandsverzeichnis::default(),
+            abt1: Abteilung1::default(),
+            abt2: Abteilung2::default(),
+            abt3: Abteilung3::default(),
+        };
+
+        let anzahl_bv = z.bereich(1, opts.max_bv.max(1));
+        for lfd_nr in 1..=anzahl_bv {
+            let flurstueck = if z.chance(0.3) {
+                format!("{}/{}", z.bereich(1, 500), z.bereich(1, 20))
+            } else {
+                z.bereich(1, 500).to_string()
+            };
+            let mut flst = BvEintragFlurstueck::new(lfd_nr, z.bereich(1, 20), flurstueck);
+            flst.bezeichnung = Some(z.waehle(NUTZUNGEN).into());
+            flst.groesse = FlurstueckGroesse::Metrisch {
+                m2: Some(z.bereich(50, 50_000) as u64),
+            };
+            flst.manuell_geroetet = z.roetung(roetung);
+            gb.bestandsverzeichnis
+                .eintraege
+                .push(BvEintrag::Flurstueck(flst));
+        }
+
+        let alle_bv_nr = (1..=anzahl_bv)
+            .map(|n| n.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        for lfd_nr in 1..=z.bereich(1, opts.max_abt1.max(1)) {
+            gb.abt1.eintraege.push(Abt1Eintrag::V1(Abt1EintragV1 {
+                lfd_nr,
+                eigentuemer: z.waehle(NAMEN).into(),
+                bv_nr: alle_bv_nr.as_str().into(),
+                grundlage_der_eintragung: StringOrLines::default(),
+                automatisch_geroetet: None,
+                manuell_geroetet: z.roetung(roetung),
+                position_in_pdf: None,
+            }));
+        }
+
+        for lfd_nr in 1..=z.bereich(0, opts.max_abt2) {
+            let bv_nr = z.bereich(1, anzahl_bv).to_string();
+            let mut e = Abt2Eintrag::new(lfd_nr, bv_nr, z.waehle(ABT2_TEXTE));
+            e.manuell_geroetet = z.roetung(roetung);
+            gb.abt2.eintraege.push(e);
+        }
+
+        for lfd_nr in 1..=z.bereich(0, opts.max_abt3) {
+            let bv_nr = z.bereich(1, anzahl_bv).to_string();
+            let betrag = format!("{}.000,00 EUR", z.bereich(5, 500));
+            let text = format!(
+                "Grundschuld ohne Brief zu {betrag} für die {}",
+                z.waehle(GLAEUBIGER)
+            );
+            let mut e = Abt3Eintrag::new(lfd_nr, bv_nr, betrag, text);
+            e.manuell_geroetet = z.roetung(roetung);
+            gb.abt3.eintraege.push(e);
+        }
+
+        gb
+    }
+}
+
+impl Bestandsverzeichnis {
+    /// Entfernt leere Zu- und Abschreibungen und fasst Einträge mit gleicher `bv_nr` und
+    /// gleichem Text (jeweils `text_clean`) zusammen. Der erste Eintrag bleibt erhalten, ist
+    /// einer der Einträge gerötet, ist es auch das Ergebnis (siehe `mische_roetung`).
+    pub fn dedupe_zu_abschreibungen(&mut self) {
+        self.zuschreibungen = dedupe_vermerke(std::mem::take(&mut self.zuschreibungen));
+        self.abschreibungen = dedupe_vermerke(std::mem::take(&mut self.abschreibungen));
+    }
+}
+
+/// Veränderliche Sicht auf die gemeinsamen Felder von `BvZuschreibung` und `BvAbschreibung`
+struct VermerkFelder<'a> {
+    bv_nr: &'a StringOrLines,
+    text: &'a StringOrLines,
+    automatisch_geroetet: &'a mut Option<bool>,
+    manuell_geroetet: &'a mut Option<bool>,
+    position_in_pdf: &'a mut Option<PositionInPdf>,
+}
+
+/// Zu- oder Abschreibung im Bestandsverzeichnis, siehe `dedupe_vermerke`
+trait BvVermerk {
+    fn felder(&mut self) -> VermerkFelder<'_>;
+}
+
+impl BvVermerk for BvZuschreibung {
+    fn felder(&mut self) -> VermerkFelder<'_> {
+        VermerkFelder {
+            bv_nr: &self.bv_nr,
+            text: &self.text,
+            automatisch_geroetet: &mut self.automatisch_geroetet,
+            manuell_geroetet: &mut self.manuell_geroetet,
+            position_in_pdf: &mut self.position_in_pdf,
+        }
+    }
+}
+
+impl BvVermerk for BvAbschreibung {
+    fn felder(&mut self) -> VermerkFelder<'_> {
+        VermerkFelder {
+            bv_nr: &self.bv_nr,
+            text: &self.text,
+            automatisch_geroetet: &mut self.automatisch_geroetet,
+            manuell_geroetet: &mut self.manuell_geroetet,
+            position_in_pdf: &mut self.position_in_pdf,
+        }
+    }
+}
+
+/// Entfernt leere Vermerke und fasst gleiche Vermerke zusammen, siehe
+/// `Bestandsverzeichnis::dedupe_zu_abschreibungen`
+fn dedupe_vermerke<T: BvVermerk>(vermerke: Vec<T>) -> Vec<T> {
+    let mut ergebnis: Vec<T> = Vec::new();
+    let mut schluessel_liste = Vec::new();
+    for mut neu in vermerke {
+        let n = neu.felder();
+        if n.bv_nr.is_empty() && n.text.is_empty() {
+            continue;
+        }
+        let schluessel = (n.bv_nr.text_clean(), n.text.text_clean());
+        match schluessel_liste.iter().position(|s| *s == schluessel) {
+            Some(i) => {
+                let mut v = ergebnis[i].felder();
+                mische_roetung(&mut v, &n);
+                if PositionInPdf::option_ist_leer(v.position_in_pdf) {
+                    *v.position_in_pdf = n.position_in_pdf.take();
+                }
+            }
+            None => {
+                ergebnis.push(neu);
+                schluessel_liste.push(schluessel);
+            }
+        }
+    }
+    ergebnis
+}
+
+/// Mischt die Rötung von `b` in `a`: ist einer der beiden gerötet (`Roetungsstatus`), wird
+/// `a` manuell gerötet. Sonst bleibt `a` ungerötet, eine manuelle Entscheidung aus `b` wird
+/// übernommen, wenn `a` keine hat.
+fn mische_roetung(a: &mut VermerkFelder<'_>, b: &VermerkFelder<'_>) {
+    let status = |f: &VermerkFelder<'_>| {
+        Roetungsstatus::aus_flags(*f.automatisch_geroetet, *f.manuell_geroetet)
+    };
+    if status(a).ist_geroetet() || status(b).ist_geroetet() {
+        *a.manuell_geroetet = Some(true);
+    } else if a.manuell_geroetet.is_none() {
+        *a.manuell_geroetet = *b.manuell_geroetet;
+    }
+}
+
+/// Knoten im Navigationsbaum eines Grundbuchs, siehe `Grundbuch::navigations_baum`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct NavNode {
+    /// Lesbare Beschriftung ("Abteilung II", "Nr. 3 – Wegerecht ...")
+    pub label: String,
+    /// Eindeutige ID im Baum ("abt2", "abt2_eintraege", "abt2_eintraege/2")
+    pub id: String,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub kinder: Vec<NavNode>,
+}
+
+/// Maximale Zeichenanzahl der Textzusammenfassung in den Labels von `NavNode`
+const NAV_LABEL_MAX_ZEICHEN: usize = 40;
+
+impl Grundbuch {
+    /// Hierarchische Struktur für eine Baum-Navigation: Grundbuch → Abteilungen → Listen
+    /// (Einträge / Veränderungen / Löschungen, leere Listen entfallen) → einzelne Einträge.
+    /// Die IDs der Einträge sind "<liste>/<index>" (siehe `EintragsListe::name`).
+    pub fn navigations_baum(&self) -> NavNode {
+        let tb = &self.titelblatt;
+        let abteilungen = [
+            (Abteilungsbereich::Bestandsverzeichnis, "bv"),
+            (Abteilungsbereich::Abt1, "abt1"),
+            (Abteilungsbereich::Abt2, "abt2"),
+            (Abteilungsbereich::Abt3, "abt3"),
+        ];
+
+        let kinder = abteilungen
+            .iter()
+            .map(|(abteilung, id)| {
+                let listen = EintragsListe::ALLE
+                    .into_iter()
+                    .filter(|l| l.abteilung() == *abteilung && self.anzahl(*l) > 0)
+                    .map(|liste| NavNode {
+                        label: liste.titel().unwrap_or("Einträge").to_string(),
+                        id: liste.name().to_string(),
+                        kinder: (0..self.anzahl(liste))
+                            .filter_map(|index| {
+                                let pfad = EintragPfad { liste, index };
+                                let eintrag = self.eintrag(&pfad)?;
+                                Some(NavNode {
+                                    label: nav_label(&eintrag, index),
+                                    id: format!("{}/{}", liste.name(), index),
+                                    kinder: Vec::new(),
+                                })
+                            })
+                            .collect(),
+                    })
+                    .collect();
+                NavNode {
+                    label: abteilung.titel().to_string(),
+                    id: id.to_string(),
+                    kinder: listen,
+                }
+            })
+            .collect();
+
+        NavNode {
+            label: format!("{} Blatt {}", tb.grundbuch_von.trim(), tb.blatt.trim()),
+            id: "grundbuch".to_string(),
+            kinder,
+        }
+    }
+}
+
+/// Label eines Eintrags im Navigationsbaum: "Nr. X – <gekürzter Haupttext>", gerötete
+/// Einträge mit dem Zusatz "(gerötet)"
+fn nav_label(eintrag: &GrundbuchEintragRef<'_>, index: usize) -> String {
+    let lfd_nr = eintrag.lfd_nr_string();
+    let mut label = if lfd_nr.trim().is_empty() || lfd_nr.trim() == "0" {
+        format!("Eintrag {}", index + 1)
+    } else {
+        format!("Nr. {}", lfd_nr.trim())
+    };
+
+    let text = match eintrag {
+        GrundbuchEintragRef::Bv(BvEintrag::Flurstueck(f)) => {
+            format!("Flur {}, Flurstück {}", f.flur, f.flurstueck.trim())
+        }
+        _ => eintrag
+            .haupttext()
+            .map(|t| normalisiere_leerzeichen(&t.text_mit(" ")))
+            .unwrap_or_default(),
+    };
+    if !text.is_empty() {
+        label.push_str(" – ");
+        label.push_str(&kuerze_text(&text, NAV_LABEL_MAX_ZEICHEN));
+    }
+
+    if eintrag.ist_geroetet() {
+        label.push_str(" (gerötet)");
+    }
+    label
+}
+
+/// Hinweis auf eine unplausible Flächenhistorie, siehe `Bestandsverzeichnis::pruefe_flaechenbilanz`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum FlaechenWarnung {
+    /// Das Flurstück `lfd_nr` ist als Teilfläche / Rest eines anderen Flurstücks vermerkt, das
+    /// Quell-Flurstück `quelle_lfd_nr` ist aber weder gerötet noch abgeschrieben
+    QuelleNichtAbgeschrieben {
+        lfd_nr: usize,
+        quelle_lfd_nr: usize,
+        flurstueck: String,
+    },
+    /// Dasselbe Flurstück kommt sowohl gerötet als auch aktiv vor
+    GeroetetUndAktiv {
+        flur: usize,
+        flurstueck: String,
+        aktiv: Vec<usize>,
+        geroetet: Vec<usize>,
+    },
+}
+
+impl Bestandsverzeichnis {
+    /// Plausibilitätsprüfung der Flurstückshistorie nach Teilungen und Vereinigungen: meldet
+    /// Teilflächen, deren Quell-Flurstück noch aktiv und nicht abgeschrieben ist, sowie
+    /// Flurstücke, die gleichzeitig gerötet und aktiv vorkommen
+    pub fn pruefe_flaechenbilanz(&self) -> Vec<FlaechenWarnung> {
+        let schluessel = |f: &BvEintragFlurstueck| -> (usize, String) {
+            (
+                f.flur,
+                f.flurstueck_normalisiert()
+                    .unwrap_or_else(|| f.flurstueck.trim().to_string()),
+            )
+        };
+
+        let flurstuecke = self
+            .eintraege
+            .iter()
+            .filter_map(|e| match e {
+                BvEintrag::Flurstueck(f) => Some(f),
+                BvEintrag::Recht(_) => None,
+            })
+            .collect::<Vec<_>>();
+
+        let abgeschrieben = self
+            .abschreibungen
+            .iter()
+            .filter(|a| !a.ist_geroetet())
+            .flat_map(|a| a.bv_nr.nummern())
+            .collect::<std::collections::BTreeSet<_>>();
+
+        let mut warnungen = Vec::new();
+
+        for f in flurstuecke.iter() {
+            let quelle = match f.teilflaechen_vermerk() {
+                Some(t) => t,
+                None => continue,
+            };
+            let quell_schluessel = (quelle.flur.unwrap_or(f.flur), quelle.flurstueck.clone());
+            for q in flurstuecke.iter() {
+                if q.lfd_nr == f.lfd_nr || schluessel(q) != quell_schluessel {
+                    continue;
+                }
+                if !q.ist_geroetet() && !abgeschrieben.contains(&q.lfd_nr) {
+                    warnungen.push(FlaechenWarnung::QuelleNichtAbgeschrieben {
+                        lfd_nr: f.lfd_nr,
+                        quelle_lfd_nr: q.lfd_nr,
+                        flurstueck: quell_schluessel.1.clone(),
+                    });
+                }
+            }
+        }
+
+        let mut nach_flurstueck = BTreeMap::<(usize, String), (Vec<usize>, Vec<usize>)>::new();
+        for f in flurstuecke.iter() {
+            let (aktiv, geroetet) = nach_flurstueck.entry(schluessel(f)).or_default();
+            if f.ist_geroetet() {
+                geroetet.push(f.lfd_nr);
+            } else {
+                aktiv.push(f.lfd_nr);
+            }
+        }
+        for ((flur, flurstueck), (aktiv, geroetet)) in nach_flurstueck {
+            if !aktiv.is_empty() && !geroetet.is_empty() {
+                warnungen.push(FlaechenWarnung::GeroetetUndAktiv {
+                    flur,
+                    flurstueck,
+                    aktiv,
+                    geroetet,
+                });
+            }
+        }
+
+        warnungen
+    }
+}
+
+/// Fehler in den Verweisen über `bisherige_lfd_nr`,
+/// siehe `Bestandsverzeichnis::pruefe_bisherige_lfd_nr_ketten`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum KettenFehler {
+    /// Eintrag `lfd_nr` verweist auf eine `bisherige_lfd_nr`, die es im Bestandsverzeichnis nicht gibt
+    GebrocheneKette {
+        lfd_nr: usize,
+        bisherige_lfd_nr: usize,
+    },
+    /// Die Einträge verweisen im Kreis aufeinander, beginnend mit der kleinsten lfd. Nr.
+    /// (z.B. `[2, 5]`: 2 verweist auf 5, 5 verweist auf 2)
+    Zyklus { lfd_nr: Vec<usize> },
+}
+
+impl Bestandsverzeichnis {
+    /// Prüft, ob jede `bisherige_lfd_nr` auf einen existierenden Eintrag verweist und die
+    /// Verweise keine Zyklen bilden. Gebrochene Ketten stehen in Eintragsreihenfolge vor den
+    /// Zyklen, jeder Zyklus wird nur einmal gemeldet.
+    pub fn pruefe_bisherige_lfd_nr_ketten(&self) -> Vec<KettenFehler> {
+        let vorhanden = self
+            .eintraege
+            .iter()
+            .map(|e| e.get_lfd_nr())
+            .collect::<std::collections::BTreeSet<_>>();
+
+        let mut fehler = Vec::new();
+        let mut verweise = BTreeMap::<usize, std::collections::BTreeSet<usize>>::new();
+        for e in self.eintraege.iter() {
+            let bisherige_lfd_nr = match e.get_bisherige_lfd_nr() {
+                Some(s) => s,
+                None => continue,
+            };
+            if vorhanden.contains(&bisherige_lfd_nr) {
+                verweise
+                    .entry(e.get_lfd_nr())
+                    .or_default()
+                    .insert(bisherige_lfd_nr);
+            } else {
+                fehler.push(KettenFehler::GebrocheneKette {
+                    lfd_nr: e.get_lfd_nr(),
+                    bisherige_lfd_nr,
+                });
+            }
+        }
+
+        let mut zyklen = std::collections::BTreeSet::new();
+        let mut besucht = std::collections::BTreeSet::new();
+        for start in verweise.keys() {
+            let mut pfad = Vec::new();
+            suche_zyklen(*start, &verweise, &mut pfad, &mut besucht, &mut zyklen);
+        }
+        fehler.extend(
+            zyklen
+                .into_iter()
+                .map(|lfd_nr| KettenFehler::Zyklus { lfd_nr }),
+        );
+
+        fehler
+    }
+}
+
+/// Tiefensuche über die `bisherige_lfd_nr`-Verweise, gefundene Zyklen werden so rotiert,
+/// dass sie mit der kleinsten Nummer beginnen
+fn suche_zyklen(
+    knoten: usize,
+    verweise: &BTreeMap<usize, std::collections::BTreeSet<usize>>,
+    pfad: &mut Vec<usize>,
+    besucht: &mut std::collections::BTreeSet<usize>,
+    zyklen: &mut std::collections::BTreeSet<Vec<usize>>,
+) {
+    if let Some(pos) = pfad.iter().position(|k| *k == knoten) {
+        let mut zyklus = pfad[pos..].to_vec();
+        let min = zyklus
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, k)| **k)
+            .map(|(i, _)| i)
+            .unwrap_or(0);
+        zyklus.rotate_left(min);
+        zyklen.insert(zyklus);
+        return;
+    }
+    if !besucht.insert(knoten) {
+        return;
+    }
+    pfad.push(knoten);
+    for naechster in verweise.get(&knoten).into_iter().flatten() {
+        suche_zyklen(*naechster, verweise, pfad, besucht, zyklen);
+    }
+    pfad.pop();
+}
+
+impl Grundbuch {
+    /// Volltextsuche über alle Textfelder der Einträge, unabhängig von Groß- / Kleinschreibung
+    /// und Umlautschreibweise (siehe `normalisiere_suchtext`). Ein leerer Suchtext passt auf
+    /// alle Einträge (wie bei `Titelblatt::passt_zu_suche`).
+    pub fn suche_eintraege(&self, suche: &str) -> Vec<EintragPfad> {
+        let suche = normalisiere_suchtext(suche);
+        self.alle_eintraege_mit_pfad()
+            .filter(|(_, e)| {
+                suche.is_empty()
+                    || e.text_felder().iter().any(|feld| {
+                        e.text_feld(*feld).is_some_and(|t| {
+                            normalisiere_suchtext(&t.text_mit(" ")).contains(&suche)
+                        })
+                    })
+            })
+            .map(|(pfad, _)| pfad)
+            .collect()
+    }
+}
+
+/// Kürzt einen Text auf höchstens `max_zeichen` Zeichen, gekürzte Texte enden auf "…"
+fn kuerze_text(text: &str, max_zeichen: usize) -> String {
+    if text.chars().count() > max_zeichen {
+        let mut gekuerzt = text.chars().take(max_zeichen).collect::<String>();
+        gekuerzt.push('…');
+        gekuerzt
+    } else {
+        text.to_string()
+    }
+}
+
+/// Ereignis in der Zeitleiste eines Grundbuchs, siehe `Grundbuch::zeitleiste`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ZeitleistenEintrag {
+    /// Datum der Eintragung, falls im Text erkennbar (siehe `parse_deutsches_datum`)
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub datum: Option<Datum>,
+    pub abteilung: Abteilungsbereich,
+    pub pfad: EintragPfad,
+    /// Betroffene lfd. Nr. (bei Zu- / Abschreibungen die BV-Nr.), z.B. "1, 2"
+    pub lfd_nr: String,
+    /// Gekürzter Text der Veränderung / Löschung
+    pub beschreibung: String,
+}
+
+/// Maximale Zeichenanzahl von `ZeitleistenEintrag::beschreibung`
+const ZEITLEISTE_BESCHREIBUNG_MAX_ZEICHEN: usize = 80;
+
+impl Grundbuch {
+    /// Alle Zu- / Abschreibungen, Veränderungen und Löschungen über alle Abteilungen, nach Datum
+    /// sortiert. Das Datum wird bevorzugt hinter "eingetragen" gesucht, sonst im ganzen Text;
+    /// Einträge ohne erkennbares Datum stehen in Dokumentreihenfolge am Ende.
+    pub fn zeitleiste(&self) -> Vec<ZeitleistenEintrag> {
+        use self::EintragsListe::*;
+
+        let mut zeitleiste = self
+            .alle_eintraege_mit_pfad()
+            .filter(|(pfad, _)| {
+                matches!(
+                    pfad.liste,
+                    BvZuschreibungen
+                        | BvAbschreibungen
+                        | Abt1Veraenderungen
+                        | Abt1Loeschungen
+                        | Abt2Veraenderungen
+                        | Abt2Loeschungen
+                        | Abt3Veraenderungen
+                        | Abt3Loeschungen
+                )
+            })
+            .map(|(pfad, e)| {
+                let text = normalisiere_leerzeichen(&e.text().replace("\r\n", " "));
+                let klein = text.to_lowercase();
+                let eingetragen = klein
+                    .find("eingetragen")
+                    .and_then(|i| parse_deutsches_datum(&klein[i..]).ok());
+                ZeitleistenEintrag {
+                    datum: eingetragen.or_else(|| parse_deutsches_datum(&text).ok()),
+                    abteilung: pfad.liste.abteilung(),
+                    pfad,
+                    lfd_nr: e.lfd_nr_string().trim().to_string(),
+                    beschreibung: kuerze_text(&text, ZEITLEISTE_BESCHREIBUNG_MAX_ZEICHEN),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        zeitleiste.sort_by_key(|z| match z.datum {
+            Some(d) => (0, d.jahr, d.monat.unwrap_or(0), d.tag.unwrap_or(0)),
+            None => (1, 0, 0, 0),
+        });
+        zeitleiste
+    }
+}
+
+impl Bestandsverzeichnis {
+    /// Gruppen von lfd. Nrn. der Flurstücke mit gleichem `BvEintragFlurstueck::fachschluessel`
+    /// (nur Gruppen mit mehr als einem Flurstück, gerötete Einträge eingeschlossen), in der
+    /// Reihenfolge ihres ersten Auftretens
+    pub fn finde_doppelte_flurstuecke(&self, titel: &Titelblatt) -> Vec<Vec<usize>> {
+        let mut gruppen: Vec<(String, Vec<usize>)> = Vec::new();
+        for e in self.eintraege.iter() {
+            let f = match e {
+                BvEintrag::Flurstueck(f) => f,
+                BvEintrag::Recht(_) => continue,
+            };
+            let schluessel = f.fachschluessel(titel);
+            match gruppen.iter_mut().find(|(s, _)| *s == schluessel) {
+                Some((_, nummern)) => nummern.push(f.lfd_nr),
+                None => gruppen.push((schluessel, vec![f.lfd_nr])),
+            }
+        }
+        gruppen
+            .into_iter()
+            .map(|(_, nummern)| nummern)
+            .filter(|n| n.len() > 1)
+            .collect()
+    }
+}
+
+/// Widerspruch zwischen Rötung und Löschungen, siehe `Grundbuch::pruefe_roetung`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum RoetungsWarnung {
+    /// Der Eintrag wird in einer nicht geröteten Löschung genannt, ist aber nicht gerötet
+    /// (siehe `Abteilung2::wende_loeschungen_an`)
+    GeloeschtAberNichtGeroetet {
+        pfad: EintragPfad,
+        lfd_nr: LaufendeNummer,
+    },
+    /// Der Eintrag ist gerötet, wird aber in keiner Löschung genannt
+    GeroetetOhneLoeschung {
+        pfad: EintragPfad,
+        lfd_nr: LaufendeNummer,
+    },
+}
+
+impl Grundbuch {
+    /// Gruppen von Einträgen in Abteilung I - III mit derselben lfd. Nr. (bei Abteilung II
+    /// inkl. Buchstabenzusatz), Einträge ohne gültige lfd. Nr. werden übersprungen
+    pub fn doppelte_lfd_nr(&self) -> Vec<Vec<EintragPfad>> {
+        let mut gruppen = BTreeMap::<(EintragsListe, LaufendeNummer), Vec<EintragPfad>>::new();
+        for (pfad, e) in self.alle_eintraege_mit_pfad() {
+            let gueltig = match e {
+                GrundbuchEintragRef::Abt1(a) => a.hat_gueltige_lfd_nr(),
+                GrundbuchEintragRef::Abt2(a) => a.hat_gueltige_lfd_nr(),
+                GrundbuchEintragRef::Abt3(a) => a.hat_gueltige_lfd_nr(),
+                _ => false,
+            };
+            if let Some(lfd_nr) = e.laufende_nummer().filter(|_| gueltig) {
+                gruppen.entry((pfad.liste, lfd_nr)).or_default().push(pfad);
+            }
+        }
+        gruppen.into_values().filter(|g| g.len() > 1).collect()
+    }
+
+    /// Prüft in Abteilung II und III, ob die Rötung der Einträge zu den Löschungen passt
+    pub fn pruefe_roetung(&self) -> Vec<RoetungsWarnung> {
+        let mut warnungen = roetung_warnungen(
+            EintragsListe::Abt2Eintraege,
+            self.abt2
+                .eintraege
+                .iter()
+                .map(|e| (e.laufende_nummer(), e.ist_geroetet())),
+            self.abt2
+                .loeschungen
+                .iter()
+                .map(|l| (l.lfd_nr.laufende_nummern(), l.ist_geroetet())),
+        );
+        warnungen.extend(roetung_warnungen(
+            EintragsListe::Abt3Eintraege,
+            self.abt3
+                .eintraege
+                .iter()
+                .map(|e| (LaufendeNummer::from(e.lfd_nr), e.ist_geroetet())),
+            self.abt3
+                .loeschungen
+                .iter()
+                .map(|l| (l.lfd_nr.laufende_nummern(), l.ist_geroetet())),
+        ));
+        warnungen
+    }
+}
+
+fn roetung_warnungen(
+    liste: EintragsListe,
+    eintraege: impl Iterator<Item = (LaufendeNummer, bool)>,
+    loeschungen: impl Iterator<Item = (Vec<LaufendeNummer>, bool)>,
+) -> Vec<RoetungsWarnung> {
+    let mut genannt = std::collections::BTreeSet::new();
+    let mut geloescht = std::collections::BTreeSet::new();
+    for (nummern, geroetet) in loeschungen {
+        if !geroetet {
+            geloescht.extend(nummern.iter().cloned());
+        }
+        genannt.extend(nummern);
+    }
+
+    let mut warnungen = Vec::new();
+    for (index, (lfd_nr, geroetet)) in eintraege.enumerate() {
+        let pfad = EintragPfad { liste, index };
+        if !geroetet && geloescht.contains(&lfd_nr) {
+            warnungen.push(RoetungsWarnung::GeloeschtAberNichtGeroetet { pfad, lfd_nr });
+        } else if geroetet && !genannt.contains(&lfd_nr) {
+            warnungen.push(RoetungsWarnung::GeroetetOhneLoeschung { pfad, lfd_nr });
+        }
+    }
+    warnungen
+}
+
+/// Schweregrad eines `PruefBefund`, aufsteigend sortiert
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum Schweregrad {
+    /// Auffälligkeit, die meist fachlich korrekt ist
+    Hinweis,
+    /// Wahrscheinlicher Erfassungsfehler
+    Warnung,
+    /// Verletzte Invariante, das Blatt sollte so nicht gespeichert werden
+    Fehler,
+}
+
+/// Teilprüfung, aus der ein `PruefBefund` stammt
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum PruefBereich {
+    /// `Grundbuch::doppelte_lfd_nr`
+    LfdNr,
+    /// `pruefe_bv_referenzen` der Abteilungen I - III
+    Referenzen,
+    /// `Bestandsverzeichnis::pruefe_flaechenbilanz` und `pruefe_bisherige_lfd_nr_ketten`
+    Flaechen,
+    /// `Grundbuch::pruefe_roetung`
+    Roetung,
+    /// `Grundbuch::invertierte_positionen`
+    Positionen,
+}
+
+/// Einzelnes Ergebnis von `Grundbuch::pruefe_alles`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PruefBefund {
+    pub schweregrad: Schweregrad,
+    pub bereich: PruefBereich,
+    /// Beschreibung für die Anzeige
+    pub meldung: String,
+    /// Betroffene Einträge, leer wenn keine Zuordnung möglich ist
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub pfade: Vec<EintragPfad>,
+}
+
+/// Ergebnis von `Grundbuch::pruefe_alles`
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PruefBericht {
+    /// Befunde in der Reihenfolge der Teilprüfungen (siehe `PruefBereich`)
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub befunde: Vec<PruefBefund>,
+}
+
+impl PruefBericht {
+    /// Ob mindestens ein Befund den Schweregrad `Fehler` hat
+    pub fn hat_fehler(&self) -> bool {
+        self.anzahl(Schweregrad::Fehler) > 0
+    }
+
+    /// Anzahl der Befunde mit genau diesem Schweregrad
+    pub fn anzahl(&self, schweregrad: Schweregrad) -> usize {
+        self.befunde
+            .iter()
+            .filter(|b| b.schweregrad == schweregrad)
+            .count()
+    }
+
+    fn melde(
+        &mut self,
+        schweregrad: Schweregrad,
+        bereich: PruefBereich,
+        meldung: String,
+        pfade: Vec<EintragPfad>,
+    ) {
+        self.befunde.push(PruefBefund {
+            schweregrad,
+            bereich,
+            meldung,
+            pfade,
+        });
+    }
+}
+
+impl Grundbuch {
+    /// Führt alle fachlichen Prüfungen in einem Durchgang aus: lfd. Nr.-Eindeutigkeit,
+    /// Referenzen der Abteilungen auf das Bestandsverzeichnis, Flächenbilanz und
+    /// `bisherige_lfd_nr`-Ketten, Rötung und Positionen. Referenzen und Flächen werden gegen
+    /// `bv` geprüft, ohne Angabe gegen das eigene Bestandsverzeichnis. Die Teilprüfungen sind
+    /// auch einzeln aufrufbar (siehe `PruefBereich`).
+    pub fn pruefe_alles(&self, bv: Option<&Bestandsverzeichnis>) -> PruefBericht {
+        let eigenes_bv = bv.is_none();
+        let bv = bv.unwrap_or(&self.bestandsverzeichnis);
+        let mut bericht = PruefBericht::default();
+
+        let pfade_fuer = |liste: EintragsListe, lfd_nr: LaufendeNummer| -> Vec<EintragPfad> {
+            if liste == EintragsListe::BvEintraege && !eigenes_bv {
+                return Vec::new();
+            }
+            self.alle_eintraege_mit_pfad()
+                .filter(|(p, e)| p.liste == liste && e.laufende_nummer().as_ref() == Some(&lfd_nr))
+                .map(|(p, _)| p)
+                .collect()
+        };
+
+        for gruppe in self.doppelte_lfd_nr() {
+            let lfd_nr = gruppe
+                .first()
+                .and_then(|p| self.eintrag(p))
+                .map(|e| e.lfd_nr_string())
+                .unwrap_or_default();
+            let titel = gruppe
+                .first()
+                .map(|p| p.liste.abteilung().titel())
+                .unwrap_or_default();
+            bericht.melde(
+                Schweregrad::Fehler,
+                PruefBereich::LfdNr,
+                format!("{titel}: lfd. Nr. {lfd_nr} ist mehrfach vergeben"),
+                gruppe,
+            );
+        }
+
+        let referenzen = [
+            (
+                EintragsListe::Abt1Eintraege,
+                self.abt1.pruefe_bv_referenzen(bv),
+            ),
+            (
+                EintragsListe::Abt2Eintraege,
+                self.abt2.pruefe_bv_referenzen(bv),
+            ),
+            (
+                EintragsListe::Abt3Eintraege,
+                self.abt3.pruefe_bv_referenzen(bv),
+            ),
+        ];
+        for (liste, fehler) in referenzen {
+            for f in fehler {
+                bericht.melde(
+                    Schweregrad::Fehler,
+                    PruefBereich::Referenzen,
+                    format!("{}: {f}", liste.abteilung().titel()),
+                    pfade_fuer(liste, f.lfd_nr.clone()),
+                );
+            }
+        }
+
+        for w in bv.pruefe_flaechenbilanz() {
+            let (meldung, pfade) = match &w {
+                FlaechenWarnung::QuelleNichtAbgeschrieben {
+                    lfd_nr,
+                    quelle_lfd_nr,
+                    flurstueck,
+                } => (
+                    format!(
+                        "Nr. {lfd_nr} ist Teilfläche von Flurstück {flurstueck} (Nr. \
+                         {quelle_lfd_nr}), das weder gerötet noch abgeschrieben ist"
+                    ),
+                    pfade_fuer(EintragsListe::BvEintraege, (*lfd_nr).into()),
+                ),
+                FlaechenWarnung::GeroetetUndAktiv {
+                    flur,
+                    flurstueck,
+                    aktiv,
+                    ..
+                } => (
+                    format!("Flur {flur} Flurstück {flurstueck} ist gerötet und zugleich aktiv"),
+                    aktiv
+                        .iter()
+                        .flat_map(|n| pfade_fuer(EintragsListe::BvEintraege, (*n).into()))
+                        .collect(),
+                ),
+            };
+            bericht.melde(Schweregrad::Warnung, PruefBereich::Flaechen, meldung, pfade);
+        }
+
+        for f in bv.pruefe_bisherige_lfd_nr_ketten() {
+            let (meldung, pfade) = match &f {
+                KettenFehler::GebrocheneKette {
+                    lfd_nr,
+                    bisherige_lfd_nr,
+                } => (
+                    format!("Nr. {lfd_nr} verweist auf nicht vorhandene bisherige Nr. {bisherige_lfd_nr}"),
+                    pfade_fuer(EintragsListe::BvEintraege, (*lfd_nr).into()),
+                ),
+                KettenFehler::Zyklus { lfd_nr } => (
+                    format!(
+                        "Bisherige lfd. Nrn. bilden einen Zyklus: {}",
+                        lfd_nr
+                            .iter()
+                            .map(|n| n.to_string())
+                            .collect::<Vec<_>>()
+                            .join(" → ")
+                    ),
+                    lfd_nr
+                        .iter()
+                        .flat_map(|n| pfade_fuer(EintragsListe::BvEintraege, (*n).into()))
+                        .collect(),
+                ),
+            };
+            bericht.melde(Schweregrad::Fehler, PruefBereich::Flaechen, meldung, pfade);
+        }
+
+        for w in self.pruefe_roetung() {
+            let (schweregrad, meldung, pfad) = match w {
+                RoetungsWarnung::GeloeschtAberNichtGeroetet { pfad, lfd_nr } => (
+                    Schweregrad::Warnung,
+                    format!("Nr. {lfd_nr} ist gelöscht, aber nicht gerötet"),
+                    pfad,
+                ),
+                RoetungsWarnung::GeroetetOhneLoeschung { pfad, lfd_nr } => (
+                    Schweregrad::Hinweis,
+                    format!("Nr. {lfd_nr} ist gerötet, aber in keiner Löschung genannt"),
+                    pfad,
+                ),
+            };
+            let meldung = format!("{}: {meldung}", pfad.liste.abteilung().titel());
+            bericht.melde(schweregrad, PruefBereich::Roetung, meldung, vec![pfad]);
+        }
+
+        for pfad in self.invertierte_positionen() {
+            bericht.melde(
+                Schweregrad::Warnung,
+                PruefBereich::Positionen,
+                "Position im PDF ist invertiert (min > max)".to_string(),
+                vec![pfad],
+            );
+        }
+
+        bericht
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_f32_schreibt_hoechstens_drei_nachkommastellen() {
+        let ausgabe = |wert: f32| {
+            let mut buf = Vec::new();
+            round_f32(&wert, &mut serde_json::Serializer::new(&mut buf)).unwrap();
+            String::from_utf8(buf).unwrap()
+        };
+        assert_eq!(ausgabe(12.3), "12.3");
+        assert_eq!(ausgabe(0.1234567), "0.123");
+        assert_eq!(ausgabe(210.0), "210.0");
+        assert_eq!(ausgabe(-5.0005), "-5.001");
+        for wert in [0.1_f32, 1.7, 33.33, 297.004, 1234.5677, 84.666_67] {
+            let s = ausgabe(wert);
+            let nachkommastellen = s.split('.').nth(1).map_or(0, str::len);
+            assert!(nachkommastellen <= 3, "{wert} -> {s}");
+            assert!(
+                (s.parse::<f32>().unwrap() - wert).abs() <= 0.0005,
+                "{wert} -> {s}"
+            );
+        }
+    }
+
+    #[test]
+    fn bv_eintrag_variante_nach_feldern() {
+        let bv = |json| serde_json::from_value::<BvEintrag>(json);
+
+        let flst = bv(serde_json::json!({
+            "lfd_nr": 1,
+            "flur": "2",
+            "flurstueck": "34/5",
+            "groesse": { "typ": "m", "wert": { "m2": 815 } },
+        }))
+        .unwrap();
+        match &flst {
+            BvEintrag::Flurstueck(f) => {
+                assert_eq!((f.flur, f.flurstueck.as_str()), (2, "34/5"));
+                assert_eq!(f.groesse, FlurstueckGroesse::Metrisch { m2: Some(815) });
+            }
+            BvEintrag::Recht(_) => panic!("Flurstück als Recht gelesen"),
+        }
+        let json = serde_json::to_value(&flst).unwrap();
+        assert_eq!(bv(json).unwrap(), flst);
+
+        let recht =
+            bv(serde_json::json!({ "lfd_nr": 3, "zu_nr": "1", "text": "Wegerecht" })).unwrap();
+        assert!(matches!(&recht, BvEintrag::Recht(r) if r.zu_nr.text() == "1"));
+        let json = serde_json::to_value(&recht).unwrap();
+        assert_eq!(bv(json).unwrap(), recht);
+        assert!(matches!(
+            bv(serde_json::json!({ "lfd_nr": 4 })).unwrap(),
+            BvEintrag::Recht(_)
+        ));
+
+        for mehrdeutig in [
+            serde_json::json!({ "lfd_nr": 1, "flur": 2, "flurstueck": "3", "text": "Recht" }),
+            serde_json::json!({ "lfd_nr": 1, "flurstueck": "3", "zu_nr": "1" }),
+            serde_json::json!({ "lfd_nr": 1, "flurstueck": "3" }),
+            serde_json::json!({ "lfd_nr": 1, "bezeichnung": "Acker" }),
+        ] {
+            assert!(bv(mehrdeutig.clone()).is_err(), "{mehrdeutig}");
+        }
+    }
+
+    #[test]
+    fn alte_feldnamen_werden_gelesen() {
+        let gb: Grundbuch = serde_json::from_str(
+            r#"{
+                "titelblatt": { "amtsgericht": "Prüfstadt", "grundbuch": "Musterdorf", "blatt": "7" },
+                "abt1": {
+                    "eintraege": [
+                        { "lfd_nr": 1, "eigentümer": "Max Mustermann", "bv_nr": "1" },
+                        { "lfd_nr": 2, "eigentümer": "Erika Mustermann", "version": 2 }
+                    ]
+                }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(gb.titelblatt.grundbuch_von, "Musterdorf");
+        assert_eq!(
+            gb.abt1.eintraege[0].get_eigentuemer().text(),
+            "Max Mustermann"
+        );
+        assert_eq!(
+            gb.abt1.eintraege[1].get_eigentuemer().text(),
+            "Erika Mustermann"
+        );
+
+        let json = serde_json::to_value(&gb).unwrap();
+        let titelblatt = &json["titelblatt"];
+        assert!(titelblatt.get("grundbuch").is_none());
+        assert!(titelblatt
+            .get("grundbuch_von")
+            .or(titelblatt.get("grundbuchVon"))
+            .is_some());
+        assert_eq!(serde_json::from_value::<Grundbuch>(json).unwrap(), gb);
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn yaml_round_trip() {
+        let gb: Grundbuch = serde_json::from_value(serde_json::json!({
+            "titelblatt": { "amtsgericht": "Prüfstadt", "grundbuch_von": "Musterdorf", "blatt": "7" },
+            "bestandsverzeichnis": {
+                "eintraege": [
+                    {
+                        "lfd_nr": 1,
+                        "flur": 2,
+                        "flurstueck": "34/5",
+                        "bezeichnung": "Gebäude- und Freifläche\nHauptstraße 1",
+                        "groesse": { "typ": "ha", "wert": { "ha": 1, "a": 20, "m2": null } },
+                    },
+                    {
+                        "lfd_nr": 2,
+                        "flur": 2,
+                        "flurstueck": "35",
+                        "groesse": { "typ": "m", "wert": { "m2": 815 } },
+                    },
+                ],
+            },
+            "abt2": {
+                "eintraege": [{
+                    "lfd_nr": 1,
+                    "bv_nr": "1, 2",
+                    "text": ["Wegerecht für den", "", "jeweiligen Eigentümer"],
+                }],
+            },
+        }))
+        .unwrap();
+
+        let yaml = gb.to_yaml().unwrap();
+        assert_eq!(Grundbuch::from_yaml(&yaml).unwrap(), gb);
+
+        for groesse in [
+            FlurstueckGroesse::default(),
+            FlurstueckGroesse::Metrisch { m2: Some(1) },
+            FlurstueckGroesse::Hektar {
+                ha: None,
+                a: Some(3),
+                m2: Some(4),
+            },
+        ] {
+            let yaml = serde_yaml::to_string(&groesse).unwrap();
+            assert_eq!(
+                serde_yaml::from_str::<FlurstueckGroesse>(&yaml).unwrap(),
+                groesse
+            );
+        }
+        for text in [
+            StringOrLines::SingleLine("eine Zeile".to_string()),
+            StringOrLines::SingleLine("zwei\nZeilen".to_string()),
+            StringOrLines::MultiLine(vec!["a".to_string(), "b".to_string()]),
+            StringOrLines::MultiLine(Vec::new()),
+        ] {
+            let yaml = serde_yaml::to_string(&text).unwrap();
+            assert_eq!(serde_yaml::from_str::<StringOrLines>(&yaml).unwrap(), text);
+        }
+    }
+
+    #[test]
+    fn get_m2_rechnet_mit_10000_m2_je_hektar() {
+        let groesse = FlurstueckGroesse::Hektar {
+            ha: Some(1),
+            a: Some(20),
+            m2: Some(5),
+        };
+        assert_eq!(groesse.get_m2(), 12_005);
+        assert_eq!(groesse.get_ha_string(), "1");
+        assert_eq!(groesse.get_a_string(), "20");
+        assert_eq!(groesse.get_m2_string(), "05");
+        assert_eq!(groesse.anzeige(GroesseFormat::Kompakt), "1,2005 ha");
+        assert_eq!(
+            FlurstueckGroesse::Metrisch { m2: Some(12_005) }.get_m2(),
+            groesse.get_m2()
+        );
+    }
+
+    fn beispiel_grundbuch() -> Grundbuch {
+        serde_json::from_value(serde_json::json!({
+            "titelblatt": { "amtsgericht": "Prüfstadt", "grundbuch_von": "Musterdorf", "blatt": "7" },
+            "abt2": {
+                "eintraege": [
+                    { "lfd_nr": 1, "bv_nr": "1", "text": "Wegerecht" },
+                    { "lfd_nr": 2, "bv_nr": "1", "text": "Leitungsrecht" },
+                    { "lfd_nr": 3, "bv_nr": "1", "text": "Wohnrecht" },
+                ],
+                "loeschungen": [{ "lfd_nr": "3", "text": "Gelöscht" }],
+            },
+            "abt3": {
+                "eintraege": [
+                    { "lfd_nr": 1, "bv_nr": "1", "betrag": "10.000 EUR", "text": "Grundschuld" },
+                ],
+            },
+        }))
+        .unwrap()
+    }
+
+    fn assert_delta_rekonstruiert(basis: &Grundbuch, neu: &Grundbuch) {
+        let delta = neu.to_delta(basis);
+        assert_eq!(&delta.apply_to(basis).unwrap(), neu);
+
+        let json = serde_json::to_string(&delta).unwrap();
+        let gelesen: GrundbuchDelta = serde_json::from_str(&json).unwrap();
+        assert_eq!(&gelesen.apply_to(basis).unwrap(), neu);
+    }
+
+    #[test]
+    fn delta_auf_basis_ergibt_neue_version() {
+        let basis = beispiel_grundbuch();
+        let abt2 = |lfd_nr, bv_nr: &str, text: &str| Abt2Eintrag {
+            lfd_nr,
+            bv_nr: bv_nr.to_string().into(),
+            text: text.to_string().into(),
+            ..basis.abt2.eintraege[0].clone()
+        };
+        assert!(basis.to_delta(&basis).is_empty());
+        assert_delta_rekonstruiert(&basis, &basis);
+
+        let mut geaendert = basis.clone();
+        geaendert.abt2.eintraege[1].text = "Leitungsrecht für Stadtwerke".to_string().into();
+        geaendert.abt2.eintraege[2].manuell_geroetet = Some(true);
+        assert_delta_rekonstruiert(&basis, &geaendert);
+
+        let mut eingefuegt = basis.clone();
+        eingefuegt
+            .abt2
+            .eintraege
+            .insert(1, abt2(4, "2", "Vorkaufsrecht"));
+        eingefuegt.abt3.eintraege.push(Abt3Eintrag {
+            lfd_nr: 2,
+            betrag: "5.000 EUR".to_string().into(),
+            text: "Hypothek".to_string().into(),
+            ..basis.abt3.eintraege[0].clone()
+        });
+        assert_delta_rekonstruiert(&basis, &eingefuegt);
+
+        let mut entfernt = basis.clone();
+        entfernt.abt2.eintraege.remove(0);
+        entfernt.abt2.loeschungen.clear();
+        entfernt.abt3.eintraege.clear();
+        assert_delta_rekonstruiert(&basis, &entfernt);
+
+        let mut umsortiert = basis.clone();
+        umsortiert.abt2.eintraege.reverse();
+        assert_delta_rekonstruiert(&basis, &umsortiert);
+
+        let mut gemischt = umsortiert.clone();
+        gemischt.abt2.eintraege.remove(1);
+        gemischt.abt2.eintraege.push(abt2(5, "3", "Nießbrauch"));
+        gemischt.abt3.eintraege[0].betrag = "12.000 EUR".to_string().into();
+        assert_delta_rekonstruiert(&basis, &gemischt);
+        assert_delta_rekonstruiert(&gemischt, &basis);
+    }
+
+    #[test]
+    fn kanonisieren_ist_idempotent() {
+        let mut gb: Grundbuch = serde_json::from_value(serde_json::json!({
+            "titelblatt": { "amtsgericht": "", "grundbuch_von": "", "blatt": "" },
+            "abt2": {
+                "eintraege": [
+                    { "lfd_nr": 3, "bv_nr": "1", "text": "Vorkaufsrecht" },
+                    { "lfd_nr": 2, "bv_nr": " 1 ", "text": "  Wegerecht\n\n" },
+                    { "lfd_nr": 1, "text": "" },
+                    { "lfd_nr": 0, "text": " " },
+                ],
+                "loeschungen": [{}],
+            },
+            "abt3": { "eintraege": [{ "lfd_nr": 1 }] },
+        }))
+        .unwrap();
+
+        gb.kanonisieren();
+        let nummern = gb
+            .abt2
+            .eintraege
+            .iter()
+            .map(|e| e.lfd_nr)
+            .collect::<Vec<_>>();
+        assert_eq!(nummern, vec![1, 2, 3]);
+        assert_eq!(gb.abt3.eintraege.len(), 1);
+        assert!(gb.abt2.loeschungen.is_empty());
+        assert_eq!(gb.abt2.eintraege[1].text.text(), "Wegerecht");
+        assert_eq!(gb.abt2.eintraege[1].bv_nr.text(), "1");
+
+        let einmal = gb.clone();
+        gb.kanonisieren();
+        assert_eq!(gb, einmal);
+    }
+
+    #[test]
+    fn string_or_lines_zeilenenden_einheitlich() {
+        for ende in ["\r\n", "\n", "\r"] {
+            let text = format!("Erste Zeile{ende}Zweite Zeile{ende}");
+            let varianten = [
+                StringOrLines::from(text.clone()),
+                StringOrLines::SingleLine(text.clone()),
+                serde_json::from_value(serde_json::json!(text)).unwrap(),
+                serde_json::from_value(serde_json::json!([text])).unwrap(),
+                serde_json::from_value(serde_json::json!(["Erste Zeile", "Zweite Zeile"])).unwrap(),
+            ];
+            for v in varianten {
+                assert_eq!(v.lines(), vec!["Erste Zeile", "Zweite Zeile"], "{v:?}");
+                assert_eq!(v.text(), "Erste Zeile\r\nZweite Zeile");
+                assert_eq!(v.text_mit("\n"), "Erste Zeile\nZweite Zeile");
+            }
+        }
+
+        let gelesen: StringOrLines =
+            serde_json::from_value(serde_json::json!("a\r\nb\rc")).unwrap();
+        assert_eq!(gelesen, StringOrLines::SingleLine("a\nb\nc".to_string()));
+        assert!(StringOrLines::from(String::new()).lines().is_empty());
+    }
+
+    #[test]
+    fn abt1_eintrag_variante_nach_version() {
+        let v1: Abt1Eintrag = serde_json::from_value(serde_json::json!({
+            "lfd_nr": 1,
+            "eigentuemer": "Max Mustermann",
+            "bv_nr": "1, 2",
+        }))
+        .unwrap();
+        assert!(matches!(&v1, Abt1Eintrag::V1(e) if e.bv_nr.text() == "1, 2"));
+
+        let v1: Abt1Eintrag =
+            serde_json::from_value(serde_json::json!({ "lfd_nr": "2", "version": 1 })).unwrap();
+        assert!(matches!(
+            v1,
+            Abt1Eintrag::V1(Abt1EintragV1 { lfd_nr: 2, .. })
+        ));
+
+        let v2: Abt1Eintrag = serde_json::from_value(serde_json::json!({
+            "lfd_nr": 3,
+            "eigentuemer": "Erika Mustermann",
+            "version": 2,
+        }))
+        .unwrap();
+        assert!(matches!(
+            v2,
+            Abt1Eintrag::V2(Abt1EintragV2 {
+                lfd_nr: 3,
+                version: 2,
+                ..
+            })
+        ));
+        let json = serde_json::to_value(&v2).unwrap();
+        assert_eq!(serde_json::from_value::<Abt1Eintrag>(json).unwrap(), v2);
+    }
+
+    #[test]
+    fn abt1_eintrag_lehnt_widerspruechliche_eingaben_ab() {
+        for json in [
+            serde_json::json!({ "lfd_nr": 1, "version": 2, "bv_nr": "1" }),
+            serde_json::json!({ "lfd_nr": 1, "version": 2, "grundlage_der_eintragung": "" }),
+            serde_json::json!({ "lfd_nr": 1, "version": 0 }),
+            #[cfg(not(feature = "skip-zero-lfd-nr"))]
+            serde_json::json!({}),
+            #[cfg(not(feature = "skip-zero-lfd-nr"))]
+            serde_json::json!({ "eigentuemer": "Max Mustermann" }),
+        ] {
+            assert!(
+                serde_json::from_value::<Abt1Eintrag>(json.clone()).is_err(),
+                "{json}"
+            );
+        }
+    }
+
+    #[test]
+    fn rect_liest_objekt_und_array() {
+        let erwartet = rect(1.0, 2.5, 3.0, 4.0);
+        for json in [
+            serde_json::json!([1.0, 2.5, 3, 4.0]),
+            serde_json::json!({ "min_x": 1, "min_y": 2.5, "max_x": 3.0, "max_y": 4.0 }),
+            serde_json::json!({ "minX": 1, "minY": 2.5, "maxX": 3.0, "maxY": 4.0, "z": "x" }),
+        ] {
+            assert_eq!(serde_json::from_value::<Rect>(json).unwrap(), erwartet);
+        }
+        let fehler = serde_json::from_value::<Rect>(serde_json::json!({ "max_x": 3.0 }));
+        assert!(fehler
+            .unwrap_err()
+            .to_string()
+            .contains("missing field `min_x`"));
+        for json in [
+            serde_json::json!([1.0, 2.0, 3.0]),
+            serde_json::json!([1.0, 2.0, 3.0, 4.0, 5.0]),
+            serde_json::json!("1 2 3 4"),
+        ] {
+            assert!(serde_json::from_value::<Rect>(json).is_err());
+        }
+
+        let json = serde_json::to_value(&erwartet).unwrap();
+        assert_eq!(json.is_array(), cfg!(feature = "rect-as-array"));
+        assert_eq!(serde_json::from_value::<Rect>(json).unwrap(), erwartet);
+    }
+
+    fn rect(min_x: f32, min_y: f32, max_x: f32, max_y: f32) -> Rect {
+        Rect {
+            min_x,
+            min_y,
+            max_x,
+            max_y,
+        }
+    }
+
+    fn testseite() -> HocrSeite {
+        let mut seite = HocrSeite::from_pixel_dpi(1000, 1400, 300.0);
+        let bounds = rect(100.0, 200.0, 300.0, 240.0);
+        seite.parsed.careas.push(HocrArea {
+            bounds: bounds.clone(),
+            paragraphs: vec![HocrParagraph {
+                bounds: bounds.clone(),
+                lines: vec![HocrLine {
+                    bounds: bounds.clone(),
+                    words: vec![HocrWord {
+                        bounds: rect(100.0, 200.0, 180.0, 240.0),
+                        confidence: 0.9,
+                        text: "Wegerecht".to_string(),
+                    }],
+                }],
+            }],
+        });
+        seite.rote_linien.push(Linie {
+            punkte: vec![Punkt { x: 10.0, y: 20.0 }, Punkt { x: 50.5, y: 20.0 }],
+        });
+        seite
+    }
+
+    /// Alle Koordinaten der Seite (Maße, hOCR-Bounds, Punkte der roten Linien)
+    fn koordinaten(seite: &HocrSeite) -> Vec<f32> {
+        let mut k = vec![seite.breite_mm, seite.hoehe_mm];
+        let mut rects = vec![&seite.parsed.bounds];
+        for a in seite.parsed.careas.iter() {
+            rects.push(&a.bounds);
+            for p in a.paragraphs.iter() {
+                rects.push(&p.bounds);
+                for l in p.lines.iter() {
+                    rects.push(&l.bounds);
+                    rects.extend(l.words.iter().map(|w| &w.bounds));
+                }
+            }
+        }
+        for r in rects {
+            k.extend([r.min_x, r.min_y, r.max_x, r.max_y]);
+        }
+        for p in seite.rote_linien.iter().flat_map(|l| l.punkte.iter()) {
+            k.extend([p.x, p.y]);
+        }
+        k
+    }
+
+    fn assert_koordinaten_gleich(a: &HocrSeite, b: &HocrSeite) {
+        let (a, b) = (koordinaten(a), koordinaten(b));
+        assert_eq!(a.len(), b.len());
+        for (x, y) in a.iter().zip(b.iter()) {
+            assert!((x - y).abs() < 1e-3, "{a:?} != {b:?}");
+        }
+    }
+
+    #[test]
+    fn rotation_und_spiegelung_sind_umkehrbar() {
+        let original = testseite();
+
+        let mut seite = original.clone();
+        seite.rotiere(Rotation::Grad180);
+        seite.rotiere(Rotation::Grad180);
+        assert_koordinaten_gleich(&seite, &original);
+
+        let mut seite = original.clone();
+        seite.rotiere(Rotation::Grad90);
+        assert_eq!(seite.parsed.bounds, rect(0.0, 0.0, 1400.0, 1000.0));
+        assert_eq!(
+            seite.parsed.careas[0].paragraphs[0].lines[0].words[0].bounds,
+            rect(1160.0, 100.0, 1200.0, 180.0)
+        );
+        seite.rotiere(Rotation::Grad270);
+        assert_koordinaten_gleich(&seite, &original);
+
+        let mut seite = original.clone();
+        seite.spiegele_horizontal();
+        assert_eq!(
+            seite.parsed.careas[0].bounds,
+            rect(700.0, 200.0, 900.0, 240.0)
+        );
+        seite.spiegele_horizontal();
+        assert_koordinaten_gleich(&seite, &original);
+    }
+
+    fn leeres_grundbuch() -> Grundbuch {
+        Grundbuch {
+            titelblatt: Titelblatt::default(),
+            bestandsverzeichnis: Bestandsverzeichnis::default(),
+            abt1: Abteilung1::default(),
+            abt2: Abteilung2::default(),
+            abt3: Abteilung3::default(),
+        }
+    }
+
+    #[test]
+    fn setze_texte_schreibt_bearbeitete_texte_zurueck() {
+        let mut gb = leeres_grundbuch();
+        gb.abt2.eintraege.push(Abt2Eintrag::new(1, "1", "Erster"));
+        gb.abt2.eintraege.push(Abt2Eintrag::new(2, "1", "Zweiter"));
+
+        let mut texte = gb.extrahiere_texte();
+        for t in texte.iter_mut() {
+            if t.text == "Erster" {
+                t.text = "Erster korrigiert".to_string();
+            }
+        }
+        gb.setze_texte(&texte).unwrap();
+
+        assert_eq!(gb.abt2.eintraege[0].text.text(), "Erster korrigiert");
+        assert_eq!(gb.abt2.eintraege[1].text.text(), "Zweiter");
+    }
+
+    #[test]
+    fn setze_texte_lehnt_verschobene_pfade_ab() {
+        let mut gb = leeres_grundbuch();
+        gb.abt2.eintraege.push(Abt2Eintrag::new(1, "1", "Erster"));
+        gb.abt2.eintraege.push(Abt2Eintrag::new(2, "1", "Zweiter"));
+
+        let mut texte = gb.extrahiere_texte();
+        for t in texte.iter_mut() {
+            if t.text == "Erster" {
+                t.text = "Erster korrigiert".to_string();
+            }
+        }
+        gb.abt2.eintraege.remove(0);
+        let vorher = gb.clone();
+
+        assert!(matches!(
+            gb.setze_texte(&texte),
+            Err(PatchError::EintragGeaendert(_))
+        ));
+        assert_eq!(gb, vorher);
+    }
+
+    fn abt2_eintrag_mit_zusatz(lfd_nr: usize, zusatz: &str, text: &str) -> Abt2Eintrag {
+        Abt2Eintrag {
+            lfd_nr_zusatz: Some(zusatz.to_string()),
+            ..Abt2Eintrag::new(lfd_nr, "1", text)
+        }
+    }
+
+    #[test]
+    fn laufende_nummern_unterscheiden_zusatz() {
+        assert_eq!(
+            parse_laufende_nummern("1a, 2 u. 4-6"),
+            vec![
+                LaufendeNummer::mit_zusatz(1, Some("a")),
+                LaufendeNummer::from(2),
+                LaufendeNummer::from(4),
+                LaufendeNummer::from(5),
+                LaufendeNummer::from(6),
+            ]
+        );
+        assert_eq!(
+            parse_laufende_nummern("1 bis 3"),
+            (1..=3).map(LaufendeNummer::from).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            LaufendeNummer::parse("1A").unwrap(),
+            LaufendeNummer::parse("1a").unwrap()
+        );
+        assert_eq!(LaufendeNummer::parse("1a").unwrap().to_string(), "1a");
+        assert!(LaufendeNummer::from(1) < LaufendeNummer::mit_zusatz(1, Some("a")));
+        assert!(LaufendeNummer::mit_zusatz(1, Some("b")) < LaufendeNummer::from(2));
+    }
+
+    #[test]
+    fn loeschung_mit_zusatz_betrifft_nur_diesen_eintrag() {
+        let mut gb = leeres_grundbuch();
+        gb.abt2
+            .eintraege
+            .push(Abt2Eintrag::new(1, "1", "Stammrecht"));
+        gb.abt2
+            .eintraege
+            .push(abt2_eintrag_mit_zusatz(1, "a", "Abgeleitetes Recht"));
+        gb.abt2.loeschungen.push(Abt2Loeschung {
+            lfd_nr: "1a".into(),
+            text: "Gelöscht am 1.2.2003".into(),
+            ..Default::default()
+        });
+
+        let historie = gb.abt2.historie(LaufendeNummer::parse("1a").unwrap());
+        assert_eq!(historie.eintrag.unwrap().text.text(), "Abgeleitetes Recht");
+        assert_eq!(historie.loeschungen.len(), 1);
+        assert!(gb.abt2.historie(1).loeschungen.is_empty());
+
+        assert_eq!(gb.abt2.wende_loeschungen_an(), 1);
+        assert!(!gb.abt2.eintraege[0].ist_geroetet());
+        assert!(gb.abt2.eintraege[1].ist_geroetet());
+    }
+
+    #[test]
+    fn roetung_propagiert_nur_auf_gleiche_laufende_nummer() {
+        let mut gb = leeres_grundbuch();
+        gb.abt2
+            .eintraege
+            .push(Abt2Eintrag::new(1, "1", "Stammrecht"));
+        let mut abgeleitet = abt2_eintrag_mit_zusatz(1, "a", "Abgeleitetes Recht");
+        abgeleitet.manuell_geroetet = Some(true);
+        gb.abt2.eintraege.push(abgeleitet);
+        for nr in ["1", "1a"] {
+            gb.abt2.veraenderungen.push(Abt2Veraenderung {
+                lfd_nr: nr.into(),
+                text: "Geändert".into(),
+                ..Default::default()
+            });
+        }
+
+        assert_eq!(
+            gb.abt2
+                .propagiere_roetung(RoetungPropagationOptions::default()),
+            1
+        );
+        assert!(!gb.abt2.veraenderungen[0].ist_geroetet());
+        assert!(gb.abt2.veraenderungen[1].ist_geroetet());
+    }
+
+    #[test]
+    fn insert_und_verschieben_beachten_zusatz() {
+        let mut gb = leeres_grundbuch();
+        gb.abt2.insert_eintrag(Abt2Eintrag::new(2, "1", "Zwei"));
+        gb.abt2
+            .insert_eintrag(abt2_eintrag_mit_zusatz(1, "a", "Eins a"));
+        gb.abt2.insert_eintrag(Abt2Eintrag::new(1, "1", "Eins"));
+        let reihenfolge = gb
+            .abt2
+            .eintraege
+            .iter()
+            .map(|e| e.lfd_nr_mit_zusatz())
+            .collect::<Vec<_>>();
+        assert_eq!(reihenfolge, vec!["1", "1a", "2"]);
+
+        assert!(matches!(
+            gb.verschiebe_eintrag(
+                Abteilungsbereich::Abt2,
+                LaufendeNummer::parse("1a").unwrap(),
+                Abteilungsbereich::Abt3
+            ),
+            Err(VerschiebeFehler::ZusatzNichtUebertragbar { .. })
+        ));
+        gb.verschiebe_eintrag(Abteilungsbereich::Abt2, 1, Abteilungsbereich::Abt3)
+            .unwrap();
+        assert_eq!(gb.abt3.eintraege[0].text.text(), "Eins");
+        assert_eq!(gb.abt2.eintraege[0].lfd_nr_mit_zusatz(), "1a");
+    }
+
+    #[test]
+    fn dedupe_zu_abschreibungen_behaelt_roetung() {
+        let zuschreibung = |automatisch_geroetet, manuell_geroetet| BvZuschreibung {
+            bv_nr: "1".into(),
+            text: "Von Blatt 12 hierher übertragen".into(),
+            automatisch_geroetet,
+            manuell_geroetet,
+            ..Default::default()
+        };
+        for (a, b) in [
+            ((Some(true), None), (None, Some(false))),
+            ((None, Some(false)), (Some(true), None)),
+            ((None, None), (None, Some(true))),
+        ] {
+            let mut bv = Bestandsverzeichnis::default();
+            bv.zuschreibungen.push(zuschreibung(a.0, a.1));
+            bv.zuschreibungen.push(zuschreibung(b.0, b.1));
+            bv.dedupe_zu_abschreibungen();
+            assert_eq!(bv.zuschreibungen.len(), 1);
+            assert!(bv.zuschreibungen[0].ist_geroetet(), "{a:?} + {b:?}");
+        }
+
+        let mut bv = Bestandsverzeichnis::default();
+        bv.abschreibungen.push(BvAbschreibung {
+            bv_nr: "2".into(),
+            text: "Nach Blatt 7 übertragen".into(),
+            ..Default::default()
+        });
+        bv.abschreibungen.push(BvAbschreibung {
+            bv_nr: "2".into(),
+            text: "Nach Blatt 7 übertragen".into(),
+            manuell_geroetet: Some(false),
+            ..Default::default()
+        });
+        bv.dedupe_zu_abschreibungen();
+        assert_eq!(bv.abschreibungen.len(), 1);
+        assert!(!bv.abschreibungen[0].ist_geroetet());
+        assert_eq!(bv.abschreibungen[0].manuell_geroetet, Some(false));
+    }
+
+    #[test]
+    fn leere_werte_werden_weggelassen() {
+        let json = serde_json::to_value(leeres_grundbuch()).unwrap();
+        assert_eq!(json.as_object().unwrap().len(), 1);
+        assert!(json.get("titelblatt").is_some());
+
+        let mut eintrag = Abt2Eintrag::new(1, "1", "Wegerecht");
+        eintrag.position_in_pdf = Some(PositionInPdf::default());
+        let json = serde_json::to_value(&eintrag).unwrap();
+        assert!(json.get("position_in_pdf").is_none());
+        assert!(json.get("positionInPdf").is_none());
+
+        let punkt = serde_json::to_value(Punkt { x: 0.0, y: 5.0 }).unwrap();
+        assert_eq!(punkt.as_object().unwrap().len(), 1);
+        let gelesen: Punkt = serde_json::from_value(punkt).unwrap();
+        assert_eq!((gelesen.x, gelesen.y), (0.0, 5.0));
+
+        let hocr = serde_json::to_value(ParsedHocr::default()).unwrap();
+        assert!(hocr.get("bounds").is_none());
+
+        // Koordinaten eines nicht leeren Rechtecks sind Pflichtfelder und bleiben erhalten
+        let rect = Rect {
+            min_x: 0.0,
+            min_y: 0.0,
+            max_x: 10.0,
+            max_y: 0.0,
+        };
+        let json = serde_json::to_value(&rect).unwrap();
+        assert_eq!(serde_json::from_value::<Rect>(json).unwrap(), rect);
+    }
+
+    #[test]
+    fn lfd_nr_null_wird_nur_mit_feature_weggelassen() {
+        let json = serde_json::to_value(Abt2Eintrag::new(0, "1", "Wegerecht")).unwrap();
+        let lfd_nr = json.get("lfd_nr").or_else(|| json.get("lfdNr"));
+        if cfg!(feature = "skip-zero-lfd-nr") {
+            assert!(lfd_nr.is_none());
+        } else {
+            assert_eq!(lfd_nr, Some(&serde_json::json!(0)));
+        }
+        let json = serde_json::to_value(Abt3Eintrag::new(0, "1", "", "Grundschuld")).unwrap();
+        assert_eq!(
+            json.get("lfd_nr").or_else(|| json.get("lfdNr")).is_some(),
+            !cfg!(feature = "skip-zero-lfd-nr")
+        );
+
+        // ohne das Feature ist eine fehlende lfd. Nr. ein Fehler
+        let flurstueck = serde_json::json!({ "flur": 2, "flurstueck": "34/5" });
+        let text = serde_json::json!({ "bv_nr": "1", "text": "Wegerecht" });
+        let fehler = [
+            serde_json::from_value::<BvEintrag>(flurstueck.clone()).err(),
+            serde_json::from_value::<BvEintragFlurstueck>(flurstueck).err(),
+            serde_json::from_value::<BvEintragRecht>(text.clone()).err(),
+            serde_json::from_value::<Abt2Eintrag>(text.clone()).err(),
+            serde_json::from_value::<Abt3Eintrag>(text).err(),
+            serde_json::from_value::<Abt1EintragV1>(serde_json::json!({ "eigentuemer": "A" }))
+                .err(),
+            serde_json::from_value::<Abt1EintragV2>(serde_json::json!({ "version": 2 })).err(),
+        ];
+        for f in fehler {
+            if cfg!(feature = "skip-zero-lfd-nr") {
+                assert!(f.is_none(), "{f:?}");
+            } else {
+                let f = f.unwrap().to_string();
+                assert!(f.contains("lfd_nr"), "{f}");
+            }
+        }
+    }
+
+    #[cfg(feature = "schema")]
+    #[test]
+    fn rect_schema_beschreibt_beide_formate() {
+        let schema = serde_json::to_value(schemars::schema_for!(Rect)).unwrap();
+        let varianten = schema["anyOf"].as_array().unwrap();
+        assert_eq!(varianten.len(), 2);
+        assert_eq!(varianten[0]["type"], "object");
+        assert_eq!(varianten[1]["type"], "array");
+    }
+
+    #[test]
+    fn suchtext_normalisierung() {
+        let norm = normalisiere_suchtext;
+        assert_eq!(norm("Königstein"), "koenigstein");
+        assert_eq!(norm("koenigstein"), "koenigstein");
+        assert_eq!(norm("Ko\u{308}nigstein"), "koenigstein");
+        assert_eq!(norm("A\u{308}U\u{308}o\u{308}"), "aeueoe");
+        assert_eq!(norm("Straße"), "strasse");
+        assert_eq!(norm("GROẞE Straße"), "grosse strasse");
+        assert_eq!(norm("MüHlEnWeG"), "muehlenweg");
+        assert_eq!(
+            norm("  Bad \t Homburg\n v. d. Höhe "),
+            "bad homburg v. d. hoehe"
+        );
+        assert_eq!(norm(" \n "), "");
+
+        let titelblatt = Titelblatt {
+            amtsgericht: "Königstein".to_string(),
+            grundbuch_von: "Mühlheim".to_string(),
+            blatt: "12".to_string(),
+        };
+        assert!(titelblatt.passt_zu_suche("KOENIGSTEIN"));
+        assert!(titelblatt.passt_zu_suche("Mu\u{308}hl"));
+        assert!(!titelblatt.passt_zu_suche("Kronberg"));
+
+        let mut gb = beispiel_grundbuch();
+        gb.titelblatt = titelblatt.clone();
+        gb.abt2.eintraege[0].text = "Wegerecht für Straßenbau".into();
+        assert_eq!(gb.suche_eintraege("STRASSENBAU").len(), 1);
+        assert_eq!(gb.suche_eintraege("fuer  strassenbau").len(), 1);
+
+        // leerer Suchtext passt überall
+        assert!(titelblatt.passt_zu_suche("  "));
+        assert_eq!(
+            gb.suche_eintraege("  ").len(),
+            gb.alle_eintraege_mit_pfad().count()
+        );
+    }
+
+    fn hocr_mit_confidences(werte: &[f32]) -> serde_json::Value {
+        let worte = werte
+            .iter()
+            .map(|c| serde_json::json!({ "bounds": [0, 0, 1, 1], "confidence": c, "text": "x" }))
+            .collect::<Vec<_>>();
+        serde_json::json!({
+            "careas": [{ "paragraphs": [{ "lines": [{ "bounds": [0, 0, 1, 1], "words": worte }] }] }]
+        })
+    }
+
+    fn confidences(hocr: &ParsedHocr) -> Vec<f32> {
+        hocr.careas[0].paragraphs[0].lines[0]
+            .words
+            .iter()
+            .map(|w| w.confidence)
+            .collect()
+    }
+
+    #[test]
+    fn confidence_wird_beim_einlesen_normalisiert() {
+        let prozent: ParsedHocr =
+            serde_json::from_value(hocr_mit_confidences(&[96.0, 50.0, 0.5, 150.0])).unwrap();
+        assert_eq!(confidences(&prozent), vec![0.96, 0.5, 0.5, 1.0]);
+
+        let bruch: ParsedHocr =
+            serde_json::from_value(hocr_mit_confidences(&[0.96, 1.0, 0.5, -0.1])).unwrap();
+        assert_eq!(confidences(&bruch), vec![0.96, 1.0, 0.5, 0.0]);
+        assert_eq!(
+            bruch.confidence_skala_erkennen(),
+            Some(ConfidenceSkala::Bruch)
+        );
+        assert_eq!(confidence_normalize(serde_json::json!(25)).unwrap(), 0.25);
+
+        // im Speicher aufgebaute Seite mit Prozentwerten
+        let mut roh = ParsedHocr::default();
+        assert_eq!(roh.normalisiere_confidence(), None);
+        roh.careas = bruch.careas.clone();
+        for (w, c) in roh.careas[0].paragraphs[0].lines[0]
+            .words
+            .iter_mut()
+            .zip([50.0, 100.0, 1.0, 0.0])
+        {
+            w.confidence = c;
+        }
+        assert_eq!(
+            roh.confidence_skala_erkennen(),
+            Some(ConfidenceSkala::Prozent)
+        );
+        assert_eq!(
+            roh.normalisiere_confidence(),
+            Some(ConfidenceSkala::Prozent)
+        );
+        assert_eq!(confidences(&roh), vec![0.5, 1.0, 0.01, 0.0]);
+    }
+
+    fn hocr_zeile(y: f32, text: &str) -> HocrLine {
+        let bounds = rect(10.0, y, 500.0, y + 10.0);
+        HocrLine {
+            bounds: bounds.clone(),
+            words: text
+                .split_whitespace()
+                .map(|w| HocrWord {
+                    bounds: bounds.clone(),
+                    confidence: 0.9,
+                    text: w.to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    fn hocr_seite(zeilen: &[&str]) -> HocrSeite {
+        let mut seite = HocrSeite::from_pixel_dpi(1000, 1000, 25.4);
+        seite.parsed.careas.push(HocrArea {
+            bounds: rect(0.0, 0.0, 1000.0, 1000.0),
+            paragraphs: vec![HocrParagraph {
+                bounds: rect(0.0, 0.0, 1000.0, 1000.0),
+                lines: zeilen
+                    .iter()
+                    .enumerate()
+                    .map(|(i, t)| hocr_zeile(i as f32 * 10.0, t))
+                    .collect(),
+            }],
+        });
+        seite
+    }
+
+    #[test]
+    fn iter_worte_folgt_der_seitennummer() {
+        let mut hocr = HocrLayout::default();
+        hocr.seiten
+            .insert("10".to_string(), hocr_seite(&["zehn", "zehn b"]));
+        hocr.seiten.insert("2".to_string(), hocr_seite(&["zwei"]));
+        hocr.seiten.insert("x".to_string(), hocr_seite(&["ohne"]));
+
+        let worte = hocr
+            .iter_worte()
+            .map(|w| (w.seite, w.wort.text.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            worte,
+            vec![
+                ("2", "zwei"),
+                ("10", "zehn"),
+                ("10", "zehn"),
+                ("10", "b"),
+                ("x", "ohne")
+            ]
+        );
+    }
+
+    #[test]
+    fn verorte_eintraege_findet_eindeutige_fundstellen() {
+        let mut hocr = HocrLayout::default();
+        hocr.seiten.insert(
+            "1".to_string(),
+            hocr_seite(&[
+                "1 Wegerecht für den jewei-",
+                "ligen   Eigentümer von Flur 3",
+                "",
+                "2 LEITUNGSRECHT Leitungsrecht",
+                "Wohnungsrecht der Eltern",
+            ]),
+        );
+        hocr.seiten
+            .insert("2".to_string(), hocr_seite(&["Wohnungsrecht der Eltern"]));
+
+        let mut gb = leeres_grundbuch();
+        for (nr, text) in [
+            (1, "Wegerecht für den\njeweiligen Eigentümer"),
+            (2, "Leitungsrecht"),
+            (3, "Vorkaufsrecht"),
+            (4, "Wohnungsrecht der Eltern"),
+        ] {
+            gb.abt2.eintraege.push(Abt2Eintrag::new(nr, "1", text));
+        }
+
+        let mut nur_eine_zeile = gb.clone();
+        let opts = VerortungsOptions {
+            max_zeilen: 1,
+            ..Default::default()
+        };
+        assert_eq!(nur_eine_zeile.verorte_eintraege(&hocr, opts), 1);
+        assert!(nur_eine_zeile.abt2.eintraege[0].position_in_pdf.is_none());
+
+        assert_eq!(gb.verorte_eintraege(&hocr, VerortungsOptions::default()), 2);
+        let pos = gb.abt2.eintraege[0].position_in_pdf.clone().unwrap();
+        assert_eq!(pos.seite, "1");
+        assert_eq!(pos.rect, rect(10.0, 0.0, 500.0, 20.0));
+        let pos = gb.abt2.eintraege[1].position_in_pdf.clone().unwrap();
+        assert_eq!(pos.rect, rect(10.0, 30.0, 500.0, 40.0));
+        assert!(gb.abt2.eintraege[2].position_in_pdf.is_none());
+        assert!(gb.abt2.eintraege[3].position_in_pdf.is_none());
+    }
+
+    /// Minimaler Validator für die im Schema verwendeten JSON-Schema-Schlüsselwörter
+    #[cfg(feature = "schema")]
+    fn schema_passt(
+        root: &serde_json::Value,
+        schema: &serde_json::Value,
+        wert: &serde_json::Value,
+    ) -> bool {
+        use serde_json::Value;
+
+        let schema = match schema {
+            Value::Bool(b) => return *b,
+            Value::Object(o) => o,
+            _ => return true,
+        };
+        if let Some(r) = schema.get("$ref").and_then(|r| r.as_str()) {
+            let name = r.trim_start_matches("#/definitions/");
+            return schema_passt(root, &root["definitions"][name], wert);
+        }
+        if let Some(typ) = schema.get("type") {
+            let typen = match typ {
+                Value::Array(a) => a.iter().filter_map(|t| t.as_str()).collect(),
+                t => vec![t.as_str().unwrap_or_default()],
+            };
+            let typ_passt = typen.iter().any(|t| match *t {
+                "null" => wert.is_null(),
+                "boolean" => wert.is_boolean(),
+                "string" => wert.is_string(),
+                "array" => wert.is_array(),
+                "object" => wert.is_object(),
+                "number" => wert.is_number(),
+                "integer" => wert.as_f64().is_some_and(|f| f.fract() == 0.0),
+                _ => false,
+            });
+            if !typ_passt {
+                return false;
+            }
+        }
+        if let Some(werte) = schema.get("enum").and_then(|e| e.as_array()) {
+            if !werte
+                .iter()
+                .any(|w| w == wert || (w.is_number() && w.as_f64() == wert.as_f64()))
+            {
+                return false;
+            }
+        }
+        if let (Some(min), Some(f)) = (
+            schema.get("minimum").and_then(|m| m.as_f64()),
+            wert.as_f64(),
+        ) {
+            if f < min {
+                return false;
+            }
+        }
+        if let (Some(muster), Some(s)) = (
+            schema.get("pattern").and_then(|p| p.as_str()),
+            wert.as_str(),
+        ) {
+            if !regex::Regex::new(muster).unwrap().is_match(s) {
+                return false;
+            }
+        }
+        if let Some(objekt) = wert.as_object() {
+            let pflicht = schema.get("required").and_then(|r| r.as_array());
+            if !pflicht
+                .into_iter()
+                .flatten()
+                .all(|f| objekt.contains_key(f.as_str().unwrap_or_default()))
+            {
+                return false;
+            }
+            let felder = schema.get("properties").and_then(|p| p.as_object());
+            for (k, v) in objekt {
+                let feld_schema = felder
+                    .and_then(|f| f.get(k))
+                    .or_else(|| schema.get("additionalProperties"));
+                if !feld_schema.is_none_or(|s| schema_passt(root, s, v)) {
+                    return false;
+                }
+            }
+        }
+        if let Some(liste) = wert.as_array() {
+            let min = schema.get("minItems").and_then(|n| n.as_u64()).unwrap_or(0);
+            let max = schema
+                .get("maxItems")
+                .and_then(|n| n.as_u64())
+                .unwrap_or(u64::MAX);
+            if (liste.len() as u64) < min || liste.len() as u64 > max {
+                return false;
+            }
+            let elemente_passen = match schema.get("items") {
+                Some(Value::Array(tupel)) => liste
+                    .iter()
+                    .zip(tupel)
+                    .all(|(w, s)| schema_passt(root, s, w)),
+                Some(s) => liste.iter().all(|w| schema_passt(root, s, w)),
+                None => true,
+            };
+            if !elemente_passen {
+                return false;
+            }
+        }
+        let liste = |name: &str| schema.get(name).and_then(|l| l.as_array());
+        if let Some(alle) = liste("allOf") {
+            if !alle.iter().all(|s| schema_passt(root, s, wert)) {
+                return false;
+            }
+        }
+        if let Some(eins) = liste("anyOf") {
+            if !eins.iter().any(|s| schema_passt(root, s, wert)) {
+                return false;
+            }
+        }
+        if let Some(genau_eins) = liste("oneOf") {
+            if genau_eins
+                .iter()
+                .filter(|s| schema_passt(root, s, wert))
+                .count()
+                != 1
+            {
+                return false;
+            }
+        }
+        if let Some(nicht) = schema.get("not") {
+            if schema_passt(root, nicht, wert) {
+                return false;
+            }
+        }
+        true
+    }
+
+    #[cfg(feature = "schema")]
+    #[test]
+    fn json_schema_akzeptiert_was_deserialisiert_wird() {
+        use serde_json::json;
+
+        let schema = pdf_file_json_schema();
+        let datei = |bv: serde_json::Value, abt1: serde_json::Value| {
+            json!({
+                "analysiert": {
+                    "titelblatt": { "amtsgericht": "Musterstadt", "blatt": "12", "grundbuch": "Musterdorf" },
+                    "bestandsverzeichnis": { "eintraege": bv },
+                    "abt1": { "eintraege": abt1 },
+                    "abt2": { "eintraege": [
+                        { "lfd_nr": "1a", "text": "Wegerecht" },
+                        { "lfdNr": "2", "bvNr": "1", "text": "Leitungsrecht" }
+                    ] }
+                },
+                "hocr": { "seiten": { "1": { "breiteMm": 210.0, "hoeheMm": 297.0, "parsed": { "careas": [] } } } }
+            })
+        };
+
+        let gueltig = datei(
+            json!([
+                { "lfdNr": "1", "flur": "2", "flurstueck": "3", "groesse": 1234 },
+                { "lfd_nr": 2, "zuNr": "1", "text": "Wegerecht",
+                  "positionInPdf": { "seite": "1", "rect": [0.0, 0.0, 10.0, 10.0] } }
+            ]),
+            json!([
+                { "lfdNr": "1", "eigentümer": "Max Mustermann", "version": 1 },
+                { "lfd_nr": 2, "eigentuemer": "Erika Mustermann", "version": "2" }
+            ]),
+        );
+        assert!(serde_json::from_value::<PdfFile>(gueltig.clone()).is_ok());
+        assert!(schema_passt(&schema, &schema, &gueltig));
+
+        let ungueltig = [
+            // Flurstück und Recht zugleich
+            datei(
+                json!([{ "lfd_nr": 1, "flur": 2, "text": "Wegerecht" }]),
+                json!([]),
+            ),
+            // Version 2 mit Feldern aus Version 1
+            datei(
+                json!([]),
+                json!([{ "lfd_nr": 1, "version": 2, "bv_nr": "1" }]),
+            ),
+            // Kein Zahlstring
+            datei(json!([{ "lfd_nr": "x", "flur": 1 }]), json!([])),
+        ];
+        // Abt. 1 ohne lfd. Nr. (nur mit `skip-zero-lfd-nr` zulässig)
+        let ohne_lfd_nr = (!cfg!(feature = "skip-zero-lfd-nr"))
+            .then(|| datei(json!([]), json!([{ "eigentuemer": "Max Mustermann" }])));
+        for datei in ungueltig.into_iter().chain(ohne_lfd_nr) {
+            assert!(serde_json::from_value::<PdfFile>(datei.clone()).is_err());
+            assert!(!schema_passt(&schema, &schema, &datei), "{datei}");
+        }
+    }
+
+    #[test]
+    fn parse_funktionen_liefern_gbx_error() {
+        assert_eq!(Geldbetrag::parse("10.000,00 DM").unwrap().cent, 1_000_000);
+        assert!(matches!(
+            Geldbetrag::parse("ohne Betrag"),
+            Err(GbxError::BetragParse(t)) if t == "ohne Betrag"
+        ));
+
+        assert_eq!(parse_deutsches_datum("am 12.3.1990").unwrap().jahr, 1990);
+        assert!(matches!(
+            parse_deutsches_datum("kein Datum"),
+            Err(GbxError::DatumParse(_))
+        ));
+
+        assert!(matches!(
+            LaufendeNummer::parse("x1"),
+            Err(GbxError::LfdNrParse(_))
+        ));
+
+        let fehler = GbxError::from(SeitenKonsistenzFehler::AnpassungOhneHocrSeite {
+            seite: "3".to_string(),
+        });
+        assert!(matches!(fehler, GbxError::SeitenKonsistenz(_)));
+        assert_eq!(fehler.to_string(), "Anpassung für Seite 3 ohne hOCR-Seite");
+        assert!(std::error::Error::source(&fehler).is_some());
+
+        let fehler = PdfFile::from_json("{").unwrap_err();
+        assert!(matches!(fehler, GbxError::JsonParse(_)));
+        assert!(fehler
+            .to_string()
+            .starts_with("Fehler beim Lesen des JSON: "));
+        assert_eq!(
+            GbxError::GroesseParse("x".to_string()).to_string(),
+            "Keine Flurstücksgröße: \"x\""
+        );
+    }
+
+    #[test]
+    fn flurstueck_groesse_parse() {
+        let hektar = FlurstueckGroesse::Hektar {
+            ha: Some(1),
+            a: Some(23),
+            m2: Some(45),
+        };
+        assert_eq!(FlurstueckGroesse::parse("1,2345 ha").unwrap(), hektar);
+        assert_eq!(FlurstueckGroesse::parse("1 ha 23 a 45 m²").unwrap(), hektar);
+        assert_eq!(FlurstueckGroesse::parse("1,5 ha").unwrap().get_m2(), 15_000);
+        assert_eq!(
+            FlurstueckGroesse::parse("1.234 m²").unwrap(),
+            FlurstueckGroesse::Metrisch { m2: Some(1234) }
+        );
+        assert_eq!(
+            FlurstueckGroesse::parse(" 1234 ").unwrap(),
+            FlurstueckGroesse::Metrisch { m2: Some(1234) }
+        );
+
+        for format in [
+            GroesseFormat::Kompakt,
+            GroesseFormat::HektarAusgeschrieben,
+            GroesseFormat::NurQuadratmeter,
+        ] {
+            for m2 in [45, 2345, 12_345, 1_234_567] {
+                let anzeige = FlurstueckGroesse::Metrisch { m2: Some(m2) }.anzeige(format);
+                assert_eq!(
+                    FlurstueckGroesse::parse(&anzeige).unwrap().get_m2(),
+                    m2,
+                    "{anzeige}"
+                );
+            }
+        }
+
+        for text in ["", "abc", "1,2 m²", "ha"] {
+            assert!(
+                matches!(
+                    FlurstueckGroesse::parse(text),
+                    Err(GbxError::GroesseParse(_))
+                ),
+                "{text}"
+            );
+        }
+        assert!(matches!(
+            FlurstueckGroesse::parse("20.000 ha"),
+            Err(GbxError::Groesse(GroesseFehler::Unplausibel { .. }))
+        ));
+    }
+
+    #[test]
+    fn pruefungen_unterscheiden_lfd_nr_mit_zusatz() {
+        let mut gb = leeres_grundbuch();
+        gb.abt2
+            .eintraege
+            .push(Abt2Eintrag::new(1, "7", "Wegerecht"));
+        gb.abt2
+            .eintraege
+            .push(abt2_eintrag_mit_zusatz(1, "a", "Wegerecht (Teilrecht)"));
+        gb.abt2.loeschungen.push(Abt2Loeschung {
+            lfd_nr: "1a".into(),
+            text: "Gelöscht".into(),
+            ..Default::default()
+        });
+        let pfad = |index| EintragPfad {
+            liste: EintragsListe::Abt2Eintraege,
+            index,
+        };
+
+        assert_eq!(
+            gb.pruefe_roetung(),
+            vec![RoetungsWarnung::GeloeschtAberNichtGeroetet {
+                pfad: pfad(1),
+                lfd_nr: LaufendeNummer::mit_zusatz(1, Some("a")),
+            }]
+        );
+
+        let referenzen = gb.abt2.pruefe_bv_referenzen(&gb.bestandsverzeichnis);
+        assert_eq!(referenzen[1].lfd_nr.to_string(), "1a");
+        assert!(referenzen[1].to_string().starts_with("Eintrag Nr. 1a "));
+
+        let bericht = gb.pruefe_alles(None);
+        let pfade = bericht
+            .befunde
+            .iter()
+            .filter(|b| b.bereich == PruefBereich::Referenzen)
+            .map(|b| b.pfade.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(pfade, vec![vec![pfad(0)], vec![pfad(1)]]);
+        assert!(gb.doppelte_lfd_nr().is_empty());
+
+        gb.abt2
+            .eintraege
+            .push(abt2_eintrag_mit_zusatz(1, "A", "Doppelt"));
+        assert_eq!(gb.doppelte_lfd_nr(), vec![vec![pfad(1), pfad(2)]]);
+    }
+
+    #[test]
+    fn gerundet_auf_ar_schneidet_ab() {
+        let groesse = |m2| FlurstueckGroesse::Metrisch { m2: Some(m2) };
+        assert_eq!(groesse(1_299).gerundet_auf_ar(), groesse(1_200));
+        assert_eq!(groesse(1_250).gerundet_auf_ar(), groesse(1_200));
+        assert_eq!(groesse(99).gerundet_auf_ar(), groesse(0));
+        assert_eq!(
+            FlurstueckGroesse::hektar_aus_m2(12_399).gerundet_auf_ar(),
+            FlurstueckGroesse::hektar_aus_m2(12_300)
+        );
+    }
+
+    #[test]
+    fn parse_aenderung_nimmt_betrag_an_der_waehrung() {
+        let veraenderung = |text: &str| Abt3Veraenderung {
+            text: text.into(),
+            ..Default::default()
+        };
+
+        let aenderung =
+            veraenderung("Zinsen ab 1.1.1990 für Teilbetrag von 10.000 DM auf 8 % erhöht")
+                .parse_aenderung();
+        assert_eq!(
+            aenderung.neuer_betrag,
+            Some(Geldbetrag {
+                cent: 1_000_000,
+                waehrung: Waehrung::DMark,
+            })
+        );
+        assert_eq!(aenderung.neuer_zinssatz, Some(8.0));
+
+        let aenderung = veraenderung("Lfd. Nr. 2 ermäßigt auf € 5.000,-").parse_aenderung();
+        assert_eq!(aenderung.neuer_betrag.map(|b| b.cent), Some(500_000));
+
+        let aenderung = veraenderung("Zinsen ab 1.1.1990 in DM").parse_aenderung();
+        assert_eq!(aenderung.neuer_betrag, None);
+    }
+
+    #[test]
+    fn parsed_hocr_worte_in_dokumentreihenfolge() {
+        let mut seite = hocr_seite(&["Grundbuch von", "Musterdorf"]);
+        seite
+            .parsed
+            .careas
+            .extend(hocr_seite(&["Blatt 12"]).parsed.careas);
+
+        assert_eq!(seite.parsed.zeilen().count(), 3);
+        let texte = |p: &ParsedHocr| p.worte().map(|w| w.text.clone()).collect::<Vec<_>>();
+        assert_eq!(
+            texte(&seite.parsed),
+            vec!["Grundbuch", "von", "Musterdorf", "Blatt", "12"]
+        );
+        assert_eq!(seite.volltext(), "Grundbuch von\nMusterdorf\nBlatt 12");
+
+        for wort in seite.parsed.worte_mut() {
+            wort.text = wort.text.to_uppercase();
+        }
+        assert_eq!(
+            texte(&seite.parsed),
+            vec!["GRUNDBUCH", "VON", "MUSTERDORF", "BLATT", "12"]
+        );
+    }
+
+    #[test]
+    fn wende_loeschungen_an_in_abt2_und_abt3_gleich() {
+        let mut gb = leeres_grundbuch();
+        gb.abt2
+            .eintraege
+            .push(Abt2Eintrag::new(1, "1", "Stammrecht"));
+        gb.abt2
+            .eintraege
+            .push(abt2_eintrag_mit_zusatz(1, "a", "Abgeleitetes Recht"));
+        let mut manuell = Abt2Eintrag::new(2, "1", "Manuell ungerötet");
+        manuell.manuell_geroetet = Some(false);
+        gb.abt2.eintraege.push(manuell);
+        gb.abt2.loeschungen.push(Abt2Loeschung {
+            lfd_nr: "1a, 2".into(),
+            ..Default::default()
+        });
+        gb.abt2.loeschungen.push(Abt2Loeschung {
+            lfd_nr: "1".into(),
+            manuell_geroetet: Some(true),
+            ..Default::default()
+        });
+
+        assert_eq!(gb.abt2.wende_loeschungen_an(), 1);
+        assert_eq!(gb.abt2.eintraege[0].automatisch_geroetet, None);
+        assert_eq!(gb.abt2.eintraege[1].automatisch_geroetet, Some(true));
+        assert_eq!(gb.abt2.eintraege[2].automatisch_geroetet, None);
+        assert_eq!(gb.abt2.wende_loeschungen_an(), 0);
+
+        gb.abt3
+            .eintraege
+            .push(Abt3Eintrag::new(1, "1", "", "Grundschuld"));
+        gb.abt3
+            .eintraege
+            .push(Abt3Eintrag::new(2, "1", "", "Hypothek"));
+        gb.abt3.loeschungen.push(Abt3Loeschung {
+            lfd_nr: "2".into(),
+            ..Default::default()
+        });
+
+        assert_eq!(gb.abt3.wende_loeschungen_an(), 1);
+        assert_eq!(gb.abt3.eintraege[0].automatisch_geroetet, None);
+        assert_eq!(gb.abt3.eintraege[1].automatisch_geroetet, Some(true));
+    }
+
+    #[test]
+    fn grundbuch_inhalt_ignoriert_roetung_wie_inhaltlich_gleich() {
+        use std::hash::{Hash, Hasher};
+
+        fn hash(gb: &Grundbuch) -> u64 {
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            GrundbuchInhalt(gb).hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let mut a = leeres_grundbuch();
+        a.abt2.eintraege.push(Abt2Eintrag::new(1, "1", "Wegerecht"));
+        let mut b = a.clone();
+        b.abt2.eintraege[0].manuell_geroetet = Some(true);
+        b.abt2.eintraege[0].automatisch_geroetet = Some(true);
+
+        assert!(a.inhaltlich_gleich(&b));
+        assert_eq!(GrundbuchInhalt(&a), GrundbuchInhalt(&b));
+        assert_eq!(hash(&a), hash(&b));
+
+        b.abt2.eintraege[0].text = "Leitungsrecht".into();
+        assert!(!a.inhaltlich_gleich(&b));
+        assert_ne!(GrundbuchInhalt(&a), GrundbuchInhalt(&b));
+    }
+
+    #[test]
+    fn grundlagen_und_parse_deutsches_datum_erkennen_gleiches_datum() {
+        let eintrag: Abt1EintragV1 = serde_json::from_value(serde_json::json!({
+            "lfd_nr": 1,
+            "grundlage_der_eintragung": [
+                "Auflassung vom 32.13.90 / 12. 03. 90",
+                "Erbschein vom 1.2.2003",
+            ],
+        }))
+        .unwrap();
+
+        let daten = eintrag
+            .grundlagen()
+            .into_iter()
+            .map(|g| g.datum)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            daten,
+            vec![Some("12.03.90".to_string()), Some("1.2.2003".to_string())]
+        );
+
+        let datum = parse_deutsches_datum("Auflassung vom 32.13.90 / 12. 03. 90").unwrap();
+        assert_eq!(
+            (datum.tag, datum.monat, datum.jahr),
+            (Some(12), Some(3), 1990)
+        );
+    }
+
+    #[test]
+    fn verifiziere_gegen_hocr_mit_eigener_mindest_aehnlichkeit() {
+        let mut hocr = HocrLayout::default();
+        hocr.seiten.insert(
+            "1".to_string(),
+            hocr_seite(&["Wegerecht für den jeweiligen Eigentümer"]),
+        );
+
+        let mut gb = leeres_grundbuch();
+        let mut eintrag = Abt2Eintrag::new(1, "1", "Wegerecht für den jeweiligen Eigentümer Nr. 5");
+        eintrag.position_in_pdf = Some(PositionInPdf {
+            seite: "1".to_string(),
+            rect: rect(0.0, 0.0, 600.0, 10.0),
+            ..Default::default()
+        });
+        gb.abt2.eintraege.push(eintrag);
+
+        assert!(gb.verifiziere_gegen_hocr(&hocr).is_empty());
+        assert_eq!(
+            gb.verifiziere_gegen_hocr_mit(&hocr, VerifikationsOptions::default()),
+            gb.verifiziere_gegen_hocr(&hocr)
+        );
+
+        let streng = VerifikationsOptions {
+            min_aehnlichkeit: 0.95,
+        };
+        let berichte = gb.verifiziere_gegen_hocr_mit(&hocr, streng);
+        assert_eq!(berichte.len(), 1);
+        assert_eq!(
+            berichte[0].ocr_text,
+            "Wegerecht für den jeweiligen Eigentümer"
+        );
+        assert!(berichte[0].aehnlichkeit >= MIN_HOCR_AEHNLICHKEIT);
     }
 }